@@ -18,7 +18,7 @@ where
 }
 
 fn main() {
-    let context = match unsafe { openvr::init(openvr::ApplicationType::Other) } {
+    let context = match openvr::init(openvr::ApplicationType::Other) {
         Ok(ivr) => ivr,
         Err(err) => {
             println!("Failed to initialize openvr: {}", err);
@@ -0,0 +1,213 @@
+//! Derive macros for the `openvr` crate. Use them through its `derive` feature rather than
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitStr, Result};
+
+/// Implements `openvr::input::ActionSet` for a struct of action handles.
+///
+/// The struct takes `#[action_set(path = "/actions/<set>", usage = LeftRight)]`; `usage` is
+/// optional and names an `ActionSetUsage` variant. Every field is one of
+///
+/// - `#[action(path = "...", ty = Boolean, requirement = Mandatory, skeleton = "...", localized =
+///   "...")]` on a `VRActionHandle`. `path` may be relative to the set, e.g. `in/grab`. `ty` names
+///   an `ActionType` variant and `requirement` an `ActionRequirement` variant. `skeleton` is
+///   required for skeleton actions. `localized` is the `en_US` display name. Only `path` and `ty`
+///   are required.
+/// - `#[action_set]` on a `VRActionSetHandle`, which receives the handle of the set itself.
+#[proc_macro_derive(ActionSet, attributes(action_set, action))]
+pub fn derive_action_set(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct SetAttr {
+    path: LitStr,
+    usage: Option<Ident>,
+}
+
+struct ActionAttr {
+    path: LitStr,
+    ty: Ident,
+    requirement: Option<Ident>,
+    skeleton: Option<LitStr>,
+    localized: Option<LitStr>,
+}
+
+enum FieldKind {
+    SetHandle,
+    Action(ActionAttr),
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let set = parse_set_attr(&input)?;
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "ActionSet needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "ActionSet can only be derived for structs",
+            ))
+        }
+    };
+
+    let set_path = set.path.value();
+    if !set_path.starts_with("/actions/") || set_path[9..].is_empty() || set_path[9..].contains('/')
+    {
+        return Err(Error::new_spanned(
+            &set.path,
+            "action set paths look like `/actions/<set>`",
+        ));
+    }
+    let usage = set.usage.unwrap_or_else(|| format_ident!("LeftRight"));
+
+    let mut manifest = Vec::new();
+    let mut load = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        match parse_field(field)? {
+            FieldKind::SetHandle => load.push(quote! {
+                #ident: input.get_action_set_handle(#set_path)?
+            }),
+            FieldKind::Action(action) => {
+                let path = action.path.value();
+                let path = if path.starts_with('/') {
+                    if !path.starts_with(&format!("{}/", set_path)) {
+                        return Err(Error::new_spanned(
+                            &action.path,
+                            format!("action is not in {}", set_path),
+                        ));
+                    }
+                    path
+                } else {
+                    format!("{}/{}", set_path, path)
+                };
+                let ty = &action.ty;
+                let mut built = quote! {
+                    ::openvr::input::Action::new(#path, ::openvr::input::ActionType::#ty)
+                };
+                if let Some(ref requirement) = action.requirement {
+                    built = quote! {
+                        #built.with_requirement(::openvr::input::ActionRequirement::#requirement)
+                    };
+                }
+                if let Some(ref skeleton) = action.skeleton {
+                    built = quote! { #built.with_skeleton(#skeleton) };
+                }
+                manifest.push(quote! { let manifest = manifest.with_action(#built); });
+                if let Some(ref localized) = action.localized {
+                    manifest.push(quote! {
+                        let manifest = manifest.with_localization("en_US", #path, #localized);
+                    });
+                }
+                load.push(quote! {
+                    #ident: input.get_action_handle(#path)?
+                });
+            }
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::openvr::input::ActionSet for #name #ty_generics #where_clause {
+            const PATH: &'static str = #set_path;
+
+            fn add_to_manifest(
+                manifest: ::openvr::input::ActionManifest,
+            ) -> ::openvr::input::ActionManifest {
+                let manifest = manifest
+                    .with_action_set(#set_path, ::openvr::input::ActionSetUsage::#usage);
+                #(#manifest)*
+                manifest
+            }
+
+            fn load(
+                input: &mut ::openvr::Input,
+            ) -> ::std::result::Result<Self, ::openvr::errors::VRInputError> {
+                ::std::result::Result::Ok(#name {
+                    #(#load,)*
+                })
+            }
+        }
+    })
+}
+
+fn parse_set_attr(input: &DeriveInput) -> Result<SetAttr> {
+    let attr = input
+        .attrs
+        .iter()
+        .find(|x| x.path().is_ident("action_set"))
+        .ok_or_else(|| {
+            Error::new_spanned(
+                &input.ident,
+                "missing #[action_set(path = \"/actions/...\")]",
+            )
+        })?;
+    let mut path = None;
+    let mut usage = None;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("path") {
+            path = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("usage") {
+            usage = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("expected `path` or `usage`"));
+        }
+        Ok(())
+    })?;
+    Ok(SetAttr {
+        path: path.ok_or_else(|| Error::new_spanned(attr, "missing `path`"))?,
+        usage,
+    })
+}
+
+fn parse_field(field: &syn::Field) -> Result<FieldKind> {
+    let mut kind = None;
+    for attr in &field.attrs {
+        if attr.path().is_ident("action_set") {
+            attr.meta.require_path_only()?;
+            kind = Some(FieldKind::SetHandle);
+        } else if attr.path().is_ident("action") {
+            let (mut path, mut ty, mut requirement, mut skeleton, mut localized) =
+                (None, None, None, None, None);
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    path = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("ty") {
+                    ty = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("requirement") {
+                    requirement = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skeleton") {
+                    skeleton = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("localized") {
+                    localized = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta
+                        .error("expected `path`, `ty`, `requirement`, `skeleton` or `localized`"));
+                }
+                Ok(())
+            })?;
+            kind = Some(FieldKind::Action(ActionAttr {
+                path: path.ok_or_else(|| Error::new_spanned(attr, "missing `path`"))?,
+                ty: ty.ok_or_else(|| Error::new_spanned(attr, "missing `ty`"))?,
+                requirement,
+                skeleton,
+                localized,
+            }));
+        }
+    }
+    kind.ok_or_else(|| Error::new_spanned(field, "fields need #[action(...)] or #[action_set]"))
+}
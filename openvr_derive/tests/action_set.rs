@@ -0,0 +1,45 @@
+use openvr::input::{ActionManifest, ActionSet, VRActionHandle, VRActionSetHandle};
+use openvr::mock::MockRuntime;
+use openvr::Context;
+
+#[derive(ActionSet)]
+#[action_set(path = "/actions/main", usage = Single)]
+struct MainActions {
+    #[action_set]
+    set: VRActionSetHandle,
+    #[action(path = "in/grab", ty = Boolean, requirement = Mandatory, localized = "Grab")]
+    grab: VRActionHandle,
+    #[action(path = "/actions/main/in/hand_left", ty = Skeleton, skeleton = "/skeleton/hand/left")]
+    hand_left: VRActionHandle,
+    #[action(path = "out/haptic", ty = Vibration)]
+    haptic: VRActionHandle,
+}
+
+#[test]
+fn manifest_matches_handles() {
+    let manifest = ActionManifest::new().with_set::<MainActions>();
+    manifest.validate().unwrap();
+    assert_eq!(MainActions::PATH, "/actions/main");
+    assert!(manifest.contains_action("/actions/main/in/grab"));
+    assert!(manifest.contains_action("/actions/main/out/haptic"));
+    assert_eq!(
+        manifest.localization["en_US"]["/actions/main/in/grab"],
+        "Grab"
+    );
+    let json = manifest.to_json();
+    assert!(json.contains(r#""usage": "single""#));
+    assert!(json.contains(r#""requirement": "mandatory""#));
+
+    let runtime = MockRuntime::new();
+    let context = Context::mock(&runtime);
+    let mut input = context.input().unwrap();
+    let _manifest = input.set_action_manifest_from(&manifest).unwrap();
+    let actions = MainActions::load(&mut input).unwrap();
+    assert_eq!(actions.set, MainActions::set_handle(&mut input).unwrap());
+    assert_eq!(
+        actions.grab,
+        input.get_action_handle("/actions/main/in/grab").unwrap()
+    );
+    assert_ne!(actions.grab, actions.hand_left);
+    assert_ne!(actions.hand_left, actions.haptic);
+}
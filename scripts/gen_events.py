@@ -0,0 +1,174 @@
+#!/usr/bin/env python3
+"""Generates src/system/event/generated.rs from the OpenVR API description.
+
+Usage: scripts/gen_events.py path/to/openvr_api.json > src/system/event/generated.rs
+
+The output is passed through `rustfmt`, which has to be on the PATH, so the file stays stable under `cargo fmt`.
+
+Event types come from `vr::EVREventType` in `openvr_api.json`. Variant docs are taken from the trailing comments in the
+`openvr.h` next to it. As with `gen_properties.py`, use the copy matching the `openvr_sys` version in Cargo.toml.
+
+Which member of `VREvent_Data_t` an event carries isn't machine-readable, so it is listed in PAYLOADS below. Events
+not listed there become unit variants.
+"""
+import contextlib
+import io
+import json
+import os
+import re
+import subprocess
+import sys
+import textwrap
+
+# Payload type of each event, by event name.
+PAYLOADS = {}
+
+
+def payload(ty, *names):
+    for name in names:
+        PAYLOADS['VREvent_' + name] = ty
+
+
+payload('Ipd', 'IpdChanged')
+payload('Property', 'PropertyChanged')
+payload('Controller', 'ButtonPress', 'ButtonUnpress', 'ButtonTouch', 'ButtonUntouch')
+payload('Mouse', 'MouseMove', 'MouseButtonDown', 'MouseButtonUp', 'LockMousePosition', 'UnlockMousePosition')
+payload('Overlay', 'FocusEnter', 'FocusLeave', 'OverlayFocusChanged', 'OverlayCreated', 'OverlayDestroyed',
+        'OverlayNameChanged')
+payload('Scroll', 'ScrollDiscrete', 'ScrollSmooth')
+payload('TouchPadMove', 'TouchPadMove')
+payload('Process', 'InputFocusCaptured', 'InputFocusReleased', 'SceneApplicationChanged', 'InputFocusChanged',
+        'SceneApplicationUsingWrongGraphicsAdapter', 'ActionBindingReloaded', 'SceneAppPipeDisconnected', 'Quit',
+        'ProcessQuit', 'QuitAcknowledged', 'ProcessConnected', 'ProcessDisconnected', 'Monitor_ShowHeadsetView',
+        'Monitor_HideHeadsetView')
+payload('Screenshot', 'RequestScreenshot', 'ScreenshotTaken', 'ScreenshotFailed', 'SubmitScreenshotToDashboard')
+payload('ScreenshotProgress', 'ScreenshotProgressToDashboard')
+payload('ShowUi', 'ShowUI')
+payload('ShowDevTools', 'ShowDevTools')
+payload('Notification', 'Notification_Shown', 'Notification_Hidden', 'Notification_BeginInteraction',
+        'Notification_Destroyed')
+payload('Chaperone', 'ChaperoneUniverseHasChanged')
+payload('SeatedZeroPoseReset', 'SeatedZeroPoseReset', 'StandingZeroPoseReset')
+payload('Status', 'StatusUpdate')
+payload('Keyboard', 'KeyboardClosed', 'KeyboardCharInput', 'KeyboardDone', 'KeyboardOpened_Global',
+        'KeyboardClosed_Global')
+payload('ApplicationLaunch', 'ApplicationMimeTypeLoad')
+payload('HdcpError', 'Compositor_HDCPError')
+payload('EditingCameraSurface', 'TrackedCamera_EditingSurface')
+payload('PerformanceTest', 'PerformanceTest_FidelityLevel')
+payload('MessageOverlay', 'MessageOverlay_Closed')
+payload('HapticVibration', 'Input_HapticVibration')
+payload('InputBindingLoad', 'Input_BindingLoadFailed', 'Input_BindingLoadSuccessful')
+payload('InputActionManifestLoad', 'Input_ActionManifestLoadFailed')
+payload('ProgressUpdate', 'Input_ProgressUpdate')
+payload('SpatialAnchor', 'SpatialAnchors_PoseUpdated', 'SpatialAnchors_DescriptorUpdated',
+        'SpatialAnchors_RequestPoseUpdate', 'SpatialAnchors_RequestDescriptorUpdate')
+payload('AudioVolumeControl', 'Audio_SetSpeakersVolume', 'Audio_SetMicrophoneVolume')
+payload('AudioMuteControl', 'Audio_SetSpeakersMute', 'Audio_SetMicrophoneMute')
+
+# Values that aren't events. `VendorSpecific` covers the range between the markers.
+SKIP = ('VREvent_None', 'VREvent_VendorSpecific_Reserved_Start', 'VREvent_VendorSpecific_Reserved_End')
+
+# Docs replacing header comments that don't make sense on their own. `None` drops the comment.
+DOCS = {
+    'VREvent_Modal_Cancel': None,
+    'VREvent_SceneApplicationStateChanged': 'Query `IVRApplications::GetSceneApplicationState` for the new state',
+}
+
+
+def header_comments(path):
+    """Trailing comments of the EVREventType values in openvr.h, by name."""
+    comments = {}
+    if not os.path.exists(path):
+        return comments
+    for line in open(path):
+        m = re.match(r'\s*(VREvent_\w+)\s*=\s*\d+,\s*//\s*(.*?)\s*$', line)
+        if m:
+            comments[m.group(1)] = m.group(2)
+    return comments
+
+
+def doc(comment):
+    """Strips payload and deprecation notes, which the generated code expresses itself."""
+    comment = re.sub(r'^(data is \w+|Data is \w+\.?|No data|no data)\s*[;,.]?\s*(-\s*)?', '', comment)
+    return re.sub(r'\s*(Data is \w+\.|DEPRECATED:?)\s*', ' ', comment).strip()
+
+
+def generate():
+    api = json.load(open(sys.argv[1]))
+    comments = header_comments(os.path.join(os.path.dirname(sys.argv[1]), 'openvr.h'))
+    (enum,) = [x for x in api['enums'] if x['enumname'] == 'vr::EVREventType']
+    events = [x['name'] for x in enum['values']
+              if x['name'] not in SKIP and not x['name'].startswith('VREvent_Reserved_')]
+    unknown = set(PAYLOADS) - set(events)
+    if unknown:
+        sys.exit('payloads listed for unknown events: %s' % ', '.join(sorted(unknown)))
+
+    print('// Generated by scripts/gen_events.py from openvr_api.json. Do not edit by hand.')
+    print()
+    print('use super::*;')
+    print()
+    print('#[allow(non_camel_case_types, deprecated)]')
+    print('#[derive(Debug, Copy, Clone)]')
+    print('pub enum Event {')
+    for name in events:
+        comment = comments.get(name, '')
+        text = DOCS[name] if name in DOCS else doc(comment)
+        for line in textwrap.wrap(text or '', 100 - len('    /// ')):
+            print('    /// %s' % line)
+        if 'DEPRECATED' in comment:
+            print('    #[deprecated]')
+        ty = PAYLOADS.get(name)
+        print('    %s%s,' % (name[len('VREvent_'):], '(%s)' % ty if ty else ''))
+    print()
+    print('    VendorSpecific(sys::EVREventType),')
+    print('    Unknown(sys::EVREventType),')
+    print('}')
+    print()
+    print('impl Event {')
+    print('    pub(super) fn from_sys(ty: sys::EVREventType, data: &sys::VREvent_Data_t) -> Self {')
+    print('        use self::Event::*;')
+    print()
+    print('        #[allow(deprecated)]')
+    print('        match ty {')
+    for name in events:
+        short = name[len('VREvent_'):]
+        arg = '(get(data))' if name in PAYLOADS else ''
+        print('            sys::EVREventType_%s => %s%s,' % (name, short, arg))
+    print('            x if (sys::EVREventType_VREvent_VendorSpecific_Reserved_Start')
+    print('                ..=sys::EVREventType_VREvent_VendorSpecific_Reserved_End)')
+    print('                .contains(&x) =>')
+    print('            {')
+    print('                VendorSpecific(x)')
+    print('            }')
+    print('            x => Unknown(x),')
+    print('        }')
+    print('    }')
+    print()
+    print('    /// The `EVREventType` this event was decoded from.')
+    print('    pub fn event_type(&self) -> sys::EVREventType {')
+    print('        use self::Event::*;')
+    print()
+    print('        #[allow(deprecated)]')
+    print('        match *self {')
+    for name in events:
+        short = name[len('VREvent_'):]
+        pat = '(_)' if name in PAYLOADS else ''
+        print('            %s%s => sys::EVREventType_%s,' % (short, pat, name))
+    print('            VendorSpecific(x) | Unknown(x) => x,')
+    print('        }')
+    print('    }')
+    print('}')
+
+
+def main():
+    out = io.StringIO()
+    with contextlib.redirect_stdout(out):
+        generate()
+    formatted = subprocess.run(['rustfmt', '--edition', '2021'], input=out.getvalue(), capture_output=True,
+                               text=True, check=True)
+    sys.stdout.write(formatted.stdout)
+
+
+if __name__ == '__main__':
+    main()
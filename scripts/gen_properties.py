@@ -0,0 +1,97 @@
+#!/usr/bin/env python3
+"""Generates src/property/generated.rs from the OpenVR API description.
+
+Usage: scripts/gen_properties.py path/to/openvr_api.json > src/property/generated.rs
+
+`openvr_api.json` ships with the OpenVR SDK under `headers/`, and is also vendored by the `openvr_sys` crate. Use the
+copy matching the `openvr_sys` version in Cargo.toml so every generated constant exists in the bindings.
+"""
+import json
+import sys
+
+# Value type of each property, keyed by the suffix of its name.
+SCALAR_TYPES = {
+    'Bool': 'bool',
+    'Float': 'f32',
+    'Int32': 'i32',
+    'Uint64': 'u64',
+    'Matrix34': '[[f32; 4]; 3]',
+    'String': 'String',
+    'Vector3': '[f32; 3]',
+}
+
+# Element type of `_Array` properties, keyed by the suffix before `_Array`.
+ARRAY_TYPES = {
+    'Float': 'f32',
+    'Int32': 'i32',
+    'Uint64': 'u64',
+    'Matrix34': 'Matrix3x4',
+    'Vector4': '[f32; 4]',
+}
+
+# Properties whose name doesn't carry their type.
+OVERRIDES = {
+    'Prop_ParentContainer': 'u64',
+    'Prop_Hmd_AllowsClientToControlTextureIndex': 'bool',
+    # Documented as an array of double despite its name.
+    'Prop_CameraDistortionCoefficients_Float_Array': 'Vec<f64>',
+}
+
+# Range markers and other values that aren't readable properties, but are still useful to have as raw values.
+MARKERS = (
+    'Prop_Invalid',
+    'Prop_DisplayHiddenArea_Binary_Start',
+    'Prop_DisplayHiddenArea_Binary_End',
+    'Prop_VendorSpecific_Reserved_Start',
+    'Prop_VendorSpecific_Reserved_End',
+)
+
+# Properties OpenVR has deprecated keep this suffix in their raw name. Their constants drop it and are marked
+# `#[deprecated]`, so existing code keeps compiling with a warning.
+DEPRECATED_SUFFIX = '_deprecated'
+DEPRECATED_NOTE = 'OpenVR deprecated this property; openvr_sys now maps it to Prop_Invalid.'
+
+
+def const(name, ty, value):
+    """A const item, broken after `=` like rustfmt does when it doesn't fit in 100 columns."""
+    line = 'pub const %s: %s = %s;' % (name, ty, value)
+    return line if len(line) <= 100 else 'pub const %s: %s =\n    %s;' % (name, ty, value)
+
+
+def main():
+    api = json.load(open(sys.argv[1]))
+    (enum,) = [x for x in api['enums'] if x['enumname'] == 'vr::ETrackedDeviceProperty']
+
+    print('// Generated by scripts/gen_properties.py from openvr_api.json. Do not edit by hand.')
+    print('#![allow(non_upper_case_globals)]')
+    print()
+    print('use super::Property;')
+    print('use crate::pose::Matrix3x4;')
+    print('use crate::TrackedDeviceProperty;')
+    print('use openvr_sys as sys;')
+    for value in enum['values']:
+        name = value['name']
+        raw = 'sys::ETrackedDeviceProperty_' + name
+        short = name[len('Prop_'):]
+        deprecated = name.endswith(DEPRECATED_SUFFIX)
+        if deprecated:
+            name = name[:-len(DEPRECATED_SUFFIX)]
+            short = short[:-len(DEPRECATED_SUFFIX)]
+            print('#[deprecated(note = "%s")]' % DEPRECATED_NOTE)
+        # Binary properties have no typed getter, so only their raw value is exposed.
+        if name in MARKERS or name.endswith('_Binary'):
+            print(const(short, 'TrackedDeviceProperty', 'TrackedDeviceProperty(%s)' % raw))
+            continue
+        ty = OVERRIDES.get(name)
+        if ty is None and name.endswith('_Array'):
+            element = ARRAY_TYPES.get(name.rsplit('_', 2)[-2])
+            ty = element and 'Vec<%s>' % element
+        elif ty is None:
+            ty = SCALAR_TYPES.get(name.rsplit('_', 1)[-1])
+        if ty is None:
+            continue
+        print(const(short, 'Property<%s>' % ty, 'Property::new(%s)' % raw))
+
+
+if __name__ == '__main__':
+    main()
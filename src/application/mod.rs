@@ -6,7 +6,7 @@ use crate::Application;
 
 type Result<T> = std::result::Result<T, VRApplicationError>;
 
-impl Application {
+impl Application<'_> {
     // ---- Handle Management ----
 
     pub fn add_application_manifest(&mut self, path: &Path, temporary: bool) -> Result<()> {
@@ -1,8 +1,12 @@
 use std::convert::From;
+use std::fmt::Write;
 
 use openvr_sys as sys;
 
-use crate::Chaperone;
+use crate::chaperone_setup::BoundsQuad;
+use crate::{
+    Chaperone, ChaperoneSetup, ColorTint, System, TrackedDevicePose, TrackingUniverseOrigin,
+};
 
 /// Chaperone warning states
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -76,7 +80,7 @@ impl From<sys::ChaperoneCalibrationState> for ChaperoneCalibrationState {
     }
 }
 
-impl Chaperone {
+impl Chaperone<'_> {
     /// Get the current state of Chaperone calibration.
     /// This state can change at any time during a session due to physical base station changes.
     /// (NOTE: Some of these error codes are never returned as implementation for the error states
@@ -127,6 +131,384 @@ impl Chaperone {
     pub fn force_bounds_visible(&self, force: bool) {
         unsafe { self.0.ForceBoundsVisible.unwrap()(force) };
     }
+
+    /// Reload chaperone data from the .vrchap file on disk.
+    pub fn reload_info(&self) {
+        unsafe { self.0.ReloadInfo.unwrap()() };
+    }
+
+    /// Hint to the chaperone about the color and brightness of the scene, so it can adapt the
+    /// bounds to it.
+    pub fn set_scene_color(&self, color: ColorTint) {
+        unsafe { self.0.SetSceneColor.unwrap()(color.into()) };
+    }
+
+    /// The colors the bounds are currently drawn with, `count` of them, for bounds faded over
+    /// `collision_bounds_fade_distance` meters. Also returns the color of the camera view.
+    pub fn get_bounds_color(
+        &self,
+        count: usize,
+        collision_bounds_fade_distance: f32,
+    ) -> (Vec<ColorTint>, ColorTint) {
+        let mut colors = vec![sys::HmdColor_t::from(ColorTint::default()); count];
+        let mut camera = sys::HmdColor_t::from(ColorTint::default());
+        unsafe {
+            self.0.GetBoundsColor.unwrap()(
+                colors.as_mut_ptr(),
+                count as i32,
+                collision_bounds_fade_distance,
+                &mut camera,
+            )
+        };
+        (colors.into_iter().map(Into::into).collect(), camera.into())
+    }
+
+    /// The play area rectangle as a `PlayArea`.
+    pub fn get_play_area(&self) -> Option<PlayArea> {
+        self.get_play_area_rect().map(|x| PlayArea::from_rect(&x))
+    }
+}
+
+impl ChaperoneSetup<'_> {
+    /// The live collision bounds as a `PlayArea`, for boundary checks of your own.
+    pub fn get_live_play_area(&self) -> Option<PlayArea> {
+        self.get_live_collision_bounds()
+            .map(|x| PlayArea::from_quads(&x))
+    }
+}
+
+impl From<ColorTint> for sys::HmdColor_t {
+    fn from(x: ColorTint) -> Self {
+        sys::HmdColor_t {
+            r: x.r,
+            g: x.g,
+            b: x.b,
+            a: x.a,
+        }
+    }
+}
+
+impl From<sys::HmdColor_t> for ColorTint {
+    fn from(x: sys::HmdColor_t) -> Self {
+        ColorTint {
+            r: x.r,
+            g: x.g,
+            b: x.b,
+            a: x.a,
+        }
+    }
+}
+
+/// The area the user may move in, as walls on the floor: the outline of the collision bounds or
+/// play area, seen from above. Points are `[x, z]` in standing space; heights are ignored.
+///
+/// ```
+/// use openvr::chaperone::PlayArea;
+///
+/// let rect = [[-1.0, 0.0, 1.0], [1.0, 0.0, 1.0], [1.0, 0.0, -1.0], [-1.0, 0.0, -1.0]];
+/// let area = PlayArea::from_rect(&rect);
+/// let head = [0.7, 1.7, 0.0];
+/// assert!(area.contains(head));
+/// let wall = area.nearest_wall(head).unwrap();
+/// assert!((wall.distance - 0.3).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayArea {
+    walls: Vec<[[f32; 2]; 2]>,
+}
+
+/// The wall of a `PlayArea` closest to a point, from `PlayArea::nearest_wall`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WallHit {
+    /// Index into `PlayArea::walls`.
+    pub index: usize,
+    /// Closest point on the wall, `[x, z]`.
+    pub point: [f32; 2],
+    /// Horizontal distance to `point`.
+    pub distance: f32,
+}
+
+impl PlayArea {
+    /// A play area bounded by `walls`, each from one `[x, z]` point to another. The walls should
+    /// form closed outlines; their order doesn't matter.
+    pub fn new(walls: Vec<[[f32; 2]; 2]>) -> Self {
+        PlayArea { walls }
+    }
+
+    /// The closed polygon through `points`.
+    pub fn from_polygon(points: &[[f32; 2]]) -> Self {
+        Self::new(
+            (0..points.len())
+                .map(|i| [points[i], points[(i + 1) % points.len()]])
+                .collect(),
+        )
+    }
+
+    /// The outline of collision bounds quads, as returned by
+    /// `ChaperoneSetup::get_live_collision_bounds`. The two lowest corners of each quad make a
+    /// wall.
+    pub fn from_quads(quads: &[BoundsQuad]) -> Self {
+        Self::new(
+            quads
+                .iter()
+                .map(|quad| {
+                    let mut corners = [0, 1, 2, 3];
+                    corners.sort_by(|&a, &b| quad[a][1].total_cmp(&quad[b][1]));
+                    let (a, b) = (corners[0].min(corners[1]), corners[0].max(corners[1]));
+                    // Corners go around the quad, so keep the wall's direction when it wraps
+                    // from the last corner to the first.
+                    let (a, b) = if (a, b) == (0, 3) { (3, 0) } else { (a, b) };
+                    [[quad[a][0], quad[a][2]], [quad[b][0], quad[b][2]]]
+                })
+                .collect(),
+        )
+    }
+
+    /// The rectangle returned by `Chaperone::get_play_area_rect`.
+    pub fn from_rect(rect: &[[f32; 3]; 4]) -> Self {
+        Self::from_polygon(&rect.map(|x| [x[0], x[2]]))
+    }
+
+    pub fn walls(&self) -> &[[[f32; 2]; 2]] {
+        &self.walls
+    }
+
+    /// Whether `point`, in standing space, is within the bounds as seen from above.
+    pub fn contains(&self, point: [f32; 3]) -> bool {
+        let (x, z) = (point[0], point[2]);
+        // Even-odd rule: count the walls a ray towards +x crosses.
+        self.walls
+            .iter()
+            .filter(|[a, b]| {
+                (a[1] > z) != (b[1] > z) && x < a[0] + (z - a[1]) / (b[1] - a[1]) * (b[0] - a[0])
+            })
+            .count()
+            % 2
+            == 1
+    }
+
+    /// The wall closest to `point`, in standing space, or `None` if there are no walls.
+    pub fn nearest_wall(&self, point: [f32; 3]) -> Option<WallHit> {
+        let p = [point[0], point[2]];
+        self.walls
+            .iter()
+            .enumerate()
+            .map(|(index, [a, b])| {
+                let ab = [b[0] - a[0], b[1] - a[1]];
+                let len2 = ab[0] * ab[0] + ab[1] * ab[1];
+                let t = if len2 > 0.0 {
+                    (((p[0] - a[0]) * ab[0] + (p[1] - a[1]) * ab[1]) / len2).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let closest = [a[0] + ab[0] * t, a[1] + ab[1] * t];
+                WallHit {
+                    index,
+                    point: closest,
+                    distance: (p[0] - closest[0]).hypot(p[1] - closest[1]),
+                }
+            })
+            .min_by(|x, y| x.distance.total_cmp(&y.distance))
+    }
+
+    /// Horizontal distance from `point` to the nearest wall: positive inside the bounds, negative
+    /// outside.
+    pub fn distance_to_edge(&self, point: [f32; 3]) -> Option<f32> {
+        let distance = self.nearest_wall(point)?.distance;
+        Some(if self.contains(point) {
+            distance
+        } else {
+            -distance
+        })
+    }
+}
+
+/// The play area rectangle from `Chaperone::get_play_area_rect`, with the geometry needed to place
+/// content in it.
+///
+/// Corners are in standing space unless moved to another universe with `in_universe`.
+///
+/// ```no_run
+/// # let context = openvr::init(openvr::ApplicationType::Scene).unwrap();
+/// use openvr::chaperone::PlayAreaRect;
+///
+/// let chaperone = context.chaperone().unwrap();
+/// if let Some(rect) = chaperone.get_play_area_rect().map(PlayAreaRect::new) {
+///     println!("{:?} m² around {:?}", rect.area(), rect.center());
+///     std::fs::write("play_area.svg", rect.to_svg()).unwrap();
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlayAreaRect {
+    corners: [[f32; 3]; 4],
+}
+
+impl PlayAreaRect {
+    pub fn new(corners: [[f32; 3]; 4]) -> Self {
+        PlayAreaRect { corners }
+    }
+
+    pub fn corners(&self) -> &[[f32; 3]; 4] {
+        &self.corners
+    }
+
+    pub fn center(&self) -> [f32; 3] {
+        let mut center = [0.0; 3];
+        for corner in &self.corners {
+            for i in 0..3 {
+                center[i] += corner[i] / 4.0;
+            }
+        }
+        center
+    }
+
+    /// Length of the first side and of the second side, in meters. In standing space these are the
+    /// width along x and the depth along z.
+    pub fn size(&self) -> (f32, f32) {
+        let [a, b, c, _] = self.corners;
+        (distance(a, b), distance(b, c))
+    }
+
+    /// Rotation about +y, in radians, from the x axis to the first side.
+    pub fn orientation(&self) -> f32 {
+        let [a, b, _, _] = self.corners;
+        (a[2] - b[2]).atan2(b[0] - a[0])
+    }
+
+    /// Floor area in square meters.
+    pub fn area(&self) -> f32 {
+        let (width, depth) = self.size();
+        width * depth
+    }
+
+    /// Whether `point` is above or below the rectangle.
+    pub fn contains(&self, point: [f32; 3]) -> bool {
+        self.play_area().contains(point)
+    }
+
+    /// Whether the device is tracked and above or below the rectangle. The pose must be in the same
+    /// universe as the rectangle.
+    pub fn contains_pose(&self, pose: &TrackedDevicePose) -> bool {
+        let m = pose.device_to_absolute_tracking();
+        pose.pose_is_valid() && self.contains([m[0][3], m[1][3], m[2][3]])
+    }
+
+    /// The rectangle with `transform` applied to each corner.
+    pub fn transformed(&self, transform: &[[f32; 4]; 3]) -> Self {
+        PlayAreaRect::new(self.corners.map(|x| transform_point(transform, x)))
+    }
+
+    /// The rectangle, given in standing space, moved to `universe`.
+    pub fn in_universe(&self, universe: TrackingUniverseOrigin, system: &System) -> Self {
+        match universe {
+            TrackingUniverseOrigin::Standing => *self,
+            TrackingUniverseOrigin::Seated => self.transformed(&invert_rigid(
+                &system.seated_zero_pose_to_standing_absolute_tracking_pose(),
+            )),
+            TrackingUniverseOrigin::RawAndUncalibrated => self.transformed(&invert_rigid(
+                &system.raw_zero_pose_to_standing_absolute_tracking_pose(),
+            )),
+        }
+    }
+
+    pub fn play_area(&self) -> PlayArea {
+        PlayArea::from_rect(&self.corners)
+    }
+
+    /// A top-down SVG drawing of the rectangle, in meters, with x to the right and z down. The
+    /// first corner and the center are marked.
+    pub fn to_svg(&self) -> String {
+        let (mut min, mut max) = ([f32::MAX; 2], [f32::MIN; 2]);
+        for corner in &self.corners {
+            for (i, v) in [corner[0], corner[2]].into_iter().enumerate() {
+                min[i] = min[i].min(v);
+                max[i] = max[i].max(v);
+            }
+        }
+        let margin = 0.25;
+        let center = self.center();
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            min[0] - margin,
+            min[1] - margin,
+            max[0] - min[0] + 2.0 * margin,
+            max[1] - min[1] + 2.0 * margin
+        )
+        .unwrap();
+        let points: Vec<_> = self
+            .corners
+            .iter()
+            .map(|x| format!("{},{}", x[0], x[2]))
+            .collect();
+        writeln!(
+            svg,
+            r#"  <polygon points="{}" fill="none" stroke="black" stroke-width="0.02"/>"#,
+            points.join(" ")
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"  <circle cx="{}" cy="{}" r="0.05" fill="red"/>"#,
+            self.corners[0][0], self.corners[0][2]
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"  <circle cx="{}" cy="{}" r="0.05" fill="blue"/>"#,
+            center[0], center[2]
+        )
+        .unwrap();
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// A Wavefront OBJ mesh of the floor rectangle and, if `wall_height` is above 0, walls of that
+    /// height around it.
+    pub fn to_obj(&self, wall_height: f32) -> String {
+        let mut obj = String::new();
+        for [x, y, z] in self.corners {
+            writeln!(obj, "v {x} {y} {z}").unwrap();
+        }
+        obj.push_str("f 1 2 3 4\n");
+        if wall_height > 0.0 {
+            for [x, y, z] in self.corners {
+                writeln!(obj, "v {x} {} {z}", y + wall_height).unwrap();
+            }
+            for i in 1..=4 {
+                let j = i % 4 + 1;
+                writeln!(obj, "f {i} {j} {} {}", j + 4, i + 4).unwrap();
+            }
+        }
+        obj
+    }
+}
+
+impl From<[[f32; 3]; 4]> for PlayAreaRect {
+    fn from(corners: [[f32; 3]; 4]) -> Self {
+        PlayAreaRect::new(corners)
+    }
+}
+
+fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+fn transform_point(m: &[[f32; 4]; 3], p: [f32; 3]) -> [f32; 3] {
+    [0, 1, 2].map(|i| m[i][0] * p[0] + m[i][1] * p[1] + m[i][2] * p[2] + m[i][3])
+}
+
+/// Inverse of a rotation and translation.
+fn invert_rigid(m: &[[f32; 4]; 3]) -> [[f32; 4]; 3] {
+    let mut inverse = [[0.0; 4]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            inverse[i][j] = m[j][i];
+        }
+        inverse[i][3] = -(0..3).map(|j| m[j][i] * m[j][3]).sum::<f32>();
+    }
+    inverse
 }
 
 #[cfg(test)]
@@ -176,4 +558,109 @@ mod test {
             ChaperoneCalibrationState::Unknown(2)
         );
     }
+
+    #[test]
+    fn play_area_queries() {
+        use crate::mock::MockRuntime;
+        use crate::Context;
+
+        // An L-shaped room: 4m x 2m with a 2m x 2m extension at one end.
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let setup = context.chaperone_setup().unwrap();
+        setup.set_working_perimeter(&[
+            [0.0, 0.0],
+            [4.0, 0.0],
+            [4.0, 4.0],
+            [2.0, 4.0],
+            [2.0, 2.0],
+            [0.0, 2.0],
+        ]);
+        setup.set_working_play_area_size(2.0, 2.0);
+        setup.commit_working_copy(crate::chaperone_setup::ChaperoneConfigFile::Live);
+        let area = setup.get_live_play_area().unwrap();
+        assert_eq!(area.walls().len(), 6);
+        assert_eq!(area.walls()[1], [[4.0, 0.0], [4.0, 4.0]]);
+
+        assert!(area.contains([1.0, 1.7, 1.0]));
+        assert!(area.contains([3.0, 1.7, 3.0]));
+        assert!(!area.contains([1.0, 1.7, 3.0]));
+        assert_eq!(area.distance_to_edge([1.0, 0.0, 1.5]), Some(0.5));
+        assert_eq!(area.distance_to_edge([1.0, 0.0, 3.0]), Some(-1.0));
+        let wall = area.nearest_wall([3.75, 1.0, 3.0]).unwrap();
+        assert_eq!(
+            (wall.index, wall.point, wall.distance),
+            (1, [4.0, 3.0], 0.25)
+        );
+        assert_eq!(PlayArea::new(vec![]).nearest_wall([0.0; 3]), None);
+
+        let chaperone = context.chaperone().unwrap();
+        let scene = ColorTint {
+            r: 0.2,
+            g: 0.3,
+            b: 0.4,
+            a: 1.0,
+        };
+        chaperone.set_scene_color(scene);
+        assert_eq!(runtime.scene_color(), Some(scene));
+        let (colors, camera) = chaperone.get_bounds_color(2, 0.5);
+        assert_eq!(colors, [runtime.bounds_color(); 2]);
+        assert_eq!(camera, runtime.bounds_color());
+        chaperone.reload_info();
+        let rect = chaperone.get_play_area().unwrap();
+        assert!(rect.contains([0.5, 0.0, -0.5]));
+        assert_eq!(rect.distance_to_edge([0.0, 0.0, 3.0]), Some(-2.0));
+    }
+
+    #[test]
+    fn play_area_rect() {
+        use crate::mock::{identity_pose, MockRuntime};
+        use crate::Context;
+
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        let rect = PlayAreaRect::new([
+            [-1.5, 0.0, 1.0],
+            [1.5, 0.0, 1.0],
+            [1.5, 0.0, -1.0],
+            [-1.5, 0.0, -1.0],
+        ]);
+        assert_eq!(rect.center(), [0.0; 3]);
+        assert_eq!(rect.size(), (3.0, 2.0));
+        assert_eq!(rect.area(), 6.0);
+        assert_eq!(rect.orientation(), 0.0);
+
+        let mut pose = identity_pose();
+        pose.0.mDeviceToAbsoluteTracking.m[0][3] = 1.4;
+        assert!(rect.contains_pose(&pose));
+        pose.0.mDeviceToAbsoluteTracking.m[0][3] = 1.6;
+        assert!(!rect.contains_pose(&pose));
+
+        // The seated origin is 1m along +x and turned 90° to the left.
+        runtime.set_seated_zero_pose_to_standing([
+            [0.0, 0.0, 1.0, 1.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-1.0, 0.0, 0.0, 0.0],
+        ]);
+        let seated = rect.in_universe(TrackingUniverseOrigin::Seated, &system);
+        assert_eq!(seated.corners()[0], [-1.0, 0.0, -2.5]);
+        assert_eq!(seated.center(), [0.0, 0.0, -1.0]);
+        assert_eq!(seated.size(), (3.0, 2.0));
+        assert!((seated.orientation() + std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(
+            rect.in_universe(TrackingUniverseOrigin::RawAndUncalibrated, &system),
+            rect
+        );
+
+        let svg = rect.to_svg();
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-1.75 -1.25 3.5 2.5">"#
+        ));
+        assert!(svg.contains(r#"points="-1.5,1 1.5,1 1.5,-1 -1.5,-1""#));
+        assert_eq!(rect.to_obj(0.0).lines().count(), 5);
+        let obj = rect.to_obj(2.0);
+        assert!(obj.contains("v -1.5 2 1\n"));
+        assert!(obj.ends_with("f 4 1 5 8\n"));
+    }
 }
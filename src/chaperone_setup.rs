@@ -0,0 +1,258 @@
+//! Editing the chaperone configuration, as room setup tools do.
+//!
+//! Changes are made to a working copy, which can be previewed with `show_working_set_preview` and
+//! becomes the live configuration only once committed with `commit_working_copy`.
+
+use std::ffi::CString;
+use std::{mem, ptr};
+
+use openvr_sys as sys;
+
+use crate::ChaperoneSetup;
+
+/// Which configuration file `commit_working_copy` and `reload_from_disk` use.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChaperoneConfigFile {
+    /// The live configuration that is used by the runtime.
+    Live = sys::EChaperoneConfigFile_Live as isize,
+    /// A temporary configuration that is lost when the runtime shuts down.
+    Temp = sys::EChaperoneConfigFile_Temp as isize,
+}
+
+/// A rectangle of collision bounds: four corners in clockwise order, in standing space.
+pub type BoundsQuad = [[f32; 3]; 4];
+
+fn quad_to_sys(quad: &BoundsQuad) -> sys::HmdQuad_t {
+    sys::HmdQuad_t {
+        vCorners: quad.map(|v| sys::HmdVector3_t { v }),
+    }
+}
+
+impl ChaperoneSetup<'_> {
+    /// Saves the working copy to `file`.
+    pub fn commit_working_copy(&self, file: ChaperoneConfigFile) -> bool {
+        unsafe { self.0.CommitWorkingCopy.unwrap()(file as sys::EChaperoneConfigFile) }
+    }
+
+    /// Resets the working copy to the live configuration.
+    ///
+    /// To edit the existing configuration, call this while `Chaperone::get_calibration_state`
+    /// reports no error, then make changes.
+    pub fn revert_working_copy(&self) {
+        unsafe { self.0.RevertWorkingCopy.unwrap()() }
+    }
+
+    /// Discards all state and reloads `file` from disk.
+    pub fn reload_from_disk(&self, file: ChaperoneConfigFile) {
+        unsafe { self.0.ReloadFromDisk.unwrap()(file as sys::EChaperoneConfigFile) }
+    }
+
+    /// Width and depth of the play area in the working copy.
+    pub fn get_working_play_area_size(&self) -> Option<(f32, f32)> {
+        let mut x: f32 = 0.0;
+        let mut z: f32 = 0.0;
+        let is_ok = unsafe { self.0.GetWorkingPlayAreaSize.unwrap()(&mut x, &mut z) };
+        if is_ok {
+            Some((x, z))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the play area in the working copy. It is centered on the origin of standing space.
+    pub fn set_working_play_area_size(&self, x: f32, z: f32) {
+        unsafe { self.0.SetWorkingPlayAreaSize.unwrap()(x, z) }
+    }
+
+    /// The 4 corners of the play area in the working copy, clockwise, on the floor.
+    pub fn get_working_play_area_rect(&self) -> Option<BoundsQuad> {
+        let mut r = quad_to_sys(&[[0.0; 3]; 4]);
+        let is_ok = unsafe { self.0.GetWorkingPlayAreaRect.unwrap()(&mut r) };
+        if is_ok {
+            Some(r.vCorners.map(|x| x.v))
+        } else {
+            None
+        }
+    }
+
+    /// Collision bounds in the working copy.
+    pub fn get_working_collision_bounds(&self) -> Option<Vec<BoundsQuad>> {
+        get_bounds(|buffer, count| unsafe {
+            self.0.GetWorkingCollisionBoundsInfo.unwrap()(buffer, count)
+        })
+    }
+
+    /// Collision bounds in the live configuration.
+    pub fn get_live_collision_bounds(&self) -> Option<Vec<BoundsQuad>> {
+        get_bounds(|buffer, count| unsafe {
+            self.0.GetLiveCollisionBoundsInfo.unwrap()(buffer, count)
+        })
+    }
+
+    /// Sets the collision bounds in the working copy. The height of the quads is ignored.
+    pub fn set_working_collision_bounds(&self, quads: &[BoundsQuad]) {
+        let mut quads: Vec<_> = quads.iter().map(quad_to_sys).collect();
+        unsafe {
+            self.0.SetWorkingCollisionBoundsInfo.unwrap()(quads.as_mut_ptr(), quads.len() as u32)
+        }
+    }
+
+    /// Sets the collision bounds in the working copy from a polygon of `[x, z]` points on the
+    /// floor.
+    pub fn set_working_perimeter(&self, points: &[[f32; 2]]) {
+        let mut points: Vec<_> = points.iter().map(|&v| sys::HmdVector2_t { v }).collect();
+        unsafe { self.0.SetWorkingPerimeter.unwrap()(points.as_mut_ptr(), points.len() as u32) }
+    }
+
+    /// Preferred seated position in the working copy, relative to raw tracking space.
+    pub fn get_working_seated_zero_pose_to_raw_tracking_pose(&self) -> Option<[[f32; 4]; 3]> {
+        get_matrix(|x| unsafe { self.0.GetWorkingSeatedZeroPoseToRawTrackingPose.unwrap()(x) })
+    }
+
+    /// Standing origin in the working copy, relative to raw tracking space.
+    pub fn get_working_standing_zero_pose_to_raw_tracking_pose(&self) -> Option<[[f32; 4]; 3]> {
+        get_matrix(|x| unsafe { self.0.GetWorkingStandingZeroPoseToRawTrackingPose.unwrap()(x) })
+    }
+
+    /// Preferred seated position in the live configuration, relative to raw tracking space.
+    pub fn get_live_seated_zero_pose_to_raw_tracking_pose(&self) -> Option<[[f32; 4]; 3]> {
+        get_matrix(|x| unsafe { self.0.GetLiveSeatedZeroPoseToRawTrackingPose.unwrap()(x) })
+    }
+
+    pub fn set_working_seated_zero_pose_to_raw_tracking_pose(&self, pose: &[[f32; 4]; 3]) {
+        let mut pose = sys::HmdMatrix34_t { m: *pose };
+        unsafe { self.0.SetWorkingSeatedZeroPoseToRawTrackingPose.unwrap()(&mut pose) }
+    }
+
+    pub fn set_working_standing_zero_pose_to_raw_tracking_pose(&self, pose: &[[f32; 4]; 3]) {
+        let mut pose = sys::HmdMatrix34_t { m: *pose };
+        unsafe { self.0.SetWorkingStandingZeroPoseToRawTrackingPose.unwrap()(&mut pose) }
+    }
+
+    /// The live configuration, in the runtime's text format, for `import_from_buffer_to_working`.
+    pub fn export_live_to_buffer(&self) -> Option<String> {
+        let mut len = 0;
+        if !unsafe { self.0.ExportLiveToBuffer.unwrap()(ptr::null_mut(), &mut len) } && len == 0 {
+            return None;
+        }
+        let mut buffer = vec![0u8; len as usize];
+        if !unsafe { self.0.ExportLiveToBuffer.unwrap()(buffer.as_mut_ptr() as *mut _, &mut len) } {
+            return None;
+        }
+        buffer.truncate(buffer.iter().position(|&x| x == 0).unwrap_or(buffer.len()));
+        String::from_utf8(buffer).ok()
+    }
+
+    /// Loads a configuration exported by `export_live_to_buffer` into the working copy. With
+    /// `bounds_only`, only the collision bounds are taken from it.
+    ///
+    /// Returns `false` if the runtime rejects the data, or if it contains a nul byte.
+    pub fn import_from_buffer_to_working(&self, buffer: &str, bounds_only: bool) -> bool {
+        let Ok(buffer) = CString::new(buffer) else {
+            return false;
+        };
+        let flags = if bounds_only {
+            sys::EChaperoneImportFlags_EChaperoneImport_BoundsOnly
+        } else {
+            0
+        };
+        unsafe { self.0.ImportFromBufferToWorking.unwrap()(buffer.as_ptr() as *mut _, flags) }
+    }
+
+    /// Shows the working copy in the compositor, to preview changes before committing them.
+    pub fn show_working_set_preview(&self) {
+        unsafe { self.0.ShowWorkingSetPreview.unwrap()() }
+    }
+
+    pub fn hide_working_set_preview(&self) {
+        unsafe { self.0.HideWorkingSetPreview.unwrap()() }
+    }
+
+    /// Tells the tracking system that room setup is about to begin, so it can make last adjustments
+    /// first. The room may appear to jump for a user in the headset.
+    pub fn room_setup_starting(&self) {
+        unsafe { self.0.RoomSetupStarting.unwrap()() }
+    }
+}
+
+/// Calls a function returning collision bounds twice: once for the count, once for the quads.
+fn get_bounds(mut f: impl FnMut(*mut sys::HmdQuad_t, *mut u32) -> bool) -> Option<Vec<BoundsQuad>> {
+    let mut count = 0;
+    f(ptr::null_mut(), &mut count);
+    let mut quads = vec![quad_to_sys(&[[0.0; 3]; 4]); count as usize];
+    if !f(quads.as_mut_ptr(), &mut count) {
+        return None;
+    }
+    quads.truncate(count as usize);
+    Some(quads.iter().map(|x| x.vCorners.map(|x| x.v)).collect())
+}
+
+fn get_matrix(f: impl FnOnce(*mut sys::HmdMatrix34_t) -> bool) -> Option<[[f32; 4]; 3]> {
+    let mut x: sys::HmdMatrix34_t = unsafe { mem::zeroed() };
+    if f(&mut x) {
+        Some(x.m)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockRuntime;
+    use crate::Context;
+
+    #[test]
+    fn working_copy() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let chaperone = context.chaperone().unwrap();
+        let setup = context.chaperone_setup().unwrap();
+        let quad = [
+            [-1.0, 0.0, -1.0],
+            [1.0, 0.0, -1.0],
+            [1.0, 2.0, -1.0],
+            [-1.0, 2.0, -1.0],
+        ];
+        let mut seated = [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 1.2],
+            [0.0, 0.0, 1.0, 0.0],
+        ];
+
+        setup.set_working_play_area_size(3.0, 2.0);
+        setup.set_working_collision_bounds(&[quad]);
+        setup.set_working_seated_zero_pose_to_raw_tracking_pose(&seated);
+        setup.show_working_set_preview();
+        assert!(runtime.working_set_preview_visible());
+        assert_eq!(setup.get_working_play_area_size(), Some((3.0, 2.0)));
+        assert_eq!(
+            setup.get_working_play_area_rect().unwrap()[0],
+            [-1.5, 0.0, 1.0]
+        );
+        assert_eq!(setup.get_working_collision_bounds(), Some(vec![quad]));
+        // Nothing is live until committed.
+        assert_eq!(chaperone.get_play_area_size(), None);
+        assert_eq!(setup.get_live_collision_bounds(), Some(vec![]));
+
+        assert!(setup.commit_working_copy(ChaperoneConfigFile::Live));
+        assert_eq!(chaperone.get_play_area_size(), Some((3.0, 2.0)));
+        assert_eq!(setup.get_live_collision_bounds(), Some(vec![quad]));
+        assert_eq!(
+            setup.get_live_seated_zero_pose_to_raw_tracking_pose(),
+            Some(seated)
+        );
+
+        let exported = setup.export_live_to_buffer().unwrap();
+        seated[1][3] = 0.0;
+        setup.set_working_seated_zero_pose_to_raw_tracking_pose(&seated);
+        setup.set_working_perimeter(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]);
+        assert_eq!(setup.get_working_collision_bounds().unwrap().len(), 3);
+        setup.revert_working_copy();
+        assert_eq!(setup.get_working_collision_bounds(), Some(vec![quad]));
+        setup.set_working_collision_bounds(&[]);
+        assert!(setup.import_from_buffer_to_working(&exported, true));
+        assert_eq!(setup.get_working_collision_bounds(), Some(vec![quad]));
+        assert!(!setup.import_from_buffer_to_working("not a chaperone", false));
+    }
+}
@@ -0,0 +1,352 @@
+//! Driving the compositor one frame at a time, with each call made in the order it expects.
+//!
+//! A frame starts with `FrameLoop::begin`, which waits for poses, and ends with the right eye
+//! submitted, after which the compositor is handed off with `post_present_handoff`. Submitting the
+//! right eye before the left, or starting a frame before the last one ended, does not compile.
+//!
+//! Dropping a frame before its right eye is submitted counts as `skip`, so a frame abandoned part
+//! way, e.g. by a failed submission or an early return while rendering, is still handed off and the
+//! next `begin` never stalls.
+
+use std::{error, fmt};
+
+use super::texture::Bounds;
+use super::{CompositorError, Texture, WaitPoses};
+use crate::{Compositor, Eye};
+
+/// The step of a frame a `FrameError` comes from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FrameStep {
+    WaitGetPoses,
+    SubmitExplicitTimingData,
+    Submit(Eye),
+}
+
+impl fmt::Display for FrameStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            FrameStep::WaitGetPoses => "wait_get_poses",
+            FrameStep::SubmitExplicitTimingData => "submit_explicit_timing_data",
+            FrameStep::Submit(Eye::Left) => "submit left eye",
+            FrameStep::Submit(Eye::Right) => "submit right eye",
+        })
+    }
+}
+
+/// A `CompositorError` and the step of the frame that returned it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FrameError {
+    pub step: FrameStep,
+    pub error: CompositorError,
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.step, self.error)
+    }
+}
+
+impl error::Error for FrameError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Runs frames on a `Compositor`, one at a time.
+///
+/// ```no_run
+/// # let context = openvr::init(openvr::ApplicationType::Scene).unwrap();
+/// # use openvr::compositor::Texture;
+/// # let (left, right): (Texture, Texture) = unimplemented!();
+/// use openvr::compositor::FrameLoop;
+///
+/// let compositor = context.compositor().unwrap();
+/// let mut frames = FrameLoop::new(&compositor);
+/// loop {
+///     let frame = frames.begin()?;
+///     // Render both eyes using `frame.poses().render`, then:
+///     let frame = unsafe { frame.submit_left(&left, None, None)? };
+///     unsafe { frame.submit_right(&right, None, None)? };
+/// }
+/// # Ok::<(), openvr::compositor::FrameError>(())
+/// ```
+pub struct FrameLoop<'a> {
+    compositor: &'a Compositor<'a>,
+    explicit_timing: bool,
+    frame_count: u64,
+}
+
+impl<'a> FrameLoop<'a> {
+    pub fn new(compositor: &'a Compositor<'a>) -> Self {
+        FrameLoop {
+            compositor,
+            explicit_timing: false,
+            frame_count: 0,
+        }
+    }
+
+    /// A frame loop in explicit timing mode, for Vulkan and D3D12. See
+    /// `Compositor::set_explicit_timing_mode`.
+    ///
+    /// Each frame submits its explicit timing data before the left eye, unless
+    /// `Frame::submit_explicit_timing_data` was called earlier, which should be just before the
+    /// frame's first submission to the GPU queue. Implicit timing is restored when the loop is
+    /// dropped.
+    pub fn with_explicit_timing(compositor: &'a Compositor<'a>) -> Self {
+        compositor.set_explicit_timing_mode(true);
+        FrameLoop {
+            compositor,
+            explicit_timing: true,
+            frame_count: 0,
+        }
+    }
+
+    pub fn explicit_timing(&self) -> bool {
+        self.explicit_timing
+    }
+
+    /// Number of frames begun.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Waits for the poses of the next frame, with `Compositor::wait_get_poses`.
+    pub fn begin(&mut self) -> Result<Frame<'_, 'a>, FrameError> {
+        let poses = self
+            .compositor
+            .wait_get_poses()
+            .map_err(|error| FrameError {
+                step: FrameStep::WaitGetPoses,
+                error,
+            })?;
+        self.frame_count += 1;
+        Ok(Frame {
+            frame_loop: self,
+            poses,
+            timing_submitted: false,
+            handed_off: false,
+        })
+    }
+}
+
+impl Drop for FrameLoop<'_> {
+    fn drop(&mut self) {
+        if self.explicit_timing {
+            self.compositor.set_explicit_timing_mode(false);
+        }
+    }
+}
+
+/// A frame whose poses have been read and whose eyes are yet to be submitted.
+///
+/// Dropping it skips the frame, like `skip`.
+#[must_use = "a frame should be submitted or skipped"]
+pub struct Frame<'l, 'a> {
+    frame_loop: &'l mut FrameLoop<'a>,
+    poses: WaitPoses,
+    timing_submitted: bool,
+    handed_off: bool,
+}
+
+impl<'l, 'a> Frame<'l, 'a> {
+    pub fn poses(&self) -> &WaitPoses {
+        &self.poses
+    }
+
+    /// Submits explicit timing data for this frame, if the loop uses explicit timing and it hasn't
+    /// been submitted yet.
+    pub fn submit_explicit_timing_data(&mut self) -> Result<(), FrameError> {
+        if self.frame_loop.explicit_timing && !self.timing_submitted {
+            self.timing_submitted = true;
+            self.frame_loop
+                .compositor
+                .submit_explicit_timing_data()
+                .map_err(|error| FrameError {
+                    step: FrameStep::SubmitExplicitTimingData,
+                    error,
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Submits the left eye. Arguments are as for `Compositor::submit`.
+    ///
+    /// On error the frame is skipped.
+    ///
+    /// # Safety
+    ///
+    /// As for `Compositor::submit`.
+    pub unsafe fn submit_left(
+        mut self,
+        texture: &Texture,
+        bounds: Option<&Bounds>,
+        pose: Option<[[f32; 4]; 3]>,
+    ) -> Result<RightEyePending<'l, 'a>, FrameError> {
+        self.submit_explicit_timing_data()?;
+        self.submit(Eye::Left, texture, bounds, pose)?;
+        Ok(RightEyePending(self))
+    }
+
+    /// Ends the frame without submitting anything. The compositor keeps showing the last frame,
+    /// reprojected.
+    pub fn skip(self) {}
+
+    unsafe fn submit(
+        &self,
+        eye: Eye,
+        texture: &Texture,
+        bounds: Option<&Bounds>,
+        pose: Option<[[f32; 4]; 3]>,
+    ) -> Result<(), FrameError> {
+        self.frame_loop
+            .compositor
+            .submit(eye, texture, bounds, pose)
+            .map_err(|error| FrameError {
+                step: FrameStep::Submit(eye),
+                error,
+            })
+    }
+
+    fn hand_off(&mut self) {
+        if !self.handed_off {
+            self.handed_off = true;
+            self.frame_loop.compositor.post_present_handoff();
+        }
+    }
+}
+
+impl Drop for Frame<'_, '_> {
+    fn drop(&mut self) {
+        self.hand_off();
+    }
+}
+
+/// A frame whose left eye has been submitted.
+///
+/// Dropping it skips the right eye, like `skip`.
+#[must_use = "the right eye should be submitted or the frame skipped"]
+pub struct RightEyePending<'l, 'a>(Frame<'l, 'a>);
+
+impl RightEyePending<'_, '_> {
+    pub fn poses(&self) -> &WaitPoses {
+        self.0.poses()
+    }
+
+    /// Submits the right eye, then hands the frame off to the compositor with
+    /// `Compositor::post_present_handoff`.
+    ///
+    /// # Safety
+    ///
+    /// As for `Compositor::submit`.
+    pub unsafe fn submit_right(
+        mut self,
+        texture: &Texture,
+        bounds: Option<&Bounds>,
+        pose: Option<[[f32; 4]; 3]>,
+    ) -> Result<(), FrameError> {
+        self.0.submit(Eye::Right, texture, bounds, pose)?;
+        self.0.hand_off();
+        Ok(())
+    }
+
+    /// Ends the frame with only the left eye submitted.
+    pub fn skip(self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compositor::texture::{ColorSpace, Handle};
+    use crate::mock::{MockCompositorCall, MockRuntime};
+    use crate::sys;
+    use crate::Context;
+
+    const TEXTURE: Texture = Texture {
+        handle: Handle::OpenGLTexture(1),
+        color_space: ColorSpace::Auto,
+    };
+
+    #[test]
+    fn ordering_and_errors() {
+        use MockCompositorCall::*;
+
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let compositor = context.compositor().unwrap();
+        let mut frames = FrameLoop::with_explicit_timing(&compositor);
+        assert!(runtime.explicit_timing());
+
+        let frame = frames.begin().unwrap();
+        let frame = unsafe { frame.submit_left(&TEXTURE, None, None) }.unwrap();
+        unsafe { frame.submit_right(&TEXTURE, None, None) }.unwrap();
+        assert_eq!(
+            runtime.compositor_calls(),
+            [
+                WaitGetPoses,
+                SubmitExplicitTimingData,
+                Submit(Eye::Left),
+                Submit(Eye::Right),
+                PostPresentHandoff
+            ]
+        );
+
+        // A failed submission reports its step, and the frame is still handed off.
+        runtime.clear_compositor_calls();
+        let mut frame = frames.begin().unwrap();
+        frame.submit_explicit_timing_data().unwrap();
+        let frame = unsafe { frame.submit_left(&TEXTURE, None, None) }.unwrap();
+        runtime.fail_next_submit(sys::EVRCompositorError_VRCompositorError_InvalidTexture);
+        let error = unsafe { frame.submit_right(&TEXTURE, None, None) }.unwrap_err();
+        assert_eq!(error.step, FrameStep::Submit(Eye::Right));
+        assert_eq!(
+            error.error,
+            crate::compositor::compositor_error::INVALID_TEXTURE
+        );
+        assert_eq!(error.to_string(), "submit right eye: INVALID_TEXTURE");
+        assert_eq!(
+            runtime.compositor_calls(),
+            [
+                WaitGetPoses,
+                SubmitExplicitTimingData,
+                Submit(Eye::Left),
+                Submit(Eye::Right),
+                PostPresentHandoff
+            ]
+        );
+
+        runtime.clear_compositor_calls();
+        frames.begin().unwrap().skip();
+        assert_eq!(
+            runtime.compositor_calls(),
+            [WaitGetPoses, PostPresentHandoff]
+        );
+
+        // So is a frame whose left eye fails.
+        runtime.clear_compositor_calls();
+        runtime.fail_next_submit(sys::EVRCompositorError_VRCompositorError_InvalidTexture);
+        let error = unsafe { frames.begin().unwrap().submit_left(&TEXTURE, None, None) }
+            .err()
+            .unwrap();
+        assert_eq!(error.step, FrameStep::Submit(Eye::Left));
+        assert_eq!(
+            runtime.compositor_calls(),
+            [
+                WaitGetPoses,
+                SubmitExplicitTimingData,
+                Submit(Eye::Left),
+                PostPresentHandoff
+            ]
+        );
+
+        // Dropping a frame skips it.
+        runtime.clear_compositor_calls();
+        drop(frames.begin().unwrap());
+        assert_eq!(
+            runtime.compositor_calls(),
+            [WaitGetPoses, PostPresentHandoff]
+        );
+        assert_eq!(frames.frame_count(), 5);
+        drop(frames);
+        assert!(!runtime.explicit_timing());
+    }
+}
@@ -13,13 +13,19 @@ use std::{error, fmt, mem, ptr};
 
 use openvr_sys::{self as sys, Compositor_FrameTiming};
 
+pub mod frame;
+pub mod telemetry;
 pub mod texture;
+pub mod timing;
 
+pub use self::frame::{Frame, FrameError, FrameLoop, FrameStep, RightEyePending};
+pub use self::telemetry::{MetricSummary, TimingMetric, TimingRecorder, TimingReport};
 pub use self::texture::Texture;
+pub use self::timing::{CumulativeStats, FrameCounts, FrameTiming, ReprojectionFlags};
 
 use super::*;
 
-impl Compositor {
+impl Compositor<'_> {
     pub fn vulkan_instance_extensions_required(&self) -> Vec<CString> {
         let temp = match unsafe {
             get_string(|ptr, n| self.0.GetVulkanInstanceExtensionsRequired.unwrap()(ptr, n))
@@ -55,6 +61,17 @@ impl Compositor {
         unsafe { self.0.SetTrackingSpace.unwrap()(origin as sys::ETrackingUniverseOrigin) }
     }
 
+    /// Tracking space returned by WaitGetPoses
+    pub fn tracking_space(&self) -> TrackingUniverseOrigin {
+        match unsafe { self.0.GetTrackingSpace.unwrap()() } {
+            sys::ETrackingUniverseOrigin_TrackingUniverseSeated => TrackingUniverseOrigin::Seated,
+            sys::ETrackingUniverseOrigin_TrackingUniverseStanding => {
+                TrackingUniverseOrigin::Standing
+            }
+            _ => TrackingUniverseOrigin::RawAndUncalibrated,
+        }
+    }
+
     /// Block until a few milliseconds before the next vsync, then return poses for the next step of rendering and game
     /// logic.
     ///
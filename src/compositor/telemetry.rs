@@ -0,0 +1,426 @@
+//! Collecting frame timing over a session and summarizing it, e.g. for automated performance tests.
+//!
+//! A `TimingRecorder` samples `Compositor::frame_timing` once per frame and keeps every frame it
+//! sees. Its `TimingReport` counts dropped and reprojected frames and gives percentiles of the main
+//! CPU and GPU times, and can be exported as CSV or JSON along with the per-frame data.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use super::FrameTiming;
+use crate::Compositor;
+
+/// Frames of history kept by the runtime.
+const HISTORY: u32 = 128;
+
+/// A per-frame time summarized by `TimingReport`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TimingMetric {
+    /// Time from new poses being ready to the second eye being submitted.
+    ApplicationCpu,
+    /// GPU time of the application, before and after submitting.
+    ApplicationGpu,
+    CompositorCpu,
+    CompositorGpu,
+    /// GPU time from the start of the frame to the end of the compositor's work.
+    TotalGpu,
+    /// Time between calls to `wait_get_poses`.
+    FrameInterval,
+}
+
+impl TimingMetric {
+    pub const ALL: [TimingMetric; 6] = [
+        TimingMetric::ApplicationCpu,
+        TimingMetric::ApplicationGpu,
+        TimingMetric::CompositorCpu,
+        TimingMetric::CompositorGpu,
+        TimingMetric::TotalGpu,
+        TimingMetric::FrameInterval,
+    ];
+
+    /// Name used for the metric in exported reports.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            TimingMetric::ApplicationCpu => "application_cpu",
+            TimingMetric::ApplicationGpu => "application_gpu",
+            TimingMetric::CompositorCpu => "compositor_cpu",
+            TimingMetric::CompositorGpu => "compositor_gpu",
+            TimingMetric::TotalGpu => "total_gpu",
+            TimingMetric::FrameInterval => "frame_interval",
+        }
+    }
+
+    /// The metric's value for one frame.
+    pub fn of(&self, timing: &FrameTiming) -> Duration {
+        match *self {
+            TimingMetric::ApplicationCpu => timing
+                .new_frame_ready
+                .saturating_sub(timing.new_poses_ready),
+            TimingMetric::ApplicationGpu => timing.pre_submit_gpu + timing.post_submit_gpu,
+            TimingMetric::CompositorCpu => timing.compositor_render_cpu,
+            TimingMetric::CompositorGpu => timing.compositor_render_gpu,
+            TimingMetric::TotalGpu => timing.total_render_gpu,
+            TimingMetric::FrameInterval => timing.client_frame_interval,
+        }
+    }
+}
+
+/// Distribution of a `TimingMetric` over the frames of a `TimingReport`. Percentiles use the
+/// nearest rank.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MetricSummary {
+    pub mean: Duration,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl MetricSummary {
+    fn new(mut values: Vec<Duration>) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        values.sort();
+        let percentile = |p: usize| values[(values.len() * p).div_ceil(100).max(1) - 1];
+        Some(MetricSummary {
+            mean: values.iter().sum::<Duration>() / values.len() as u32,
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: *values.last().unwrap(),
+        })
+    }
+}
+
+/// Summary of the frames seen by a `TimingRecorder`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimingReport {
+    /// Number of frames recorded.
+    pub frames: usize,
+    /// Frames between recorded ones that weren't sampled before they left the runtime's history.
+    pub missing_frames: u32,
+    /// Number of times a frame was late and the previous one was scanned out again.
+    pub dropped_frames: u32,
+    /// Frames that were reprojected because the application missed its CPU or GPU deadline.
+    pub reprojected_frames: usize,
+    /// Frames presented on another vsync than the one they were predicted for.
+    pub mispresented_frames: usize,
+    pub motion_smoothed_frames: usize,
+    /// Time from the first recorded frame to the last.
+    pub duration: Duration,
+    /// Summary of each metric in `TimingMetric::ALL`, empty if no frames were recorded.
+    pub metrics: Vec<(TimingMetric, MetricSummary)>,
+}
+
+impl TimingReport {
+    pub fn metric(&self, metric: TimingMetric) -> Option<&MetricSummary> {
+        self.metrics.iter().find(|x| x.0 == metric).map(|x| &x.1)
+    }
+
+    /// Writes the metrics as CSV, one row per metric with times in milliseconds.
+    pub fn write_csv(&self, w: &mut impl Write) -> io::Result<()> {
+        writeln!(w, "metric,mean_ms,p50_ms,p90_ms,p99_ms,max_ms")?;
+        for (metric, x) in &self.metrics {
+            writeln!(
+                w,
+                "{},{},{},{},{},{}",
+                metric.name(),
+                ms(x.mean),
+                ms(x.p50),
+                ms(x.p90),
+                ms(x.p99),
+                ms(x.max)
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes the whole report as a JSON object, with times in milliseconds.
+    pub fn write_json(&self, w: &mut impl Write) -> io::Result<()> {
+        let mut metrics = String::new();
+        for (i, (metric, x)) in self.metrics.iter().enumerate() {
+            let separator = if i + 1 < self.metrics.len() { "," } else { "" };
+            writeln!(
+                metrics,
+                concat!(
+                    r#"    "{}": {{"mean_ms": {}, "p50_ms": {}, "p90_ms": {}, "#,
+                    r#""p99_ms": {}, "max_ms": {}}}{}"#
+                ),
+                metric.name(),
+                ms(x.mean),
+                ms(x.p50),
+                ms(x.p90),
+                ms(x.p99),
+                ms(x.max),
+                separator
+            )
+            .unwrap();
+        }
+        writeln!(w, "{{")?;
+        writeln!(w, r#"  "frames": {},"#, self.frames)?;
+        writeln!(w, r#"  "missing_frames": {},"#, self.missing_frames)?;
+        writeln!(w, r#"  "dropped_frames": {},"#, self.dropped_frames)?;
+        writeln!(w, r#"  "reprojected_frames": {},"#, self.reprojected_frames)?;
+        writeln!(
+            w,
+            r#"  "mispresented_frames": {},"#,
+            self.mispresented_frames
+        )?;
+        writeln!(
+            w,
+            r#"  "motion_smoothed_frames": {},"#,
+            self.motion_smoothed_frames
+        )?;
+        writeln!(w, r#"  "duration_ms": {},"#, ms(self.duration))?;
+        write!(w, "  \"metrics\": {{\n{metrics}  }}\n}}\n")
+    }
+}
+
+/// Milliseconds, rounded to the microsecond.
+fn ms(x: Duration) -> f64 {
+    (x.as_nanos() as f64 / 1000.0).round() / 1000.0
+}
+
+/// Records the timing of every frame, for a `TimingReport` at the end of a session.
+///
+/// ```no_run
+/// # let context = openvr::init(openvr::ApplicationType::Scene).unwrap();
+/// use openvr::compositor::TimingRecorder;
+///
+/// let compositor = context.compositor().unwrap();
+/// let mut recorder = TimingRecorder::new();
+/// # let running = || false;
+/// while running() {
+///     // Render and submit a frame, then:
+///     recorder.sample(&compositor);
+/// }
+/// let report = recorder.report();
+/// println!("{} of {} frames reprojected", report.reprojected_frames, report.frames);
+/// report.write_json(&mut std::fs::File::create("timing.json").unwrap()).unwrap();
+/// recorder.write_frames_csv(&mut std::fs::File::create("frames.csv").unwrap()).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TimingRecorder {
+    frames: Vec<FrameTiming>,
+}
+
+impl TimingRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the frames completed since the last call. Returns how many were recorded.
+    ///
+    /// The latest frame is still being presented, so its timing is incomplete; it is recorded by
+    /// the next call. Up to 128 frames are kept by the runtime, so frames are only missed if this
+    /// is called less often than that.
+    pub fn sample(&mut self, compositor: &Compositor) -> usize {
+        let Some(latest) = compositor.frame_timing(1) else {
+            return 0;
+        };
+        match self.last_index() {
+            Some(last) if latest.frame_index <= last => 0,
+            Some(last) if latest.frame_index > last + 1 => {
+                let count = (latest.frame_index - last + 1).min(HISTORY);
+                compositor
+                    .frame_timings(count)
+                    .into_iter()
+                    .filter(|x| x.frame_index <= latest.frame_index && self.record(*x))
+                    .count()
+            }
+            _ => self.record(latest) as usize,
+        }
+    }
+
+    /// Records a frame, unless it is not newer than the last one recorded.
+    pub fn record(&mut self, timing: FrameTiming) -> bool {
+        if self.last_index().is_some_and(|x| timing.frame_index <= x) {
+            return false;
+        }
+        self.frames.push(timing);
+        true
+    }
+
+    fn last_index(&self) -> Option<u32> {
+        self.frames.last().map(|x| x.frame_index)
+    }
+
+    /// Frames recorded so far, oldest first.
+    pub fn frames(&self) -> &[FrameTiming] {
+        &self.frames
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    pub fn report(&self) -> TimingReport {
+        let frames = &self.frames;
+        TimingReport {
+            frames: frames.len(),
+            missing_frames: frames
+                .windows(2)
+                .map(|x| x[1].frame_index - x[0].frame_index - 1)
+                .sum(),
+            dropped_frames: frames.iter().map(|x| x.dropped_frames).sum(),
+            reprojected_frames: frames
+                .iter()
+                .filter(|x| x.reprojection.reprojected())
+                .count(),
+            mispresented_frames: frames.iter().filter(|x| x.mispresents > 0).count(),
+            motion_smoothed_frames: frames
+                .iter()
+                .filter(|x| x.reprojection.motion_smoothing())
+                .count(),
+            duration: match (frames.first(), frames.last()) {
+                (Some(first), Some(last)) => last.system_time.saturating_sub(first.system_time),
+                _ => Duration::ZERO,
+            },
+            metrics: TimingMetric::ALL
+                .into_iter()
+                .filter_map(|metric| {
+                    MetricSummary::new(frames.iter().map(|x| metric.of(x)).collect())
+                        .map(|x| (metric, x))
+                })
+                .collect(),
+        }
+    }
+
+    /// Writes every recorded frame as CSV, with times in milliseconds: the frame index and time,
+    /// the presented, dropped and reprojected counts and flags, then each metric in
+    /// `TimingMetric::ALL`.
+    pub fn write_frames_csv(&self, w: &mut impl Write) -> io::Result<()> {
+        write!(
+            w,
+            "frame_index,system_time_ms,presents,dropped_frames,reprojected,motion_smoothing"
+        )?;
+        for metric in TimingMetric::ALL {
+            write!(w, ",{}_ms", metric.name())?;
+        }
+        writeln!(w)?;
+        for x in &self.frames {
+            write!(
+                w,
+                "{},{},{},{},{},{}",
+                x.frame_index,
+                ms(x.system_time),
+                x.presents,
+                x.dropped_frames,
+                x.reprojection.reprojected() as u8,
+                x.reprojection.motion_smoothing() as u8
+            )?;
+            for metric in TimingMetric::ALL {
+                write!(w, ",{}", ms(metric.of(x)))?;
+            }
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+    use crate::mock::MockRuntime;
+    use crate::sys;
+    use crate::Context;
+
+    fn timing(frame_index: u32) -> sys::Compositor_FrameTiming {
+        sys::Compositor_FrameTiming {
+            m_nSize: mem::size_of::<sys::Compositor_FrameTiming>() as u32,
+            m_nFrameIndex: frame_index,
+            m_nNumFramePresents: 1,
+            m_flSystemTimeInSeconds: frame_index as f64 * 0.0125,
+            m_flPreSubmitGpuMs: frame_index as f32,
+            m_flPostSubmitGpuMs: 0.5,
+            m_flClientFrameIntervalMs: 12.5,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sampling_and_report() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let compositor = context.compositor().unwrap();
+        let mut recorder = TimingRecorder::new();
+        assert_eq!(recorder.sample(&compositor), 0);
+
+        for i in 1..=3 {
+            runtime.push_frame_timing(timing(i));
+        }
+        // Recording starts at the latest complete frame; frame 3 is still in progress.
+        assert_eq!(recorder.sample(&compositor), 1);
+        assert_eq!(recorder.sample(&compositor), 0);
+        let mut late = timing(4);
+        late.m_nNumDroppedFrames = 2;
+        late.m_nNumMisPresented = 1;
+        late.m_nReprojectionFlags = 0x01 | 0x08;
+        runtime.push_frame_timing(late);
+        runtime.push_frame_timing(timing(5));
+        assert_eq!(recorder.sample(&compositor), 2);
+        // Frame 5 was in progress, and 72 more frames are pushed than the history holds.
+        for i in 6..=205 {
+            runtime.push_frame_timing(timing(i));
+        }
+        assert_eq!(recorder.sample(&compositor), 127);
+        let indices: Vec<_> = recorder.frames().iter().map(|x| x.frame_index).collect();
+        assert_eq!(indices[..4], [2, 3, 4, 78]);
+        assert_eq!(*indices.last().unwrap(), 204);
+
+        let report = recorder.report();
+        assert_eq!(report.frames, 130);
+        assert_eq!(report.missing_frames, 73);
+        assert_eq!(report.dropped_frames, 2);
+        assert_eq!(
+            (
+                report.reprojected_frames,
+                report.mispresented_frames,
+                report.motion_smoothed_frames
+            ),
+            (1, 1, 1)
+        );
+        assert_eq!(report.duration, Duration::from_millis(2525));
+        let gpu = report.metric(TimingMetric::ApplicationGpu).unwrap();
+        assert_eq!(gpu.p50, Duration::from_micros(139_500));
+        assert_eq!(gpu.p99, Duration::from_micros(203_500));
+        assert_eq!(gpu.max, Duration::from_micros(204_500));
+        assert_eq!(
+            report.metric(TimingMetric::FrameInterval).unwrap().mean,
+            Duration::from_micros(12_500)
+        );
+
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 7);
+        assert!(csv.contains("\napplication_gpu,"));
+        assert!(csv.contains("\nframe_interval,12.5,12.5,12.5,12.5,12.5\n"));
+
+        let mut json = Vec::new();
+        report.write_json(&mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.starts_with("{\n  \"frames\": 130,\n"));
+        assert!(json.contains(concat!(
+            r#"    "frame_interval": {"mean_ms": 12.5, "p50_ms": 12.5, "p90_ms": 12.5, "#,
+            r#""p99_ms": 12.5, "max_ms": 12.5}"#
+        )));
+        assert!(json.ends_with("  }\n}\n"));
+
+        let mut frames = Vec::new();
+        recorder.write_frames_csv(&mut frames).unwrap();
+        let frames = String::from_utf8(frames).unwrap();
+        let lines: Vec<_> = frames.lines().collect();
+        assert_eq!(lines.len(), 131);
+        assert_eq!(lines[3], "4,50,1,2,1,1,0,4.5,0,0,0,12.5");
+
+        recorder.clear();
+        let empty = recorder.report();
+        assert_eq!((empty.frames, empty.duration), (0, Duration::ZERO));
+        assert!(empty.metrics.is_empty());
+    }
+}
@@ -0,0 +1,380 @@
+//! Frame timing and performance statistics reported by the compositor.
+
+use std::mem;
+use std::time::Duration;
+
+use openvr_sys as sys;
+
+use crate::{Compositor, TrackedDevicePose};
+
+const REPROJECTION_REASON_CPU: u32 = 0x01;
+const REPROJECTION_REASON_GPU: u32 = 0x02;
+const REPROJECTION_ASYNC: u32 = 0x04;
+const REPROJECTION_MOTION: u32 = 0x08;
+const PREDICTION_MASK: u32 = 0xF0;
+const THROTTLE_MASK: u32 = 0xF00;
+
+/// `Compositor_FrameTiming::m_nReprojectionFlags`, decoded.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct ReprojectionFlags(pub u32);
+
+impl ReprojectionFlags {
+    /// Reprojection was applied because the application missed its CPU deadline.
+    pub fn cpu(&self) -> bool {
+        self.0 & REPROJECTION_REASON_CPU != 0
+    }
+
+    /// Reprojection was applied because the application missed its GPU deadline.
+    pub fn gpu(&self) -> bool {
+        self.0 & REPROJECTION_REASON_GPU != 0
+    }
+
+    /// Whether the previous frame was reused, for either reason.
+    pub fn reprojected(&self) -> bool {
+        self.cpu() || self.gpu()
+    }
+
+    /// Async reprojection is enabled. This doesn't mean it was applied to this frame.
+    pub fn async_reprojection(&self) -> bool {
+        self.0 & REPROJECTION_ASYNC != 0
+    }
+
+    /// Motion smoothing was applied to this frame.
+    pub fn motion_smoothing(&self) -> bool {
+        self.0 & REPROJECTION_MOTION != 0
+    }
+
+    /// Frames the runtime predicted ahead beyond the usual one, because the application is taking
+    /// too long to render.
+    pub fn additional_predicted_frames(&self) -> u32 {
+        (self.0 & PREDICTION_MASK) >> 4
+    }
+
+    /// Frames the compositor is throttling the application by.
+    pub fn throttled_frames(&self) -> u32 {
+        (self.0 & THROTTLE_MASK) >> 8
+    }
+}
+
+/// Timing of a single frame, from `Compositor::frame_timing`.
+///
+/// The `*_called`, `*_ready`, `*_start` and `*_end` times are relative to `system_time`.
+#[derive(Debug, Copy, Clone)]
+pub struct FrameTiming {
+    pub frame_index: u32,
+    /// Number of times this frame was presented.
+    pub presents: u32,
+    /// Number of times this frame was presented on another vsync than the one it was predicted for.
+    pub mispresents: u32,
+    /// Number of additional times the previous frame was scanned out.
+    pub dropped_frames: u32,
+    pub reprojection: ReprojectionFlags,
+    /// Absolute time of the vsync that running start is relative to, for comparing frames.
+    pub system_time: Duration,
+    /// GPU time spent rendering the scene, between `wait_get_poses` and the second `submit`.
+    pub pre_submit_gpu: Duration,
+    /// GPU time spent rendering after the second `submit`, e.g. for a companion window.
+    pub post_submit_gpu: Duration,
+    /// GPU time from the start of the frame to the end of the compositor's work.
+    pub total_render_gpu: Duration,
+    /// GPU time spent on distortion correction, chaperone, overlays and so on.
+    pub compositor_render_gpu: Duration,
+    /// CPU time spent submitting `compositor_render_gpu` work.
+    pub compositor_render_cpu: Duration,
+    /// CPU time spent waiting for running start, which the application could have used.
+    pub compositor_idle_cpu: Duration,
+    /// Time between calls to `wait_get_poses`.
+    pub client_frame_interval: Duration,
+    pub present_call_cpu: Duration,
+    pub wait_for_present_cpu: Duration,
+    /// Time spent in `submit`.
+    pub submit_frame: Duration,
+    pub wait_get_poses_called: Duration,
+    pub new_poses_ready: Duration,
+    /// When the second eye was submitted.
+    pub new_frame_ready: Duration,
+    pub compositor_update_start: Duration,
+    pub compositor_update_end: Duration,
+    pub compositor_render_start: Duration,
+    /// Pose used to render this frame.
+    pub hmd_pose: TrackedDevicePose,
+    pub vsyncs_ready_for_use: u32,
+    pub vsyncs_to_first_view: u32,
+    pub transfer_latency: Duration,
+}
+
+impl From<sys::Compositor_FrameTiming> for FrameTiming {
+    fn from(x: sys::Compositor_FrameTiming) -> Self {
+        FrameTiming {
+            frame_index: x.m_nFrameIndex,
+            presents: x.m_nNumFramePresents,
+            mispresents: x.m_nNumMisPresented,
+            dropped_frames: x.m_nNumDroppedFrames,
+            reprojection: ReprojectionFlags(x.m_nReprojectionFlags),
+            system_time: Duration::try_from_secs_f64(x.m_flSystemTimeInSeconds).unwrap_or_default(),
+            pre_submit_gpu: ms(x.m_flPreSubmitGpuMs),
+            post_submit_gpu: ms(x.m_flPostSubmitGpuMs),
+            total_render_gpu: ms(x.m_flTotalRenderGpuMs),
+            compositor_render_gpu: ms(x.m_flCompositorRenderGpuMs),
+            compositor_render_cpu: ms(x.m_flCompositorRenderCpuMs),
+            compositor_idle_cpu: ms(x.m_flCompositorIdleCpuMs),
+            client_frame_interval: ms(x.m_flClientFrameIntervalMs),
+            present_call_cpu: ms(x.m_flPresentCallCpuMs),
+            wait_for_present_cpu: ms(x.m_flWaitForPresentCpuMs),
+            submit_frame: ms(x.m_flSubmitFrameMs),
+            wait_get_poses_called: ms(x.m_flWaitGetPosesCalledMs),
+            new_poses_ready: ms(x.m_flNewPosesReadyMs),
+            new_frame_ready: ms(x.m_flNewFrameReadyMs),
+            compositor_update_start: ms(x.m_flCompositorUpdateStartMs),
+            compositor_update_end: ms(x.m_flCompositorUpdateEndMs),
+            compositor_render_start: ms(x.m_flCompositorRenderStartMs),
+            hmd_pose: x.m_HmdPose.into(),
+            vsyncs_ready_for_use: x.m_nNumVSyncsReadyForUse,
+            vsyncs_to_first_view: x.m_nNumVSyncsToFirstView,
+            transfer_latency: ms(x.m_flTransferLatencyMs),
+        }
+    }
+}
+
+/// Milliseconds as reported by the runtime. Negative values, which shouldn't happen, become zero.
+fn ms(x: f32) -> Duration {
+    Duration::try_from_secs_f64(x as f64 / 1000.0).unwrap_or_default()
+}
+
+/// Presented, dropped and reprojected frame counts for a period of an application's life.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct FrameCounts {
+    /// Times a frame was presented, including reprojected frames.
+    pub presents: u32,
+    /// Times an old frame was scanned out again without reprojection.
+    pub dropped: u32,
+    /// Times an old frame was scanned out again with reprojection.
+    pub reprojected: u32,
+}
+
+/// Statistics accumulated over the life of the last connected application, from
+/// `Compositor::cumulative_stats`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct CumulativeStats {
+    /// Process the statistics belong to. It may no longer be running.
+    pub pid: u32,
+    pub total: FrameCounts,
+    /// Frames before the application first faded in.
+    pub startup: FrameCounts,
+    /// Number of times the application faded to the compositor, e.g. while loading a level.
+    pub loading_count: u32,
+    /// Frames while faded to the compositor, excluding startup.
+    pub loading: FrameCounts,
+    /// Number of times the application stopped submitting frames for long enough that the
+    /// compositor faded out.
+    pub timed_out_count: u32,
+    /// Frames while timed out, excluding startup and loading.
+    pub timed_out: FrameCounts,
+    pub frame_submits: u32,
+    /// Total compositor CPU time over `frame_submits` frames.
+    pub compositor_cpu: Duration,
+    /// Total compositor GPU time over `frame_submits` frames.
+    pub compositor_gpu: Duration,
+    /// Total target frame time over `frame_submits` frames.
+    pub target_frame_time: Duration,
+    /// Total application CPU time over `frame_submits` frames.
+    pub application_cpu: Duration,
+    /// Total application GPU time over `frame_submits` frames.
+    pub application_gpu: Duration,
+    pub frames_with_depth: u32,
+}
+
+impl CumulativeStats {
+    /// `total`, one of the summed times, per submitted frame.
+    pub fn per_frame(&self, total: Duration) -> Duration {
+        total.checked_div(self.frame_submits).unwrap_or_default()
+    }
+}
+
+impl From<sys::Compositor_CumulativeStats> for CumulativeStats {
+    fn from(x: sys::Compositor_CumulativeStats) -> Self {
+        let ms = |x: f64| Duration::try_from_secs_f64(x / 1000.0).unwrap_or_default();
+        CumulativeStats {
+            pid: x.m_nPid,
+            total: FrameCounts {
+                presents: x.m_nNumFramePresents,
+                dropped: x.m_nNumDroppedFrames,
+                reprojected: x.m_nNumReprojectedFrames,
+            },
+            startup: FrameCounts {
+                presents: x.m_nNumFramePresentsOnStartup,
+                dropped: x.m_nNumDroppedFramesOnStartup,
+                reprojected: x.m_nNumReprojectedFramesOnStartup,
+            },
+            loading_count: x.m_nNumLoading,
+            loading: FrameCounts {
+                presents: x.m_nNumFramePresentsLoading,
+                dropped: x.m_nNumDroppedFramesLoading,
+                reprojected: x.m_nNumReprojectedFramesLoading,
+            },
+            timed_out_count: x.m_nNumTimedOut,
+            timed_out: FrameCounts {
+                presents: x.m_nNumFramePresentsTimedOut,
+                dropped: x.m_nNumDroppedFramesTimedOut,
+                reprojected: x.m_nNumReprojectedFramesTimedOut,
+            },
+            frame_submits: x.m_nNumFrameSubmits,
+            compositor_cpu: ms(x.m_flSumCompositorCPUTimeMS),
+            compositor_gpu: ms(x.m_flSumCompositorGPUTimeMS),
+            target_frame_time: ms(x.m_flSumTargetFrameTimes),
+            application_cpu: ms(x.m_flSumApplicationCPUTimeMS),
+            application_gpu: ms(x.m_flSumApplicationGPUTimeMS),
+            frames_with_depth: x.m_nNumFramesWithDepth,
+        }
+    }
+}
+
+fn empty_frame_timing() -> sys::Compositor_FrameTiming {
+    sys::Compositor_FrameTiming {
+        m_nSize: mem::size_of::<sys::Compositor_FrameTiming>() as u32,
+        ..Default::default()
+    }
+}
+
+impl Compositor<'_> {
+    /// Timing of the frame `frames_ago` frames before the latest. The oldest frame is returned if
+    /// `frames_ago` is beyond the history, which holds the last 128 frames.
+    pub fn frame_timing(&self, frames_ago: u32) -> Option<FrameTiming> {
+        let mut timing = empty_frame_timing();
+        if unsafe { self.0.GetFrameTiming.unwrap()(&mut timing, frames_ago) } {
+            Some(timing.into())
+        } else {
+            None
+        }
+    }
+
+    /// Timing of up to the last `count` frames, oldest first.
+    pub fn frame_timings(&self, count: u32) -> Vec<FrameTiming> {
+        let mut timings = vec![empty_frame_timing(); count as usize];
+        let n = unsafe { self.0.GetFrameTimings.unwrap()(timings.as_mut_ptr(), count) };
+        timings.truncate(n as usize);
+        timings.into_iter().map(Into::into).collect()
+    }
+
+    /// Time left in the current frame. Due to running start, this may roll over to the next frame
+    /// before reaching zero.
+    pub fn frame_time_remaining(&self) -> Duration {
+        Duration::try_from_secs_f32(unsafe { self.0.GetFrameTimeRemaining.unwrap()() })
+            .unwrap_or_default()
+    }
+
+    /// Statistics for the last connected application.
+    pub fn cumulative_stats(&self) -> CumulativeStats {
+        let mut stats = sys::Compositor_CumulativeStats::default();
+        unsafe {
+            self.0.GetCumulativeStats.unwrap()(
+                &mut stats,
+                mem::size_of::<sys::Compositor_CumulativeStats>() as u32,
+            )
+        };
+        stats.into()
+    }
+
+    /// Process that rendered the last frame, or `None` if the compositor rendered it itself.
+    pub fn last_frame_renderer(&self) -> Option<u32> {
+        match unsafe { self.0.GetLastFrameRenderer.unwrap()() } {
+            0 => None,
+            pid => Some(pid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockRuntime;
+    use crate::Context;
+
+    fn timing(frame_index: u32) -> sys::Compositor_FrameTiming {
+        sys::Compositor_FrameTiming {
+            m_nFrameIndex: frame_index,
+            m_nNumFramePresents: 2,
+            m_nReprojectionFlags: REPROJECTION_REASON_GPU | REPROJECTION_ASYNC | 0x10 | 0x300,
+            m_flSystemTimeInSeconds: 12.5,
+            m_flPreSubmitGpuMs: 7.5,
+            m_flCompositorIdleCpuMs: -1.0,
+            ..empty_frame_timing()
+        }
+    }
+
+    #[test]
+    fn typed_timing() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let compositor = context.compositor().unwrap();
+        assert!(compositor.frame_timing(0).is_none());
+        assert!(compositor.frame_timings(4).is_empty());
+
+        for i in 1..=3 {
+            runtime.push_frame_timing(timing(i));
+        }
+        let latest = compositor.frame_timing(0).unwrap();
+        assert_eq!(latest.frame_index, 3);
+        assert_eq!(latest.presents, 2);
+        assert_eq!(latest.system_time, Duration::from_millis(12500));
+        assert_eq!(latest.pre_submit_gpu, Duration::from_micros(7500));
+        assert_eq!(latest.compositor_idle_cpu, Duration::ZERO);
+        let flags = latest.reprojection;
+        assert!(flags.gpu() && !flags.cpu() && flags.reprojected());
+        assert!(flags.async_reprojection() && !flags.motion_smoothing());
+        assert_eq!(
+            (
+                flags.additional_predicted_frames(),
+                flags.throttled_frames()
+            ),
+            (1, 3)
+        );
+        assert_eq!(compositor.frame_timing(10).unwrap().frame_index, 1);
+        let indices: Vec<_> = compositor
+            .frame_timings(2)
+            .iter()
+            .map(|x| x.frame_index)
+            .collect();
+        assert_eq!(indices, [2, 3]);
+        assert_eq!(compositor.frame_timings(10).len(), 3);
+
+        runtime.set_frame_time_remaining(0.004);
+        assert_eq!(
+            compositor.frame_time_remaining(),
+            Duration::from_secs_f32(0.004)
+        );
+        assert_eq!(compositor.last_frame_renderer(), None);
+        runtime.set_last_frame_renderer(1234);
+        assert_eq!(compositor.last_frame_renderer(), Some(1234));
+
+        runtime.set_cumulative_stats(sys::Compositor_CumulativeStats {
+            m_nPid: 1234,
+            m_nNumFramePresents: 100,
+            m_nNumReprojectedFrames: 5,
+            m_nNumLoading: 1,
+            m_nNumDroppedFramesLoading: 2,
+            m_nNumFrameSubmits: 90,
+            m_flSumApplicationGPUTimeMS: 900.0,
+            ..Default::default()
+        });
+        let stats = compositor.cumulative_stats();
+        assert_eq!(stats.pid, 1234);
+        assert_eq!(
+            stats.total,
+            FrameCounts {
+                presents: 100,
+                dropped: 0,
+                reprojected: 5
+            }
+        );
+        assert_eq!((stats.loading_count, stats.loading.dropped), (1, 2));
+        assert_eq!(
+            stats.per_frame(stats.application_gpu),
+            Duration::from_millis(10)
+        );
+        assert_eq!(
+            CumulativeStats::default().per_frame(Duration::from_secs(1)),
+            Duration::ZERO
+        );
+    }
+}
@@ -0,0 +1,428 @@
+//! Decoding the legacy `ControllerState` into buttons and typed axes.
+
+use enumset::{EnumSet, EnumSetType};
+use openvr_sys as sys;
+
+use crate::property;
+use crate::{ControllerAxis, ControllerState, System, TrackedDeviceIndex};
+
+/// A button reported in the masks of `ControllerState`. Discriminants are the `EVRButtonId`s,
+/// written out because `EnumSetType` needs literals.
+///
+/// Several `EVRButtonId`s share a value; those are associated constants, e.g.
+/// `Button::INDEX_CONTROLLER_A` is `Button::Grip`.
+#[derive(EnumSetType, Debug, Hash)]
+#[enumset(repr = "u64")]
+pub enum Button {
+    System = 0,
+    ApplicationMenu = 1,
+    Grip = 2,
+    DPadLeft = 3,
+    DPadUp = 4,
+    DPadRight = 5,
+    DPadDown = 6,
+    A = 7,
+    ProximitySensor = 31,
+    Axis0 = 32,
+    Axis1 = 33,
+    Axis2 = 34,
+    Axis3 = 35,
+    Axis4 = 36,
+}
+
+// The discriminants of `Button` must stay equal to the `EVRButtonId`s they stand for.
+const _: () = {
+    assert!(Button::System as sys::EVRButtonId == sys::EVRButtonId_k_EButton_System);
+    assert!(
+        Button::ApplicationMenu as sys::EVRButtonId == sys::EVRButtonId_k_EButton_ApplicationMenu
+    );
+    assert!(Button::Grip as sys::EVRButtonId == sys::EVRButtonId_k_EButton_Grip);
+    assert!(Button::DPadLeft as sys::EVRButtonId == sys::EVRButtonId_k_EButton_DPad_Left);
+    assert!(Button::DPadUp as sys::EVRButtonId == sys::EVRButtonId_k_EButton_DPad_Up);
+    assert!(Button::DPadRight as sys::EVRButtonId == sys::EVRButtonId_k_EButton_DPad_Right);
+    assert!(Button::DPadDown as sys::EVRButtonId == sys::EVRButtonId_k_EButton_DPad_Down);
+    assert!(Button::A as sys::EVRButtonId == sys::EVRButtonId_k_EButton_A);
+    assert!(
+        Button::ProximitySensor as sys::EVRButtonId == sys::EVRButtonId_k_EButton_ProximitySensor
+    );
+    assert!(Button::Axis0 as sys::EVRButtonId == sys::EVRButtonId_k_EButton_Axis0);
+    assert!(Button::Axis1 as sys::EVRButtonId == sys::EVRButtonId_k_EButton_Axis1);
+    assert!(Button::Axis2 as sys::EVRButtonId == sys::EVRButtonId_k_EButton_Axis2);
+    assert!(Button::Axis3 as sys::EVRButtonId == sys::EVRButtonId_k_EButton_Axis3);
+    assert!(Button::Axis4 as sys::EVRButtonId == sys::EVRButtonId_k_EButton_Axis4);
+};
+
+impl Button {
+    pub const STEAM_VR_TOUCHPAD: Button = Button::Axis0;
+    pub const STEAM_VR_TRIGGER: Button = Button::Axis1;
+    pub const DASHBOARD_BACK: Button = Button::Grip;
+    pub const INDEX_CONTROLLER_A: Button = Button::Grip;
+    pub const INDEX_CONTROLLER_B: Button = Button::ApplicationMenu;
+    pub const INDEX_CONTROLLER_JOYSTICK: Button = Button::Axis3;
+    /// Older name of `INDEX_CONTROLLER_A`.
+    pub const KNUCKLES_A: Button = Button::INDEX_CONTROLLER_A;
+    pub const KNUCKLES_B: Button = Button::INDEX_CONTROLLER_B;
+    pub const KNUCKLES_JOYSTICK: Button = Button::INDEX_CONTROLLER_JOYSTICK;
+
+    /// The button with the given `EVRButtonId`, if it is one of the known ones.
+    pub fn from_id(id: sys::EVRButtonId) -> Option<Button> {
+        EnumSet::<Button>::try_from_u64(1u64.checked_shl(id)?)?
+            .iter()
+            .next()
+    }
+
+    pub fn id(self) -> sys::EVRButtonId {
+        self as sys::EVRButtonId
+    }
+
+    /// The bit of this button in `ControllerState::button_pressed` and `button_touched`.
+    pub fn mask(self) -> u64 {
+        1 << self as u32
+    }
+
+    /// The button reporting whether `axis` is pressed, for axes 0 to 4.
+    pub fn for_axis(axis: usize) -> Option<Button> {
+        [
+            Button::Axis0,
+            Button::Axis1,
+            Button::Axis2,
+            Button::Axis3,
+            Button::Axis4,
+        ]
+        .get(axis)
+        .copied()
+    }
+}
+
+/// What an axis of `ControllerState` measures, from the `Axis0Type_Int32`..`Axis4Type_Int32`
+/// properties.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum AxisType {
+    /// The device has no such axis.
+    #[default]
+    None = sys::EVRControllerAxisType_k_eControllerAxis_None as isize,
+    /// A touchpad: x and y from -1 to 1.
+    TrackPad = sys::EVRControllerAxisType_k_eControllerAxis_TrackPad as isize,
+    /// A thumbstick: x and y from -1 to 1.
+    Joystick = sys::EVRControllerAxisType_k_eControllerAxis_Joystick as isize,
+    /// An analog trigger: x from 0 to 1.
+    Trigger = sys::EVRControllerAxisType_k_eControllerAxis_Trigger as isize,
+}
+
+impl From<i32> for AxisType {
+    fn from(x: i32) -> Self {
+        match x as sys::EVRControllerAxisType {
+            sys::EVRControllerAxisType_k_eControllerAxis_TrackPad => AxisType::TrackPad,
+            sys::EVRControllerAxisType_k_eControllerAxis_Joystick => AxisType::Joystick,
+            sys::EVRControllerAxisType_k_eControllerAxis_Trigger => AxisType::Trigger,
+            _ => AxisType::None,
+        }
+    }
+}
+
+/// A set of buttons, as in the masks of `ControllerState` and the `SupportedButtons_Uint64`
+/// property. Convert from those with `EnumSet::from_u64_truncated`, which drops bits of unknown
+/// buttons, and back with `as_u64`.
+pub type ButtonMask = EnumSet<Button>;
+
+impl ControllerState {
+    pub fn pressed(&self) -> ButtonMask {
+        ButtonMask::from_u64_truncated(self.button_pressed)
+    }
+
+    pub fn touched(&self) -> ButtonMask {
+        ButtonMask::from_u64_truncated(self.button_touched)
+    }
+}
+
+const AXIS_TYPE_PROPERTIES: [property::Property<i32>; 5] = [
+    property::Axis0Type_Int32,
+    property::Axis1Type_Int32,
+    property::Axis2Type_Int32,
+    property::Axis3Type_Int32,
+    property::Axis4Type_Int32,
+];
+
+/// The state of a controller, decoded, with changes since the previous packet.
+///
+/// The runtime increments `packet_num` whenever the state changes, so `update` with a state
+/// carrying the same packet number leaves everything as it was and clears the edges reported by
+/// `just_pressed` and friends.
+///
+/// ```no_run
+/// # let context = openvr::init(openvr::ApplicationType::Scene).unwrap();
+/// # let system = context.system().unwrap();
+/// use openvr::controller::{Button, ControllerInput};
+///
+/// let role = openvr::TrackedControllerRole::RightHand;
+/// let hand = system.tracked_device_index_for_controller_role(role).unwrap();
+/// let mut controller = ControllerInput::for_device(&system, hand);
+/// // Every frame:
+/// controller.poll(&system, hand);
+/// if controller.just_pressed(Button::Grip) {
+///     println!("grabbed with trigger at {:?}", controller.trigger());
+/// }
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct ControllerInput {
+    axis_types: [AxisType; 5],
+    current: ControllerState,
+    previous: ControllerState,
+    /// Whether `current` came from the runtime, rather than being the initial empty state.
+    received: bool,
+}
+
+impl ControllerInput {
+    /// A controller with nothing pressed and axes of the given types.
+    pub fn new(axis_types: [AxisType; 5]) -> Self {
+        let state = ControllerState {
+            packet_num: 0,
+            button_pressed: 0,
+            button_touched: 0,
+            axis: [ControllerAxis { x: 0.0, y: 0.0 }; 5],
+        };
+        ControllerInput {
+            axis_types,
+            current: state,
+            previous: state,
+            received: false,
+        }
+    }
+
+    /// A controller with the axis types reported for `device`. Axes whose property can't be read
+    /// are `None`.
+    pub fn for_device(system: &System, device: TrackedDeviceIndex) -> Self {
+        Self::new(AXIS_TYPE_PROPERTIES.map(|x| {
+            system
+                .property(device, x)
+                .map_or(AxisType::None, AxisType::from)
+        }))
+    }
+
+    /// Takes the latest state of the controller. Returns whether it is a new packet, which the
+    /// first state taken always is.
+    pub fn update(&mut self, state: ControllerState) -> bool {
+        self.previous = self.current;
+        if self.received && state.packet_num == self.current.packet_num {
+            return false;
+        }
+        self.current = state;
+        self.received = true;
+        true
+    }
+
+    /// Reads and takes the state of `device`. Returns whether it is a new packet; `false` also if
+    /// the state couldn't be read, e.g. while the dashboard is open, in which case edges are
+    /// cleared and the last state kept.
+    pub fn poll(&mut self, system: &System, device: TrackedDeviceIndex) -> bool {
+        match system.controller_state(device) {
+            Some(state) => self.update(state),
+            None => {
+                self.previous = self.current;
+                false
+            }
+        }
+    }
+
+    pub fn state(&self) -> &ControllerState {
+        &self.current
+    }
+
+    pub fn packet_num(&self) -> u32 {
+        self.current.packet_num
+    }
+
+    pub fn is_pressed(&self, button: Button) -> bool {
+        self.current.button_pressed & button.mask() != 0
+    }
+
+    pub fn is_touched(&self, button: Button) -> bool {
+        self.current.button_touched & button.mask() != 0
+    }
+
+    /// Whether `button` went down with the last `update`.
+    pub fn just_pressed(&self, button: Button) -> bool {
+        self.is_pressed(button) && self.previous.button_pressed & button.mask() == 0
+    }
+
+    /// Whether `button` went up with the last `update`.
+    pub fn just_released(&self, button: Button) -> bool {
+        !self.is_pressed(button) && self.previous.button_pressed & button.mask() != 0
+    }
+
+    pub fn just_touched(&self, button: Button) -> bool {
+        self.is_touched(button) && self.previous.button_touched & button.mask() == 0
+    }
+
+    pub fn just_untouched(&self, button: Button) -> bool {
+        !self.is_touched(button) && self.previous.button_touched & button.mask() != 0
+    }
+
+    /// Buttons that went down with the last `update`.
+    pub fn pressed_since_last_update(&self) -> ButtonMask {
+        self.current.pressed() - self.previous.pressed()
+    }
+
+    /// Buttons that went up with the last `update`.
+    pub fn released_since_last_update(&self) -> ButtonMask {
+        self.previous.pressed() - self.current.pressed()
+    }
+
+    pub fn axis_types(&self) -> [AxisType; 5] {
+        self.axis_types
+    }
+
+    /// Raw value of axis `index`, or `None` past the fifth axis or if the device doesn't have it.
+    pub fn axis(&self, index: usize) -> Option<ControllerAxis> {
+        match self.axis_types.get(index)? {
+            AxisType::None => None,
+            _ => Some(self.current.axis[index]),
+        }
+    }
+
+    /// Change of axis `index` with the last `update`.
+    pub fn axis_delta(&self, index: usize) -> Option<ControllerAxis> {
+        let x = self.axis(index)?;
+        let previous = self.previous.axis[index];
+        Some(ControllerAxis {
+            x: x.x - previous.x,
+            y: x.y - previous.y,
+        })
+    }
+
+    /// Index of the first axis of type `ty`.
+    pub fn find_axis(&self, ty: AxisType) -> Option<usize> {
+        self.axis_types.iter().position(|&x| x == ty)
+    }
+
+    /// Value of the first trigger, from 0 to 1.
+    pub fn trigger(&self) -> Option<f32> {
+        Some(self.current.axis[self.find_axis(AxisType::Trigger)?].x)
+    }
+
+    /// Position on the first touchpad. Only meaningful while it is touched; see `touchpad_touched`.
+    pub fn touchpad(&self) -> Option<ControllerAxis> {
+        Some(self.current.axis[self.find_axis(AxisType::TrackPad)?])
+    }
+
+    pub fn touchpad_touched(&self) -> bool {
+        self.find_axis(AxisType::TrackPad)
+            .and_then(Button::for_axis)
+            .is_some_and(|x| self.is_touched(x))
+    }
+
+    /// Position of the first thumbstick.
+    pub fn joystick(&self) -> Option<ControllerAxis> {
+        Some(self.current.axis[self.find_axis(AxisType::Joystick)?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockDevice, MockRuntime};
+    use crate::{Context, TrackedDeviceClass};
+
+    fn state(packet_num: u32, pressed: &[Button], trigger: f32) -> ControllerState {
+        let mut axis = [ControllerAxis { x: 0.0, y: 0.0 }; 5];
+        axis[1].x = trigger;
+        ControllerState {
+            packet_num,
+            button_pressed: pressed.iter().fold(0, |m, x| m | x.mask()),
+            button_touched: Button::Axis0.mask(),
+            axis,
+        }
+    }
+
+    #[test]
+    fn decodes_and_detects_edges() {
+        let runtime = MockRuntime::new();
+        let hand = runtime.add_device(
+            MockDevice::new(TrackedDeviceClass::Controller)
+                .with_property(
+                    property::Axis0Type_Int32,
+                    sys::EVRControllerAxisType_k_eControllerAxis_TrackPad as i32,
+                )
+                .with_property(
+                    property::Axis1Type_Int32,
+                    sys::EVRControllerAxisType_k_eControllerAxis_Trigger as i32,
+                )
+                .with_controller_state(state(1, &[Button::Grip], 0.25)),
+        );
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        let mut controller = ControllerInput::for_device(&system, hand);
+        assert_eq!(
+            controller.axis_types(),
+            [
+                AxisType::TrackPad,
+                AxisType::Trigger,
+                AxisType::None,
+                AxisType::None,
+                AxisType::None
+            ]
+        );
+
+        assert!(controller.poll(&system, hand));
+        assert!(controller.is_pressed(Button::Grip) && controller.just_pressed(Button::Grip));
+        assert!(
+            controller.touchpad_touched() && controller.just_touched(Button::STEAM_VR_TOUCHPAD)
+        );
+        assert_eq!(controller.trigger(), Some(0.25));
+        assert!(controller.joystick().is_none() && controller.axis(2).is_none());
+
+        // Same packet: nothing new, edges cleared.
+        assert!(!controller.poll(&system, hand));
+        assert!(controller.is_pressed(Button::Grip) && !controller.just_pressed(Button::Grip));
+
+        runtime.set_controller_state(hand, state(2, &[Button::Axis1], 1.0));
+        assert!(controller.poll(&system, hand));
+        assert!(
+            controller.just_released(Button::Grip)
+                && controller.just_pressed(Button::STEAM_VR_TRIGGER)
+        );
+        assert_eq!(controller.axis_delta(1).unwrap().x, 0.75);
+        assert_eq!(controller.pressed_since_last_update(), Button::Axis1);
+        assert_eq!(controller.released_since_last_update(), Button::Grip);
+
+        // The first state is taken even if its packet number is 0.
+        let mut types = [AxisType::None; 5];
+        types[1] = AxisType::Trigger;
+        let mut controller = ControllerInput::new(types);
+        assert!(controller.update(state(0, &[Button::Grip], 0.5)));
+        assert!(controller.just_pressed(Button::Grip));
+        assert_eq!(controller.trigger(), Some(0.5));
+        assert!(!controller.update(state(0, &[Button::Grip], 0.5)));
+    }
+
+    #[test]
+    fn masks_and_names() {
+        assert_eq!(
+            Button::from_id(sys::EVRButtonId_k_EButton_IndexController_JoyStick),
+            Some(Button::Axis3)
+        );
+        assert_eq!(Button::from_id(20), None);
+        assert_eq!(Button::from_id(sys::EVRButtonId_k_EButton_Max), None);
+        assert_eq!(
+            Button::Axis1.mask(),
+            ButtonMask::only(Button::Axis1).as_u64()
+        );
+
+        let supported = Button::System | Button::INDEX_CONTROLLER_A | Button::KNUCKLES_JOYSTICK;
+        let runtime = MockRuntime::new();
+        let hand = runtime.add_device(
+            MockDevice::new(TrackedDeviceClass::Controller).with_property(
+                property::SupportedButtons_Uint64,
+                supported.as_u64() | 1 << 20,
+            ),
+        );
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        assert_eq!(system.supported_buttons(hand).unwrap(), supported);
+        assert_eq!(system.button_name(Button::Grip), "k_EButton_Grip");
+        assert_eq!(
+            system.controller_axis_type_name(AxisType::Joystick),
+            "k_eControllerAxis_Joystick"
+        );
+    }
+}
@@ -0,0 +1,531 @@
+//! Playing haptic patterns built from many vibrations, on top of the one-shot haptic calls.
+
+use std::time::{Duration, Instant};
+
+use super::{Result, VRActionHandle, VRInputValueHandle};
+use crate::{Input, System, TrackedDeviceIndex};
+
+/// Longest pulse `System::trigger_haptic_pulse` accepts, used for full amplitude on
+/// `HapticTarget::Device`.
+const MAX_PULSE_MICROSECONDS: f32 = 3999.0;
+
+/// A constant vibration within a `HapticPattern`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HapticSegment {
+    pub duration: Duration,
+    /// In Hz. Ignored by `HapticTarget::Device`.
+    pub frequency: f32,
+    /// From 0 to 1.
+    pub amplitude: f32,
+}
+
+impl HapticSegment {
+    pub fn new(duration: Duration, frequency: f32, amplitude: f32) -> Self {
+        HapticSegment {
+            duration,
+            frequency,
+            amplitude,
+        }
+    }
+
+    /// A segment that doesn't vibrate.
+    pub fn pause(duration: Duration) -> Self {
+        Self::new(duration, 0.0, 0.0)
+    }
+}
+
+/// How often a `HapticPattern` plays its segments.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HapticRepeat {
+    Times(u32),
+    /// Until cancelled or replaced.
+    Forever,
+}
+
+/// A sequence of vibrations, built up from segments, ramps and pulses.
+///
+/// ```
+/// use openvr::input::HapticPattern;
+/// use std::time::Duration;
+///
+/// let ms = Duration::from_millis;
+/// // Swell up over 200ms, then three sharp knocks.
+/// let pattern = HapticPattern::new()
+///     .ramp(ms(200), 160.0, 0.0, 1.0, 10)
+///     .pulses(3, ms(20), ms(60), 320.0, 1.0);
+/// assert_eq!(pattern.duration(), Some(ms(440)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HapticPattern {
+    segments: Vec<HapticSegment>,
+    repeat: HapticRepeat,
+}
+
+impl Default for HapticPattern {
+    fn default() -> Self {
+        HapticPattern {
+            segments: Vec::new(),
+            repeat: HapticRepeat::Times(1),
+        }
+    }
+}
+
+impl HapticPattern {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn segment(mut self, segment: HapticSegment) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    pub fn vibrate(self, duration: Duration, frequency: f32, amplitude: f32) -> Self {
+        self.segment(HapticSegment::new(duration, frequency, amplitude))
+    }
+
+    pub fn pause(self, duration: Duration) -> Self {
+        self.segment(HapticSegment::pause(duration))
+    }
+
+    /// Changes amplitude linearly from `from` to `to` over `duration`, in `steps` segments.
+    pub fn ramp(
+        mut self,
+        duration: Duration,
+        frequency: f32,
+        from: f32,
+        to: f32,
+        steps: u32,
+    ) -> Self {
+        let steps = steps.max(1);
+        for i in 0..steps {
+            let t = if steps == 1 {
+                1.0
+            } else {
+                i as f32 / (steps - 1) as f32
+            };
+            self.segments.push(HapticSegment::new(
+                duration / steps,
+                frequency,
+                from + (to - from) * t,
+            ));
+        }
+        self
+    }
+
+    /// `count` vibrations of length `on`, each followed by a pause of length `off`.
+    pub fn pulses(
+        mut self,
+        count: u32,
+        on: Duration,
+        off: Duration,
+        frequency: f32,
+        amplitude: f32,
+    ) -> Self {
+        for _ in 0..count {
+            self.segments
+                .push(HapticSegment::new(on, frequency, amplitude));
+            self.segments.push(HapticSegment::pause(off));
+        }
+        self
+    }
+
+    pub fn repeat(mut self, repeat: HapticRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    pub fn segments(&self) -> &[HapticSegment] {
+        &self.segments
+    }
+
+    /// Total length, or `None` if it repeats forever.
+    pub fn duration(&self) -> Option<Duration> {
+        let once: Duration = self.segments.iter().map(|x| x.duration).sum();
+        match self.repeat {
+            HapticRepeat::Times(n) => Some(once * n),
+            HapticRepeat::Forever => None,
+        }
+    }
+
+    /// The `n`th segment played, counting across repetitions.
+    fn nth(&self, n: usize) -> Option<&HapticSegment> {
+        let len = self.segments.len();
+        if len == 0 {
+            return None;
+        }
+        // A pattern that repeats forever but takes no time would never let `update` return.
+        let endless = self.repeat == HapticRepeat::Forever
+            && self.segments.iter().any(|x| !x.duration.is_zero());
+        match self.repeat {
+            HapticRepeat::Times(times) if n < len * times as usize => Some(&self.segments[n % len]),
+            HapticRepeat::Forever if endless => Some(&self.segments[n % len]),
+            _ => None,
+        }
+    }
+}
+
+/// What a `HapticPlayer` vibrates. Each target plays one pattern at a time.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HapticTarget {
+    /// A vibration action, optionally restricted to one input source such as `/user/hand/left`.
+    Action {
+        action: VRActionHandle,
+        restrict: VRInputValueHandle,
+    },
+    /// A device, through the legacy `System::trigger_haptic_pulse`. Amplitude maps to pulse length
+    /// and frequency is ignored.
+    Device {
+        device: TrackedDeviceIndex,
+        axis: u32,
+    },
+}
+
+impl HapticTarget {
+    /// `action` on whichever device it is bound to.
+    pub fn action(action: VRActionHandle) -> Self {
+        HapticTarget::Action {
+            action,
+            restrict: VRInputValueHandle(0),
+        }
+    }
+
+    /// `action`, only on the device of `source`, e.g. the handle of `/user/hand/right`.
+    pub fn action_on(action: VRActionHandle, source: VRInputValueHandle) -> Self {
+        HapticTarget::Action {
+            action,
+            restrict: source,
+        }
+    }
+
+    pub fn device(device: TrackedDeviceIndex) -> Self {
+        HapticTarget::Device { device, axis: 0 }
+    }
+}
+
+/// Identifies a pattern started with `HapticPlayer::play`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HapticId(u64);
+
+struct Playback {
+    id: HapticId,
+    target: HapticTarget,
+    pattern: HapticPattern,
+    priority: u8,
+    started: Instant,
+    /// Next segment to issue, for actions, or the current one, for devices.
+    cursor: usize,
+    /// Offset of `cursor`'s segment from `started`.
+    cursor_start: Duration,
+    /// Whether a vibration has been issued that may still be running.
+    issued: bool,
+}
+
+/// Plays `HapticPattern`s on several targets, driven by calling `update` once per frame.
+///
+/// Action vibrations are queued a little ahead of time (see `set_lookahead`) so frame timing
+/// doesn't leave gaps between segments. Legacy device pulses can't be queued and are only as
+/// fine-grained as the update rate.
+///
+/// ```no_run
+/// # let context = openvr::init(openvr::ApplicationType::Scene).unwrap();
+/// # let system = context.system().unwrap();
+/// # let mut input = context.input().unwrap();
+/// use openvr::input::{HapticPattern, HapticPlayer, HapticTarget};
+/// use std::time::{Duration, Instant};
+///
+/// let haptic = input.get_action_handle("/actions/main/out/haptic").unwrap();
+/// let left = input.get_input_source_handle("/user/hand/left").unwrap();
+/// let mut player = HapticPlayer::new();
+/// let knock = HapticPattern::new().vibrate(Duration::from_millis(30), 200.0, 1.0);
+/// player.play(HapticTarget::action_on(haptic, left), knock, 0, Instant::now());
+/// // Every frame:
+/// player.update(Instant::now(), &mut input, &system).unwrap();
+/// ```
+pub struct HapticPlayer {
+    playing: Vec<Playback>,
+    /// Action targets whose vibration has to be stopped on the next update.
+    stops: Vec<HapticTarget>,
+    next_id: u64,
+    lookahead: Duration,
+}
+
+impl Default for HapticPlayer {
+    fn default() -> Self {
+        HapticPlayer {
+            playing: Vec::new(),
+            stops: Vec::new(),
+            next_id: 0,
+            lookahead: Duration::from_millis(20),
+        }
+    }
+}
+
+impl HapticPlayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How far ahead of time action vibrations are issued. Should cover the time between two
+    /// `update`s; defaults to 20ms. Longer makes cancellation less immediate for patterns with many
+    /// short segments.
+    pub fn set_lookahead(&mut self, lookahead: Duration) {
+        self.lookahead = lookahead;
+    }
+
+    /// Starts playing `pattern` on `target` at `now`.
+    ///
+    /// A pattern already playing on `target` is replaced if its priority is not higher than
+    /// `priority`. Otherwise nothing happens and `None` is returned.
+    pub fn play(
+        &mut self,
+        target: HapticTarget,
+        pattern: HapticPattern,
+        priority: u8,
+        now: Instant,
+    ) -> Option<HapticId> {
+        if let Some(i) = self.playing.iter().position(|x| x.target == target) {
+            if self.playing[i].priority > priority {
+                return None;
+            }
+            self.remove(i);
+        }
+        let id = HapticId(self.next_id);
+        self.next_id += 1;
+        self.playing.push(Playback {
+            id,
+            target,
+            pattern,
+            priority,
+            started: now,
+            cursor: 0,
+            cursor_start: Duration::ZERO,
+            issued: false,
+        });
+        Some(id)
+    }
+
+    /// Stops the pattern `id`, if it is still playing.
+    pub fn cancel(&mut self, id: HapticId) -> bool {
+        match self.playing.iter().position(|x| x.id == id) {
+            Some(i) => {
+                self.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stops whatever is playing on `target`.
+    pub fn cancel_target(&mut self, target: HapticTarget) -> bool {
+        match self.playing.iter().position(|x| x.target == target) {
+            Some(i) => {
+                self.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn cancel_all(&mut self) {
+        while !self.playing.is_empty() {
+            self.remove(0);
+        }
+    }
+
+    pub fn is_playing(&self, id: HapticId) -> bool {
+        self.playing.iter().any(|x| x.id == id)
+    }
+
+    /// Number of patterns still playing.
+    pub fn active_count(&self) -> usize {
+        self.playing.len()
+    }
+
+    fn remove(&mut self, index: usize) {
+        let playback = self.playing.swap_remove(index);
+        if playback.issued && matches!(playback.target, HapticTarget::Action { .. }) {
+            self.stops.push(playback.target);
+        }
+    }
+
+    /// Issues the vibrations due by `now` and drops finished patterns.
+    pub fn update(&mut self, now: Instant, input: &mut Input, system: &System) -> Result<()> {
+        // A stop is only dequeued once sent, so the ones left after an error are retried on the
+        // next update.
+        while let Some(&target) = self.stops.first() {
+            if let HapticTarget::Action { action, restrict } = target {
+                // A new vibration replaces the running one, so an empty one stops it.
+                input.trigger_haptic_vibration_action(
+                    action,
+                    0.0,
+                    Duration::ZERO,
+                    0.0,
+                    0.0,
+                    restrict,
+                )?;
+            }
+            self.stops.remove(0);
+        }
+        let lookahead = self.lookahead;
+        let mut result = Ok(());
+        self.playing.retain_mut(|x| {
+            // After a failure, leave the rest for the next update.
+            if result.is_err() {
+                return true;
+            }
+            let elapsed = now.saturating_duration_since(x.started);
+            match x.target {
+                HapticTarget::Action { action, restrict } => {
+                    while let Some(&segment) = x.pattern.nth(x.cursor) {
+                        if x.cursor_start > elapsed + lookahead {
+                            break;
+                        }
+                        if segment.amplitude > 0.0 && !segment.duration.is_zero() {
+                            let delay = x.cursor_start.saturating_sub(elapsed);
+                            if let Err(e) = input.trigger_haptic_vibration_action(
+                                action,
+                                delay.as_secs_f32(),
+                                segment.duration,
+                                segment.frequency,
+                                segment.amplitude.min(1.0),
+                                restrict,
+                            ) {
+                                result = Err(e);
+                                return true;
+                            }
+                            x.issued = true;
+                        }
+                        x.cursor += 1;
+                        x.cursor_start += segment.duration;
+                    }
+                    x.pattern.nth(x.cursor).is_some() || x.cursor_start > elapsed
+                }
+                HapticTarget::Device { device, axis } => {
+                    while let Some(segment) = x.pattern.nth(x.cursor) {
+                        if x.cursor_start + segment.duration > elapsed {
+                            break;
+                        }
+                        x.cursor += 1;
+                        x.cursor_start += segment.duration;
+                    }
+                    match x.pattern.nth(x.cursor) {
+                        Some(segment) => {
+                            let pulse = segment.amplitude.clamp(0.0, 1.0) * MAX_PULSE_MICROSECONDS;
+                            if pulse >= 1.0 {
+                                system.trigger_haptic_pulse(device, axis, pulse as u16);
+                            }
+                            true
+                        }
+                        None => false,
+                    }
+                }
+            }
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::VRInputError;
+    use crate::mock::{MockDevice, MockRuntime};
+    use crate::{sys, Context, TrackedDeviceClass};
+
+    fn ms(x: u64) -> Duration {
+        Duration::from_millis(x)
+    }
+
+    #[test]
+    fn schedules_segments_ahead() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        let mut input = context.input().unwrap();
+        let haptic = input.get_action_handle("/actions/main/out/haptic").unwrap();
+        let left = input.get_input_source_handle("/user/hand/left").unwrap();
+        let target = HapticTarget::action_on(haptic, left);
+
+        let t0 = Instant::now();
+        let mut player = HapticPlayer::new();
+        let pattern = HapticPattern::new()
+            .vibrate(ms(10), 100.0, 0.5)
+            .pause(ms(10))
+            .vibrate(ms(30), 200.0, 1.0)
+            .repeat(HapticRepeat::Times(2));
+        let id = player.play(target, pattern, 1, t0).unwrap();
+
+        player.update(t0, &mut input, &system).unwrap();
+        let issued = runtime.haptic_vibrations();
+        assert_eq!(issued.len(), 2);
+        assert_eq!(issued[1].start_seconds_from_now, 0.02);
+        assert_eq!(issued[1].amplitude, 1.0);
+        assert_eq!(issued[1].restrict, left);
+
+        player.update(t0 + ms(45), &mut input, &system).unwrap();
+        assert_eq!(runtime.haptic_vibrations().len(), 3);
+        player.update(t0 + ms(70), &mut input, &system).unwrap();
+        assert_eq!(runtime.haptic_vibrations().len(), 4);
+        assert!(player.is_playing(id));
+        player.update(t0 + ms(100), &mut input, &system).unwrap();
+        assert!(!player.is_playing(id));
+        assert_eq!(runtime.haptic_vibrations().len(), 4);
+    }
+
+    #[test]
+    fn priority_and_cancellation() {
+        let runtime = MockRuntime::new();
+        let hand = runtime.add_device(MockDevice::new(TrackedDeviceClass::Controller));
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        let mut input = context.input().unwrap();
+        let haptic = input.get_action_handle("/actions/main/out/haptic").unwrap();
+
+        let t0 = Instant::now();
+        let mut player = HapticPlayer::new();
+        let rumble = HapticPattern::new()
+            .vibrate(ms(50), 60.0, 0.25)
+            .repeat(HapticRepeat::Forever);
+        let low = player
+            .play(HapticTarget::action(haptic), rumble.clone(), 0, t0)
+            .unwrap();
+        player.update(t0, &mut input, &system).unwrap();
+        let hit = HapticPattern::new().vibrate(ms(10), 300.0, 1.0);
+        let high = player
+            .play(HapticTarget::action(haptic), hit.clone(), 5, t0 + ms(5))
+            .unwrap();
+        assert!(!player.is_playing(low));
+        assert_eq!(
+            player.play(HapticTarget::action(haptic), rumble, 1, t0 + ms(6)),
+            None
+        );
+        player.update(t0 + ms(5), &mut input, &system).unwrap();
+        let issued = runtime.haptic_vibrations();
+        // The rumble, a stop for it, then the hit.
+        assert_eq!(issued.len(), 3);
+        assert_eq!(
+            (issued[1].duration_seconds, issued[1].amplitude),
+            (0.0, 0.0)
+        );
+        assert_eq!(issued[2].frequency, 300.0);
+        assert!(player.cancel(high));
+        // A stop that fails to send is kept and sent on the next update.
+        runtime.fail_next_haptic_vibration(sys::EVRInputError_VRInputError_InvalidHandle);
+        assert_eq!(
+            player.update(t0 + ms(6), &mut input, &system),
+            Err(VRInputError::InvalidHandle)
+        );
+        assert_eq!(runtime.haptic_vibrations().len(), 3);
+        player.update(t0 + ms(6), &mut input, &system).unwrap();
+        assert_eq!(runtime.haptic_vibrations()[3].amplitude, 0.0);
+
+        // Device targets pulse every update for as long as a segment lasts.
+        player.play(HapticTarget::device(hand), hit, 0, t0);
+        player.update(t0, &mut input, &system).unwrap();
+        player.update(t0 + ms(5), &mut input, &system).unwrap();
+        player.update(t0 + ms(10), &mut input, &system).unwrap();
+        assert_eq!(runtime.haptic_pulses(), [(hand, 0, 3999), (hand, 0, 3999)]);
+        assert_eq!(player.active_count(), 0);
+    }
+}
@@ -0,0 +1,657 @@
+//! Building SteamVR action manifests in code instead of maintaining the JSON by hand.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{error, fs, io};
+
+use super::VRActionSetHandle;
+use crate::errors::VRInputError;
+use crate::Input;
+
+/// Value type of an action. Decides which `Input::get_*_action_data` call reads it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ActionType {
+    Boolean,
+    Vector1,
+    Vector2,
+    Vector3,
+    /// Haptic output. Vibration actions live under `/out/`, all others under `/in/`.
+    Vibration,
+    Pose,
+    /// Needs a skeleton path, see `Action::with_skeleton`.
+    Skeleton,
+}
+
+impl ActionType {
+    fn as_str(self) -> &'static str {
+        match self {
+            ActionType::Boolean => "boolean",
+            ActionType::Vector1 => "vector1",
+            ActionType::Vector2 => "vector2",
+            ActionType::Vector3 => "vector3",
+            ActionType::Vibration => "vibration",
+            ActionType::Pose => "pose",
+            ActionType::Skeleton => "skeleton",
+        }
+    }
+}
+
+/// How strongly SteamVR nags the user when an action is left unbound.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum ActionRequirement {
+    Mandatory,
+    #[default]
+    Suggested,
+    Optional,
+}
+
+impl ActionRequirement {
+    fn as_str(self) -> &'static str {
+        match self {
+            ActionRequirement::Mandatory => "mandatory",
+            ActionRequirement::Suggested => "suggested",
+            ActionRequirement::Optional => "optional",
+        }
+    }
+}
+
+/// How the binding UI presents an action set.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum ActionSetUsage {
+    /// Actions can be bound to each hand separately.
+    #[default]
+    LeftRight,
+    /// Actions are bound once, to either hand.
+    Single,
+    /// Not shown in the binding UI.
+    Hidden,
+}
+
+impl ActionSetUsage {
+    fn as_str(self) -> &'static str {
+        match self {
+            ActionSetUsage::LeftRight => "leftright",
+            ActionSetUsage::Single => "single",
+            ActionSetUsage::Hidden => "hidden",
+        }
+    }
+}
+
+/// An action declared in an `ActionManifest`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Action {
+    /// Full path, e.g. `/actions/main/in/grab`.
+    pub name: String,
+    pub ty: ActionType,
+    pub requirement: ActionRequirement,
+    /// `/skeleton/hand/left` or `/skeleton/hand/right`, for skeleton actions.
+    pub skeleton: Option<String>,
+}
+
+impl Action {
+    pub fn new(name: impl Into<String>, ty: ActionType) -> Self {
+        Action {
+            name: name.into(),
+            ty,
+            requirement: ActionRequirement::default(),
+            skeleton: None,
+        }
+    }
+
+    pub fn with_requirement(mut self, requirement: ActionRequirement) -> Self {
+        self.requirement = requirement;
+        self
+    }
+
+    pub fn with_skeleton(mut self, skeleton: impl Into<String>) -> Self {
+        self.skeleton = Some(skeleton.into());
+        self
+    }
+}
+
+/// A binding file SteamVR uses for a controller type until the user picks another one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefaultBinding {
+    /// e.g. `knuckles`, `vive_controller` or `oculus_touch`
+    pub controller_type: String,
+    /// Relative paths are resolved against the directory the manifest is written to.
+    pub binding_url: PathBuf,
+}
+
+/// Why an `ActionManifest` was rejected, or couldn't be loaded.
+#[derive(Debug)]
+pub enum ManifestError {
+    /// An action set name is not of the form `/actions/<set>`.
+    InvalidActionSetName(String),
+    /// An action name is not of the form `/actions/<set>/in/<action>` or
+    /// `/actions/<set>/out/<action>`.
+    InvalidActionName(String),
+    /// An action belongs to a set that wasn't declared.
+    UnknownActionSet(String),
+    /// A set or action is declared twice. Paths are case-insensitive.
+    Duplicate(String),
+    /// A vibration action under `/in/`, or another type under `/out/`.
+    WrongDirection(String),
+    /// A skeleton action without a valid skeleton path, or another type with one.
+    InvalidSkeleton(String),
+    /// A localized string for a path that isn't a declared set or action.
+    UnknownLocalization { language: String, path: String },
+    /// Writing the manifest file failed.
+    Io(io::Error),
+    /// The runtime rejected the manifest.
+    Input(VRInputError),
+}
+
+impl error::Error for ManifestError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ManifestError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ManifestError::InvalidActionSetName(ref x) => {
+                write!(f, "invalid action set name {:?}", x)
+            }
+            ManifestError::InvalidActionName(ref x) => write!(f, "invalid action name {:?}", x),
+            ManifestError::UnknownActionSet(ref x) => {
+                write!(f, "action {:?} belongs to an undeclared set", x)
+            }
+            ManifestError::Duplicate(ref x) => write!(f, "{:?} is declared more than once", x),
+            ManifestError::WrongDirection(ref x) => {
+                write!(
+                    f,
+                    "action {:?} must be under /out/ if and only if it is a vibration",
+                    x
+                )
+            }
+            ManifestError::InvalidSkeleton(ref x) => {
+                write!(f, "action {:?} has an invalid skeleton", x)
+            }
+            ManifestError::UnknownLocalization {
+                ref language,
+                ref path,
+            } => write!(f, "{} localization names undeclared {:?}", language, path),
+            ManifestError::Io(ref e) => write!(f, "failed to write action manifest: {}", e),
+            ManifestError::Input(e) => write!(f, "runtime rejected action manifest: {:?}", e),
+        }
+    }
+}
+
+impl From<io::Error> for ManifestError {
+    fn from(x: io::Error) -> Self {
+        ManifestError::Io(x)
+    }
+}
+
+impl From<VRInputError> for ManifestError {
+    fn from(x: VRInputError) -> Self {
+        ManifestError::Input(x)
+    }
+}
+
+/// Contents of an action manifest, the file passed to `Input::set_action_manifest`.
+///
+/// ```
+/// use openvr::input::{Action, ActionManifest, ActionRequirement, ActionSetUsage, ActionType};
+///
+/// let manifest = ActionManifest::new()
+///     .with_action_set("/actions/main", ActionSetUsage::LeftRight)
+///     .with_action(
+///         Action::new("/actions/main/in/grab", ActionType::Boolean)
+///             .with_requirement(ActionRequirement::Mandatory),
+///     )
+///     .with_action(Action::new("/actions/main/out/haptic", ActionType::Vibration))
+///     .with_localization("en_US", "/actions/main/in/grab", "Grab")
+///     .with_default_binding("knuckles", "bindings_knuckles.json");
+/// manifest.validate().unwrap();
+/// assert!(manifest.to_json().contains(r#""name": "/actions/main/in/grab""#));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActionManifest {
+    pub action_sets: Vec<(String, ActionSetUsage)>,
+    pub actions: Vec<Action>,
+    pub default_bindings: Vec<DefaultBinding>,
+    /// Display names of sets and actions, by language tag (e.g. `en_US`) and then path.
+    pub localization: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl ActionManifest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_action_set(mut self, name: impl Into<String>, usage: ActionSetUsage) -> Self {
+        self.action_sets.push((name.into(), usage));
+        self
+    }
+
+    pub fn with_action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    pub fn with_default_binding(
+        mut self,
+        controller_type: impl Into<String>,
+        binding_url: impl Into<PathBuf>,
+    ) -> Self {
+        self.default_bindings.push(DefaultBinding {
+            controller_type: controller_type.into(),
+            binding_url: binding_url.into(),
+        });
+        self
+    }
+
+    /// Adds the set and actions declared by `T`.
+    pub fn with_set<T: ActionSet>(self) -> Self {
+        T::add_to_manifest(self)
+    }
+
+    /// Sets the name shown for the set or action at `path` in `language`.
+    pub fn with_localization(
+        mut self,
+        language: impl Into<String>,
+        path: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        self.localization
+            .entry(language.into())
+            .or_default()
+            .insert(path.into(), text.into());
+        self
+    }
+
+    /// Whether `name` is declared as an action. Paths are compared case-insensitively, like SteamVR
+    /// does.
+    pub fn contains_action(&self, name: &str) -> bool {
+        self.actions
+            .iter()
+            .any(|x| x.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks everything SteamVR would otherwise only report when the actions are looked up.
+    pub fn validate(&self) -> Result<(), ManifestError> {
+        let mut seen = HashSet::new();
+        let mut sets = HashSet::new();
+        for (name, _) in &self.action_sets {
+            if parse_action_set_name(name).is_none() {
+                return Err(ManifestError::InvalidActionSetName(name.clone()));
+            }
+            if !seen.insert(name.to_ascii_lowercase()) {
+                return Err(ManifestError::Duplicate(name.clone()));
+            }
+            sets.insert(name.to_ascii_lowercase());
+        }
+        for action in &self.actions {
+            let (set, direction) = parse_action_name(&action.name)
+                .ok_or_else(|| ManifestError::InvalidActionName(action.name.clone()))?;
+            if !sets.contains(&set.to_ascii_lowercase()) {
+                return Err(ManifestError::UnknownActionSet(action.name.clone()));
+            }
+            if !seen.insert(action.name.to_ascii_lowercase()) {
+                return Err(ManifestError::Duplicate(action.name.clone()));
+            }
+            if (action.ty == ActionType::Vibration) != direction.eq_ignore_ascii_case("out") {
+                return Err(ManifestError::WrongDirection(action.name.clone()));
+            }
+            let skeleton_ok = match action.skeleton {
+                Some(ref x) => action.ty == ActionType::Skeleton && is_skeleton_path(x),
+                None => action.ty != ActionType::Skeleton,
+            };
+            if !skeleton_ok {
+                return Err(ManifestError::InvalidSkeleton(action.name.clone()));
+            }
+        }
+        for (language, strings) in &self.localization {
+            if let Some(path) = strings
+                .keys()
+                .find(|x| !seen.contains(&x.to_ascii_lowercase()))
+            {
+                return Err(ManifestError::UnknownLocalization {
+                    language: language.clone(),
+                    path: path.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The manifest in SteamVR's JSON format. Binding URLs are written as given.
+    pub fn to_json(&self) -> String {
+        self.to_json_with(|x| x.to_path_buf())
+    }
+
+    fn to_json_with(&self, binding_url: impl Fn(&Path) -> PathBuf) -> String {
+        let mut out = String::from("{\n  \"action_sets\": [");
+        for (i, (name, usage)) in self.action_sets.iter().enumerate() {
+            out += if i == 0 { "\n" } else { ",\n" };
+            write_object(&mut out, &[("name", name), ("usage", usage.as_str())]);
+        }
+        out += "\n  ],\n  \"actions\": [";
+        for (i, action) in self.actions.iter().enumerate() {
+            out += if i == 0 { "\n" } else { ",\n" };
+            let mut fields = vec![
+                ("name", action.name.as_str()),
+                ("type", action.ty.as_str()),
+                ("requirement", action.requirement.as_str()),
+            ];
+            if let Some(ref x) = action.skeleton {
+                fields.push(("skeleton", x));
+            }
+            write_object(&mut out, &fields);
+        }
+        out += "\n  ],\n  \"default_bindings\": [";
+        for (i, binding) in self.default_bindings.iter().enumerate() {
+            out += if i == 0 { "\n" } else { ",\n" };
+            let url = binding_url(&binding.binding_url);
+            let url = url.to_string_lossy().replace('\\', "/");
+            write_object(
+                &mut out,
+                &[
+                    ("controller_type", &binding.controller_type),
+                    ("binding_url", &url),
+                ],
+            );
+        }
+        out += "\n  ],\n  \"localization\": [";
+        for (i, (language, strings)) in self.localization.iter().enumerate() {
+            out += if i == 0 { "\n" } else { ",\n" };
+            let mut fields = vec![("language_tag", language.as_str())];
+            fields.extend(strings.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+            write_object(&mut out, &fields);
+        }
+        out += "\n  ]\n}\n";
+        out
+    }
+
+    /// Validates the manifest and writes it to `path`.
+    ///
+    /// Relative binding URLs are written as given, so they must be relative to the directory of
+    /// `path`.
+    pub fn write(&self, path: &Path) -> Result<(), ManifestError> {
+        self.validate()?;
+        fs::write(path, self.to_json())?;
+        Ok(())
+    }
+
+    /// Validates the manifest and writes it to a new directory in the system temp directory.
+    ///
+    /// The directory is created fresh, readable only by the current user on Unix, and removed with
+    /// its contents when the returned `TempManifest` is dropped. Relative binding URLs are made
+    /// absolute against the current directory, so they keep pointing at the same files.
+    pub fn write_temp(&self) -> Result<TempManifest, ManifestError> {
+        self.validate()?;
+        let cwd = std::env::current_dir()?;
+        let manifest = TempManifest::create()?;
+        fs::write(&manifest.path, self.to_json_with(|x| cwd.join(x)))?;
+        Ok(manifest)
+    }
+}
+
+/// An action manifest written by `ActionManifest::write_temp`, removed along with its directory
+/// when dropped.
+///
+/// SteamVR keeps reading the manifest from its path, e.g. for `Input::open_binding_ui` and when
+/// bindings are reloaded, so a manifest passed to the runtime must be kept for as long as the
+/// session lasts.
+#[must_use = "the manifest is deleted when this is dropped"]
+#[derive(Debug)]
+pub struct TempManifest {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl TempManifest {
+    /// Creates an empty directory that did not exist before, so no other process can have placed
+    /// files in it.
+    fn create() -> io::Result<Self> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        loop {
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .subsec_nanos();
+            let dir = std::env::temp_dir().join(format!(
+                "openvr-actions-{}-{}-{:08x}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed),
+                nanos
+            ));
+            match builder.create(&dir) {
+                Ok(()) => {
+                    let path = dir.join("actions.json");
+                    return Ok(TempManifest { dir, path });
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Path of the manifest file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempManifest {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// A struct holding the handles of an action set and its actions, usually implemented with
+/// `#[derive(ActionSet)]` from the `derive` feature.
+///
+/// The same declaration provides the manifest entries and the handle lookups, so they can't go out
+/// of sync.
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use openvr::input::{ActionManifest, ActionSet, VRActionHandle, VRActionSetHandle};
+///
+/// #[derive(ActionSet)]
+/// #[action_set(path = "/actions/main", usage = LeftRight)]
+/// struct MainActions {
+///     #[action_set]
+///     set: VRActionSetHandle,
+///     #[action(path = "in/grab", ty = Boolean, requirement = Mandatory, localized = "Grab")]
+///     grab: VRActionHandle,
+///     #[action(path = "in/hand_left", ty = Skeleton, skeleton = "/skeleton/hand/left")]
+///     hand_left: VRActionHandle,
+/// }
+///
+/// let manifest = ActionManifest::new().with_set::<MainActions>();
+/// assert!(manifest.contains_action("/actions/main/in/grab"));
+/// # }
+/// ```
+pub trait ActionSet: Sized {
+    /// Path of the action set, e.g. `/actions/main`.
+    const PATH: &'static str;
+
+    /// Adds the set and its actions to `manifest`.
+    fn add_to_manifest(manifest: ActionManifest) -> ActionManifest;
+
+    /// Looks up the handles of the set and all of its actions.
+    fn load(input: &mut Input) -> Result<Self, VRInputError>;
+
+    /// Looks up the handle of the set alone.
+    fn set_handle(input: &mut Input) -> Result<VRActionSetHandle, VRInputError> {
+        input.get_action_set_handle(Self::PATH)
+    }
+}
+
+impl Input<'_> {
+    /// Validates `manifest`, writes it with `ActionManifest::write_temp` and loads it with
+    /// `set_action_manifest`.
+    ///
+    /// Returns the written file, which is removed when it is dropped. It must be kept for as long as
+    /// the session lasts, since SteamVR keeps reading the manifest from its path.
+    #[must_use = "the manifest is deleted when the returned `TempManifest` is dropped"]
+    pub fn set_action_manifest_from(
+        &mut self,
+        manifest: &ActionManifest,
+    ) -> Result<TempManifest, ManifestError> {
+        let written = manifest.write_temp()?;
+        self.set_action_manifest(written.path())?;
+        Ok(written)
+    }
+}
+
+/// Splits `/actions/<set>` into the set's name, if it is a valid action set path.
+fn parse_action_set_name(name: &str) -> Option<&str> {
+    let set = name.strip_prefix("/actions/")?;
+    is_identifier(set).then_some(set)
+}
+
+/// Splits `/actions/<set>/<direction>/<action>` into `/actions/<set>` and the direction, if it is a
+/// valid action path.
+fn parse_action_name(name: &str) -> Option<(&str, &str)> {
+    let rest = name.strip_prefix("/actions/")?;
+    let mut parts = rest.split('/');
+    let (set, direction, action) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() || !is_identifier(set) || !is_identifier(action) {
+        return None;
+    }
+    if !direction.eq_ignore_ascii_case("in") && !direction.eq_ignore_ascii_case("out") {
+        return None;
+    }
+    Some((
+        &name[..name.len() - direction.len() - action.len() - 2],
+        direction,
+    ))
+}
+
+fn is_identifier(x: &str) -> bool {
+    !x.is_empty()
+        && x.bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'_' || c == b'-')
+}
+
+fn is_skeleton_path(x: &str) -> bool {
+    x.eq_ignore_ascii_case("/skeleton/hand/left") || x.eq_ignore_ascii_case("/skeleton/hand/right")
+}
+
+fn write_object(out: &mut String, fields: &[(&str, &str)]) {
+    out.push_str("    {");
+    for (i, (key, value)) in fields.iter().enumerate() {
+        out.push_str(if i == 0 { "\n      " } else { ",\n      " });
+        write_string(out, key);
+        out.push_str(": ");
+        write_string(out, value);
+    }
+    out.push_str("\n    }");
+}
+
+fn write_string(out: &mut String, x: &str) {
+    out.push('"');
+    for c in x.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockRuntime;
+    use crate::Context;
+
+    fn manifest() -> ActionManifest {
+        ActionManifest::new()
+            .with_action_set("/actions/main", ActionSetUsage::LeftRight)
+            .with_action(Action::new("/actions/main/in/grab", ActionType::Boolean))
+            .with_action(
+                Action::new("/actions/main/in/hand_left", ActionType::Skeleton)
+                    .with_skeleton("/skeleton/hand/left"),
+            )
+            .with_action(Action::new(
+                "/actions/main/out/haptic",
+                ActionType::Vibration,
+            ))
+    }
+
+    #[test]
+    fn rejects_bad_paths() {
+        assert!(manifest().validate().is_ok());
+        let bad = |m: ActionManifest| m.validate().unwrap_err();
+        assert!(matches!(
+            bad(manifest().with_action(Action::new("/actions/main/in/gr ab", ActionType::Boolean))),
+            ManifestError::InvalidActionName(_)
+        ));
+        assert!(matches!(
+            bad(manifest().with_action(Action::new("/actions/mian/in/grab", ActionType::Boolean))),
+            ManifestError::UnknownActionSet(_)
+        ));
+        assert!(matches!(
+            bad(manifest().with_action(Action::new("/actions/main/in/Grab", ActionType::Vector1))),
+            ManifestError::Duplicate(_)
+        ));
+        let buzz = Action::new("/actions/main/in/buzz", ActionType::Vibration);
+        assert!(matches!(
+            bad(manifest().with_action(buzz)),
+            ManifestError::WrongDirection(_)
+        ));
+        assert!(matches!(
+            bad(manifest().with_action(Action::new(
+                "/actions/main/in/hand_right",
+                ActionType::Skeleton
+            ))),
+            ManifestError::InvalidSkeleton(_)
+        ));
+        assert!(matches!(
+            bad(manifest().with_action_set("actions/other", ActionSetUsage::Single)),
+            ManifestError::InvalidActionSetName(_)
+        ));
+        assert!(matches!(
+            bad(manifest().with_localization("en_US", "/actions/main/in/jump", "Jump")),
+            ManifestError::UnknownLocalization { .. }
+        ));
+    }
+
+    #[test]
+    fn loads_through_temp_file() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let mut input = context.input().unwrap();
+        let manifest = manifest()
+            .with_localization("en_US", "/actions/main/in/grab", "Grab \"it\"")
+            .with_default_binding("knuckles", "bindings/knuckles.json");
+        let written = input.set_action_manifest_from(&manifest).unwrap();
+        let path = written.path().to_owned();
+        assert_eq!(runtime.action_manifest().as_deref(), Some(path.as_path()));
+        assert_ne!(manifest.write_temp().unwrap().path(), path);
+
+        let json = fs::read_to_string(&path).unwrap();
+        assert!(json.contains(r#""/actions/main/in/grab": "Grab \"it\"""#));
+        assert!(json.contains(r#""skeleton": "/skeleton/hand/left""#));
+        let binding = std::env::current_dir()
+            .unwrap()
+            .join("bindings/knuckles.json");
+        assert!(json.contains(&binding.to_string_lossy().replace('\\', "/")));
+
+        drop(written);
+        assert!(!path.exists() && !path.parent().unwrap().exists());
+    }
+}
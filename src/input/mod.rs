@@ -0,0 +1,746 @@
+use enumset::{EnumSet, EnumSetType};
+
+use crate::render_models::ComponentState;
+use crate::{errors::VRInputError, pose, Compositor, Input, TrackedDeviceIndex, TrackedDevicePose};
+
+use std::{
+    ffi::{CStr, CString},
+    mem::MaybeUninit,
+    os::raw::c_char,
+    path::Path,
+    time::Duration,
+};
+
+#[cfg(feature = "derive")]
+pub use openvr_derive::ActionSet;
+
+mod haptics;
+mod manifest;
+mod skeleton;
+mod snapshot;
+
+pub use self::haptics::{
+    HapticId, HapticPattern, HapticPlayer, HapticRepeat, HapticSegment, HapticTarget,
+};
+pub use self::manifest::{
+    Action, ActionManifest, ActionRequirement, ActionSet, ActionSetUsage, ActionType,
+    DefaultBinding, ManifestError, TempManifest,
+};
+pub use self::skeleton::{
+    BoneIndex, BoneTransform, CompressedSkeleton, SkeletalActionData, SkeletalMotionRange,
+    SkeletalReferencePose, SkeletalSummary, SkeletalTrackingLevel, SkeletalTransformSpace,
+    SummaryType,
+};
+pub use self::snapshot::{ActionState, ActionStateSnapshot, ActionValue};
+
+/// Most bindings `Input::get_action_binding_info` makes room for.
+pub const MAX_BINDING_INFOS: usize = 1024;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum TrackedControllerRole {
+    Invalid = openvr_sys::ETrackedControllerRole_TrackedControllerRole_Invalid as isize,
+    LeftHand = openvr_sys::ETrackedControllerRole_TrackedControllerRole_LeftHand as isize,
+    RightHand = openvr_sys::ETrackedControllerRole_TrackedControllerRole_RightHand as isize,
+    OptOut = openvr_sys::ETrackedControllerRole_TrackedControllerRole_OptOut as isize,
+    Treadmill = openvr_sys::ETrackedControllerRole_TrackedControllerRole_Treadmill as isize,
+    Stylus = openvr_sys::ETrackedControllerRole_TrackedControllerRole_Stylus as isize,
+}
+impl From<openvr_sys::ETrackedControllerRole> for TrackedControllerRole {
+    fn from(item: openvr_sys::ETrackedControllerRole) -> Self {
+        match item {
+            openvr_sys::ETrackedControllerRole_TrackedControllerRole_LeftHand => Self::LeftHand,
+            openvr_sys::ETrackedControllerRole_TrackedControllerRole_RightHand => Self::RightHand,
+            openvr_sys::ETrackedControllerRole_TrackedControllerRole_OptOut => Self::OptOut,
+            openvr_sys::ETrackedControllerRole_TrackedControllerRole_Treadmill => Self::Treadmill,
+            openvr_sys::ETrackedControllerRole_TrackedControllerRole_Stylus => Self::Stylus,
+            _ => Self::Invalid,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct VRActionHandle(pub openvr_sys::VRActionHandle_t);
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VRActionSetHandle(pub openvr_sys::VRActionHandle_t);
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VRInputValueHandle(pub openvr_sys::VRInputValueHandle_t);
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct VRActiveActionSet(pub openvr_sys::VRActiveActionSet_t);
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct VRDigitalActionData(pub openvr_sys::InputDigitalActionData_t);
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct VRAnalogActionData(pub openvr_sys::InputAnalogActionData_t);
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct VRPoseActionData(pub openvr_sys::InputPoseActionData_t);
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct VROriginInfo(pub openvr_sys::InputOriginInfo_t);
+type Result<T> = std::result::Result<T, VRInputError>;
+
+impl VRDigitalActionData {
+    /// Whether the action is bound and its action set is active.
+    pub fn active(&self) -> bool {
+        self.0.bActive
+    }
+    pub fn active_origin(&self) -> VRInputValueHandle {
+        VRInputValueHandle(self.0.activeOrigin)
+    }
+    pub fn state(&self) -> bool {
+        self.0.bState
+    }
+    /// Whether `state` changed since the previous `update_actions`.
+    pub fn changed(&self) -> bool {
+        self.0.bChanged
+    }
+    /// When the state last changed, in seconds relative to now (i.e. zero or negative).
+    pub fn update_time(&self) -> f32 {
+        self.0.fUpdateTime
+    }
+}
+
+impl VRAnalogActionData {
+    pub fn active(&self) -> bool {
+        self.0.bActive
+    }
+    pub fn active_origin(&self) -> VRInputValueHandle {
+        VRInputValueHandle(self.0.activeOrigin)
+    }
+    /// Current value. Axes the action doesn't have are 0.
+    pub fn value(&self) -> [f32; 3] {
+        [self.0.x, self.0.y, self.0.z]
+    }
+    /// Change of `value` since the previous `update_actions`.
+    pub fn delta(&self) -> [f32; 3] {
+        [self.0.deltaX, self.0.deltaY, self.0.deltaZ]
+    }
+    pub fn update_time(&self) -> f32 {
+        self.0.fUpdateTime
+    }
+}
+
+impl VRPoseActionData {
+    pub fn active(&self) -> bool {
+        self.0.bActive
+    }
+    pub fn active_origin(&self) -> VRInputValueHandle {
+        VRInputValueHandle(self.0.activeOrigin)
+    }
+    pub fn pose(&self) -> TrackedDevicePose {
+        TrackedDevicePose(self.0.pose)
+    }
+}
+
+impl VROriginInfo {
+    /// Handle of the device the origin is on, e.g. `/user/hand/left`.
+    pub fn device_path(&self) -> VRInputValueHandle {
+        VRInputValueHandle(self.0.devicePath)
+    }
+    pub fn tracked_device_index(&self) -> TrackedDeviceIndex {
+        TrackedDeviceIndex(self.0.trackedDeviceIndex)
+    }
+    /// Render model component to highlight for the origin, e.g. `trigger`.
+    pub fn render_model_component_name(&self) -> String {
+        from_c_chars(&self.0.rchRenderModelComponentName)
+    }
+}
+
+/// Where an action is bound, as returned by `Input::get_action_binding_info`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BindingInfo {
+    /// e.g. `/user/hand/right`
+    pub device_path: String,
+    /// e.g. `/input/trigger`
+    pub input_path: String,
+    /// e.g. `button` or `trigger`
+    pub mode: String,
+    /// e.g. `click` or `pull`
+    pub slot: String,
+    /// e.g. `button` or `trigger`
+    pub source_type: String,
+}
+
+impl From<&openvr_sys::InputBindingInfo_t> for BindingInfo {
+    fn from(x: &openvr_sys::InputBindingInfo_t) -> Self {
+        BindingInfo {
+            device_path: from_c_chars(&x.rchDevicePathName),
+            input_path: from_c_chars(&x.rchInputPathName),
+            mode: from_c_chars(&x.rchModeName),
+            slot: from_c_chars(&x.rchSlotName),
+            source_type: from_c_chars(&x.rchInputSourceType),
+        }
+    }
+}
+
+impl From<&BindingInfo> for openvr_sys::InputBindingInfo_t {
+    /// Strings too long for the fixed-size fields are truncated.
+    fn from(x: &BindingInfo) -> Self {
+        let mut raw = openvr_sys::InputBindingInfo_t::default();
+        to_c_chars(&x.device_path, &mut raw.rchDevicePathName);
+        to_c_chars(&x.input_path, &mut raw.rchInputPathName);
+        to_c_chars(&x.mode, &mut raw.rchModeName);
+        to_c_chars(&x.slot, &mut raw.rchSlotName);
+        to_c_chars(&x.source_type, &mut raw.rchInputSourceType);
+        raw
+    }
+}
+
+/// Reads a nul-terminated string out of a fixed-size C array.
+fn from_c_chars(x: &[c_char]) -> String {
+    let bytes: Vec<u8> = x.iter().map(|&c| c as u8).take_while(|&c| c != 0).collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Writes `x` into a fixed-size C array, truncating it to leave room for the nul terminator.
+fn to_c_chars(x: &str, out: &mut [c_char]) {
+    let len = x.len().min(out.len() - 1);
+    for (o, &c) in out.iter_mut().zip(&x.as_bytes()[..len]) {
+        *o = c as c_char;
+    }
+    out[len] = 0;
+}
+#[derive(EnumSetType, Debug)]
+#[enumset(repr = "u32")]
+pub enum InputString {
+    Hand,
+    ControllerType,
+    InputSource,
+    // TODO: openvr allows you to pass a u32 with all bits set to get a string that has all information, current and future.
+    //       is there a good way to represent that with enumset? do we care?
+}
+
+impl Input<'_> {
+    // ---- Handle Management ----
+
+    pub fn set_action_manifest(&mut self, path: &Path) -> Result<()> {
+        let path = if let Ok(s) = CString::new(path.to_string_lossy().as_bytes()) {
+            s
+        } else {
+            return Err(VRInputError::InvalidParam);
+        };
+        self.set_action_manifest_raw(&path)
+    }
+
+    pub fn set_action_manifest_raw(&mut self, path: &CStr) -> Result<()> {
+        let err = unsafe { self.0.SetActionManifestPath.unwrap()(path.as_ptr().cast_mut().cast()) };
+        VRInputError::new(err)
+    }
+
+    pub fn get_action_set_handle(&mut self, name: &str) -> Result<VRActionSetHandle> {
+        let name = if let Ok(s) = CString::new(name) {
+            s
+        } else {
+            return Err(VRInputError::InvalidParam);
+        };
+
+        self.get_action_set_handle_raw(&name)
+    }
+
+    pub fn get_action_set_handle_raw(&mut self, name: &CStr) -> Result<VRActionSetHandle> {
+        let mut handle: VRActionSetHandle = VRActionSetHandle(0);
+
+        let err = unsafe {
+            self.0.GetActionSetHandle.unwrap()(name.as_ptr().cast_mut().cast(), &mut handle.0)
+        };
+
+        VRInputError::new(err)?;
+        Ok(handle)
+    }
+
+    pub fn get_action_handle(&mut self, name: &str) -> Result<VRActionHandle> {
+        let name = if let Ok(s) = CString::new(name) {
+            s
+        } else {
+            return Err(VRInputError::InvalidParam);
+        };
+
+        self.get_action_handle_raw(&name)
+    }
+
+    pub fn get_action_handle_raw(&mut self, name: &CStr) -> Result<VRActionHandle> {
+        let mut handle: VRActionHandle = VRActionHandle(0);
+
+        let err = unsafe {
+            self.0.GetActionHandle.unwrap()(name.as_ptr().cast_mut().cast(), &mut handle.0)
+        };
+
+        VRInputError::new(err)?;
+        Ok(handle)
+    }
+
+    pub fn get_input_source_handle(&mut self, name: &str) -> Result<VRInputValueHandle> {
+        let name = if let Ok(s) = CString::new(name) {
+            s
+        } else {
+            return Err(VRInputError::InvalidParam);
+        };
+
+        self.get_input_source_handle_raw(&name)
+    }
+
+    pub fn get_input_source_handle_raw(&mut self, name: &CStr) -> Result<VRInputValueHandle> {
+        let mut handle: VRInputValueHandle = VRInputValueHandle(0);
+
+        let err = unsafe {
+            self.0.GetInputSourceHandle.unwrap()(name.as_ptr().cast_mut().cast(), &mut handle.0)
+        };
+
+        VRInputError::new(err)?;
+        Ok(handle)
+    }
+
+    // ---- Read Action State ----
+
+    pub fn update_actions(&mut self, sets: &mut [VRActiveActionSet]) -> Result<()> {
+        let err = unsafe {
+            self.0.UpdateActionState.unwrap()(
+                sets.as_mut_ptr().cast(),
+                size_of::<VRActiveActionSet>() as u32,
+                sets.len() as u32,
+            )
+        };
+
+        VRInputError::new(err)
+    }
+
+    pub fn get_digital_action_data(
+        &mut self,
+        action: VRActionHandle,
+        restrict: VRInputValueHandle,
+    ) -> Result<VRDigitalActionData> {
+        let mut data: MaybeUninit<VRDigitalActionData> = MaybeUninit::uninit();
+        let err = unsafe {
+            self.0.GetDigitalActionData.unwrap()(
+                action.0,
+                data.as_mut_ptr().cast(),
+                size_of::<VRDigitalActionData>() as u32,
+                restrict.0,
+            )
+        };
+        VRInputError::new(err)?;
+        Ok(VRDigitalActionData(unsafe { data.assume_init().0 }))
+    }
+
+    pub fn get_analog_action_data(
+        &mut self,
+        action: VRActionHandle,
+        restrict: VRInputValueHandle,
+    ) -> Result<VRAnalogActionData> {
+        let mut data: MaybeUninit<VRAnalogActionData> = MaybeUninit::uninit();
+        let err = unsafe {
+            self.0.GetAnalogActionData.unwrap()(
+                action.0,
+                data.as_mut_ptr().cast(),
+                size_of::<VRAnalogActionData>() as u32,
+                restrict.0,
+            )
+        };
+        VRInputError::new(err)?;
+        Ok(VRAnalogActionData(unsafe { data.assume_init().0 }))
+    }
+
+    pub fn get_pose_action_data_relative_to_now(
+        &mut self,
+        action: VRActionHandle,
+        universe: pose::TrackingUniverseOrigin,
+        seconds_from_now: f32,
+        restrict: VRInputValueHandle,
+    ) -> Result<VRPoseActionData> {
+        let mut data: MaybeUninit<VRPoseActionData> = MaybeUninit::uninit();
+        let err = unsafe {
+            self.0.GetPoseActionDataRelativeToNow.unwrap()(
+                action.0,
+                universe,
+                seconds_from_now,
+                data.as_mut_ptr().cast(),
+                size_of::<VRPoseActionData>() as u32,
+                restrict.0,
+            )
+        };
+
+        VRInputError::new(err)?;
+        Ok(VRPoseActionData(unsafe { data.assume_init().0 }))
+    }
+
+    /// Like `get_pose_action_data_relative_to_now`, predicted for the frame of the last
+    /// `Compositor::wait_get_poses`, i.e. to match its `render` poses.
+    pub fn get_pose_action_data_for_next_frame(
+        &mut self,
+        action: VRActionHandle,
+        universe: pose::TrackingUniverseOrigin,
+        restrict: VRInputValueHandle,
+    ) -> Result<VRPoseActionData> {
+        let mut data: MaybeUninit<VRPoseActionData> = MaybeUninit::uninit();
+        let err = unsafe {
+            self.0.GetPoseActionDataForNextFrame.unwrap()(
+                action.0,
+                universe,
+                data.as_mut_ptr().cast(),
+                size_of::<VRPoseActionData>() as u32,
+                restrict.0,
+            )
+        };
+
+        VRInputError::new(err)?;
+        Ok(VRPoseActionData(unsafe { data.assume_init().0 }))
+    }
+
+    /// Poses of `actions` for rendering the current frame, in the same order.
+    ///
+    /// Call after `Compositor::wait_get_poses`. The poses are predicted for the same time as its
+    /// `render` poses and are in `compositor`'s tracking space, so they can be combined with device
+    /// poses directly.
+    pub fn get_frame_action_poses(
+        &mut self,
+        compositor: &Compositor,
+        actions: &[VRActionHandle],
+    ) -> Result<Vec<VRPoseActionData>> {
+        let universe = compositor.tracking_space() as pose::TrackingUniverseOrigin;
+        actions
+            .iter()
+            .map(|&x| self.get_pose_action_data_for_next_frame(x, universe, VRInputValueHandle(0)))
+            .collect()
+    }
+
+    // ---- Action Origins ----
+
+    /// Input sources bound to `digital_action_handle` in `action_set`. The runtime reports at most
+    /// `k_unMaxActionOriginCount` (16).
+    pub fn get_action_origins(
+        &mut self,
+        action_set: VRActionSetHandle,
+        digital_action_handle: VRActionHandle,
+    ) -> Result<Vec<VRInputValueHandle>> {
+        let mut origins = vec![
+            openvr_sys::k_ulInvalidInputValueHandle;
+            openvr_sys::k_unMaxActionOriginCount as usize
+        ];
+        let err = unsafe {
+            self.0.GetActionOrigins.unwrap()(
+                action_set.0,
+                digital_action_handle.0,
+                origins.as_mut_ptr(),
+                origins.len() as u32,
+            )
+        };
+        VRInputError::new(err)?;
+        Ok(origins
+            .into_iter()
+            .take_while(|&x| x != openvr_sys::k_ulInvalidInputValueHandle)
+            .map(VRInputValueHandle)
+            .collect())
+    }
+
+    pub fn get_origin_localized_name(
+        &mut self,
+        origin: VRInputValueHandle,
+        bits: EnumSet<InputString>,
+    ) -> Result<String> {
+        let mut name: [::std::os::raw::c_char; 128usize] = unsafe { ::std::mem::zeroed() };
+
+        let err = unsafe {
+            self.0.GetOriginLocalizedName.unwrap()(
+                origin.0,
+                name.as_mut_ptr() as *mut i8,
+                128,
+                bits.as_repr() as i32,
+            )
+        };
+
+        VRInputError::new(err)?;
+        let trimmed_str = name
+            .iter()
+            .map(|&c| c as u8)
+            .take_while(|&x| x != 0)
+            .collect();
+
+        Ok(String::from_utf8(trimmed_str).expect("Could not parse string from name array"))
+    }
+
+    pub fn get_origin_tracked_device_info(
+        &mut self,
+        origin: VRInputValueHandle,
+    ) -> Result<VROriginInfo> {
+        let mut data: MaybeUninit<openvr_sys::InputOriginInfo_t> = MaybeUninit::uninit();
+        let err = unsafe {
+            self.0.GetOriginTrackedDeviceInfo.unwrap()(
+                origin.0,
+                data.as_mut_ptr(),
+                std::mem::size_of::<openvr_sys::InputOriginInfo_t>() as u32,
+            )
+        };
+
+        VRInputError::new(err)?;
+        Ok(VROriginInfo(unsafe { data.assume_init() }))
+    }
+
+    pub fn show_action_origins(
+        &mut self,
+        set: VRActionSetHandle,
+        action: VRActionHandle,
+    ) -> Result<()> {
+        let err = unsafe { self.0.ShowActionOrigins.unwrap()(set.0, action.0) };
+
+        VRInputError::new(err)
+    }
+
+    pub fn show_bindings_for_action_set(
+        &mut self,
+        sets: &mut [VRActiveActionSet],
+        origin: VRInputValueHandle,
+    ) -> Result<()> {
+        let err = unsafe {
+            self.0.ShowBindingsForActionSet.unwrap()(
+                sets.as_mut_ptr().cast(),
+                std::mem::size_of::<VRActiveActionSet>() as u32,
+                sets.len() as u32,
+                origin.0,
+            )
+        };
+
+        VRInputError::new(err)
+    }
+
+    pub fn trigger_haptic_vibration_action(
+        &mut self,
+        action: VRActionHandle,
+        start_seconds_from_now: f32,
+        duration: Duration,
+        frequency: f32,
+        amplitude: f32,
+        restrict: VRInputValueHandle,
+    ) -> Result<()> {
+        let err = unsafe {
+            self.0.TriggerHapticVibrationAction.unwrap()(
+                action.0,
+                start_seconds_from_now,
+                duration.as_secs_f32(),
+                frequency,
+                amplitude,
+                restrict.0,
+            )
+        };
+
+        VRInputError::new(err)
+    }
+
+    /// Opens the binding UI, for `app_key` or the calling application if `None`.
+    ///
+    /// `action_set` and `input_device` select the set and device to show first; `None` lets the UI
+    /// pick.
+    pub fn open_binding_ui(
+        &mut self,
+        app_key: Option<&str>,
+        action_set: Option<VRActionSetHandle>,
+        input_device: Option<VRInputValueHandle>,
+        show_on_desktop: bool,
+    ) -> Result<()> {
+        let app_key = match app_key.map(CString::new) {
+            Some(Ok(x)) => Some(x),
+            Some(Err(_)) => return Err(VRInputError::InvalidParam),
+            None => None,
+        };
+        let app_key_ptr = app_key.as_ref().map_or(std::ptr::null(), |x| x.as_ptr());
+        let err = unsafe {
+            self.0.OpenBindingUI.unwrap()(
+                app_key_ptr.cast_mut(),
+                action_set.map_or(openvr_sys::k_ulInvalidActionSetHandle, |x| x.0),
+                input_device.map_or(openvr_sys::k_ulInvalidInputValueHandle, |x| x.0),
+                show_on_desktop,
+            )
+        };
+        VRInputError::new(err)
+    }
+
+    /// Where `action` is currently bound, one entry per binding.
+    ///
+    /// Fails with `BufferTooSmall` if the action has more than `MAX_BINDING_INFOS` bindings.
+    pub fn get_action_binding_info(&mut self, action: VRActionHandle) -> Result<Vec<BindingInfo>> {
+        let mut data = vec![openvr_sys::InputBindingInfo_t::default(); 16];
+        loop {
+            let mut count = 0;
+            let err = unsafe {
+                self.0.GetActionBindingInfo.unwrap()(
+                    action.0,
+                    data.as_mut_ptr(),
+                    size_of::<openvr_sys::InputBindingInfo_t>() as u32,
+                    data.len() as u32,
+                    &mut count,
+                )
+            };
+            match VRInputError::new(err) {
+                Ok(()) => {
+                    let count = count.min(data.len() as u32) as usize;
+                    return Ok(data[..count].iter().map(BindingInfo::from).collect());
+                }
+                // The required count is reported back, but don't trust it to grow or to be sane.
+                Err(VRInputError::BufferTooSmall) if data.len() < MAX_BINDING_INFOS => {
+                    let len = (count as usize).max(data.len() * 2).min(MAX_BINDING_INFOS);
+                    data.resize(len, Default::default());
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// State of a render model component driven by the given bindings, e.g. from
+    /// `get_action_binding_info`, so the component that triggers an action can be animated.
+    pub fn get_component_state_for_binding(
+        &mut self,
+        render_model: &CStr,
+        component: &CStr,
+        bindings: &[BindingInfo],
+    ) -> Result<ComponentState> {
+        let mut raw: Vec<openvr_sys::InputBindingInfo_t> =
+            bindings.iter().map(Into::into).collect();
+        let mut state = MaybeUninit::<ComponentState>::uninit();
+        let err = unsafe {
+            self.0.GetComponentStateForBinding.unwrap()(
+                render_model.as_ptr().cast_mut(),
+                component.as_ptr().cast_mut(),
+                raw.as_mut_ptr(),
+                size_of::<openvr_sys::InputBindingInfo_t>() as u32,
+                raw.len() as u32,
+                state.as_mut_ptr().cast(),
+            )
+        };
+        VRInputError::new(err)?;
+        Ok(unsafe { state.assume_init() })
+    }
+
+    /// The hand the user prefers to use, `LeftHand` or `RightHand`.
+    pub fn get_dominant_hand(&mut self) -> Result<TrackedControllerRole> {
+        let mut hand = openvr_sys::ETrackedControllerRole_TrackedControllerRole_Invalid;
+        let err = unsafe { self.0.GetDominantHand.unwrap()(&mut hand) };
+        VRInputError::new(err)?;
+        Ok(hand.into())
+    }
+
+    /// Sets the user's dominant hand, which swaps left- and right-hand bindings for left-handed
+    /// users.
+    pub fn set_dominant_hand(&mut self, hand: TrackedControllerRole) -> Result<()> {
+        let err =
+            unsafe { self.0.SetDominantHand.unwrap()(hand as openvr_sys::ETrackedControllerRole) };
+        VRInputError::new(err)
+    }
+
+    /// Whether the application is being driven through the legacy controller input API instead of
+    /// actions.
+    pub fn is_using_legacy_input(&mut self) -> bool {
+        unsafe { self.0.IsUsingLegacyInput.unwrap()() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{identity_pose, MockActionValue, MockRuntime};
+    use crate::{Context, TrackingUniverseOrigin};
+
+    #[test]
+    fn frame_action_poses() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let compositor = context.compositor().unwrap();
+        let mut input = context.input().unwrap();
+        compositor.set_tracking_space(TrackingUniverseOrigin::Seated);
+        assert_eq!(compositor.tracking_space(), TrackingUniverseOrigin::Seated);
+
+        let aim = input.get_action_handle("/actions/main/in/aim").unwrap();
+        let unbound = input
+            .get_action_handle("/actions/main/in/other_aim")
+            .unwrap();
+        runtime.set_action_value(
+            "/actions/main/in/aim",
+            MockActionValue::Pose(identity_pose()),
+        );
+        input.update_actions(&mut []).unwrap();
+        compositor.wait_get_poses().unwrap();
+
+        let poses = input
+            .get_frame_action_poses(&compositor, &[aim, unbound])
+            .unwrap();
+        assert!(poses[0].active() && poses[0].pose().pose_is_valid());
+        assert!(!poses[1].active());
+    }
+
+    #[test]
+    fn bindings_and_origins() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let mut input = context.input().unwrap();
+        let binding = |i: usize| BindingInfo {
+            device_path: "/user/hand/right".to_owned(),
+            input_path: format!("/input/button_{}", i),
+            mode: "button".to_owned(),
+            slot: "click".to_owned(),
+            source_type: "button".to_owned(),
+        };
+        // More bindings than fit in the first buffer `get_action_binding_info` tries.
+        let bindings: Vec<_> = (0..20)
+            .map(|i| (VRInputValueHandle(100 + i as u64), binding(i)))
+            .collect();
+        runtime.set_action_bindings("/actions/main/in/fire", bindings);
+
+        let set = input.get_action_set_handle("/actions/main").unwrap();
+        let fire = input.get_action_handle("/actions/main/in/fire").unwrap();
+        let other = input.get_action_handle("/actions/main/in/other").unwrap();
+        let origins = input.get_action_origins(set, fire).unwrap();
+        assert_eq!(origins.len(), 16);
+        assert_eq!(origins[3], VRInputValueHandle(103));
+        assert_eq!(input.get_action_origins(set, other), Ok(vec![]));
+        let info = input.get_action_binding_info(fire).unwrap();
+        assert_eq!(info.len(), 20);
+        assert_eq!(info[19], binding(19));
+        // Past the cap, the error is returned instead of growing the buffer further.
+        let too_many = vec![(VRInputValueHandle(100), binding(0)); MAX_BINDING_INFOS + 1];
+        runtime.set_action_bindings("/actions/main/in/other", too_many);
+        assert_eq!(
+            input.get_action_binding_info(other),
+            Err(VRInputError::BufferTooSmall)
+        );
+
+        let long = BindingInfo {
+            source_type: "x".repeat(40),
+            ..binding(0)
+        };
+        let raw = openvr_sys::InputBindingInfo_t::from(&long);
+        assert_eq!(BindingInfo::from(&raw).source_type.len(), 31);
+
+        assert_eq!(
+            input.get_dominant_hand(),
+            Ok(TrackedControllerRole::RightHand)
+        );
+        assert_eq!(
+            TrackedControllerRole::from(42),
+            TrackedControllerRole::Invalid
+        );
+        input
+            .set_dominant_hand(TrackedControllerRole::LeftHand)
+            .unwrap();
+        assert_eq!(runtime.dominant_hand(), TrackedControllerRole::LeftHand);
+        assert_eq!(
+            input.set_dominant_hand(TrackedControllerRole::Treadmill),
+            Err(VRInputError::InvalidParam)
+        );
+        assert!(!input.is_using_legacy_input());
+        runtime.set_using_legacy_input(true);
+        assert!(input.is_using_legacy_input());
+
+        input.open_binding_ui(None, None, None, false).unwrap();
+        input
+            .open_binding_ui(Some("my.app"), Some(set), None, true)
+            .unwrap();
+        let requests = runtime.binding_ui_requests();
+        assert_eq!(requests[0].app_key, None);
+        assert_eq!(requests[0].action_set, None);
+        assert_eq!(requests[1].app_key.as_deref(), Some("my.app"));
+        assert_eq!(requests[1].action_set, Some(set));
+        assert!(requests[1].show_on_desktop);
+    }
+}
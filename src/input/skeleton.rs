@@ -0,0 +1,533 @@
+//! Skeletal input: per-bone hand poses read through skeleton actions.
+
+use std::ffi::CStr;
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+
+use openvr_sys as sys;
+
+use super::{Result, VRActionHandle, VRInputValueHandle};
+use crate::errors::VRInputError;
+use crate::pose::Quaternion;
+use crate::Input;
+
+/// Index of a bone in a skeleton. Parents of root bones are `-1`.
+pub type BoneIndex = sys::BoneIndex_t;
+
+/// Transform of a single bone, laid out like `VRBoneTransform_t`
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct BoneTransform {
+    /// Position in meters. The fourth component is always 1.
+    pub position: [f32; 4],
+    pub orientation: Quaternion,
+}
+
+const _: () = assert!(mem::size_of::<BoneTransform>() == mem::size_of::<sys::VRBoneTransform_t>());
+
+impl Default for BoneTransform {
+    fn default() -> Self {
+        BoneTransform {
+            position: [0., 0., 0., 1.],
+            orientation: Quaternion::IDENTITY,
+        }
+    }
+}
+
+impl From<sys::VRBoneTransform_t> for BoneTransform {
+    fn from(x: sys::VRBoneTransform_t) -> Self {
+        BoneTransform {
+            position: x.position.v,
+            orientation: x.orientation.into(),
+        }
+    }
+}
+
+impl From<BoneTransform> for sys::VRBoneTransform_t {
+    fn from(x: BoneTransform) -> Self {
+        sys::VRBoneTransform_t {
+            position: sys::HmdVector4_t { v: x.position },
+            orientation: x.orientation.into(),
+        }
+    }
+}
+
+/// Which space bone transforms are expressed in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SkeletalTransformSpace {
+    /// Relative to the skeleton's root
+    Model = sys::EVRSkeletalTransformSpace_VRSkeletalTransformSpace_Model as isize,
+    /// Relative to each bone's parent
+    Parent = sys::EVRSkeletalTransformSpace_VRSkeletalTransformSpace_Parent as isize,
+}
+
+/// Whether the hand pose accounts for the controller being held.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SkeletalMotionRange {
+    /// The range of motion of the skeleton takes into account any physical limits imposed by the
+    /// controller itself. This will tend to be the most accurate pose compared to the user's actual
+    /// hand pose, but might not allow a closed fist for example.
+    WithController = sys::EVRSkeletalMotionRange_VRSkeletalMotionRange_WithController as isize,
+    /// Retarget the range of motion provided by the input device to make the hand appear to move as
+    /// if it was not holding a controller, e.g. map "hand grasping controller" to "closed fist".
+    WithoutController =
+        sys::EVRSkeletalMotionRange_VRSkeletalMotionRange_WithoutController as isize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SkeletalReferencePose {
+    BindPose = sys::EVRSkeletalReferencePose_VRSkeletalReferencePose_BindPose as isize,
+    OpenHand = sys::EVRSkeletalReferencePose_VRSkeletalReferencePose_OpenHand as isize,
+    Fist = sys::EVRSkeletalReferencePose_VRSkeletalReferencePose_Fist as isize,
+    GripLimit = sys::EVRSkeletalReferencePose_VRSkeletalReferencePose_GripLimit as isize,
+}
+
+/// How much of the skeleton the input device actually tracks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SkeletalTrackingLevel {
+    /// Body part location can't be directly determined by the device. Any skeletal pose provided by
+    /// the device is estimated by assuming the position required to active buttons, triggers,
+    /// joysticks, or other input sensors.
+    Estimated = sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Estimated as isize,
+    /// Body part location can be measured directly but with fewer degrees of freedom than the
+    /// actual body part.
+    Partial = sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Partial as isize,
+    /// Body part location can be measured directly throughout the entire range of motion of the
+    /// body part.
+    Full = sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Full as isize,
+}
+impl From<sys::EVRSkeletalTrackingLevel> for SkeletalTrackingLevel {
+    fn from(item: sys::EVRSkeletalTrackingLevel) -> Self {
+        match item {
+            sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Estimated => Self::Estimated,
+            sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Partial => Self::Partial,
+            _ => Self::Full,
+        }
+    }
+}
+
+/// Where `SkeletalSummary` values come from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SummaryType {
+    /// Computed from the animated skeleton, so it matches `get_skeletal_bone_data`
+    FromAnimation = sys::EVRSummaryType_VRSummaryType_FromAnimation as isize,
+    /// Straight from the device, possibly with lower latency but not matching the animation
+    FromDevice = sys::EVRSummaryType_VRSummaryType_FromDevice as isize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SkeletalActionData {
+    /// Whether or not this action is currently available to be bound in the active action set
+    pub active: bool,
+    /// The origin that caused this action's current state
+    pub active_origin: VRInputValueHandle,
+}
+
+/// Per-finger curl and splay, as a cheaper alternative to the full skeleton.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct SkeletalSummary {
+    /// Curl of each finger from 0 (straight) to 1 (fully curled), indexed by `EVRFinger`: thumb,
+    /// index, middle, ring, pinky.
+    pub finger_curl: [f32; 5],
+    /// Splay between adjacent fingers from 0 (together) to 1 (spread), indexed by `EVRFingerSplay`:
+    /// thumb-index, index-middle, middle-ring, ring-pinky.
+    pub finger_splay: [f32; 4],
+}
+
+/// Bone data of one skeleton in the runtime's compressed format, as returned by
+/// `Input::get_skeletal_bone_data_compressed`.
+///
+/// The data is only meaningful to `Input::decompress_skeletal_bone_data`, which doesn't need the
+/// action it was read from, so skeletons can be recorded or received from other players. `to_bytes`
+/// and `from_bytes` add the bone count and motion range needed to decode it on the other end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedSkeleton {
+    bone_count: u32,
+    motion_range: SkeletalMotionRange,
+    data: Vec<u8>,
+}
+
+/// Length of the header `CompressedSkeleton::to_bytes` puts before the data.
+const HEADER_LEN: usize = 8;
+
+impl CompressedSkeleton {
+    pub fn new(bone_count: u32, motion_range: SkeletalMotionRange, data: Vec<u8>) -> Self {
+        CompressedSkeleton {
+            bone_count,
+            motion_range,
+            data,
+        }
+    }
+
+    pub fn bone_count(&self) -> u32 {
+        self.bone_count
+    }
+
+    pub fn motion_range(&self) -> SkeletalMotionRange {
+        self.motion_range
+    }
+
+    /// The compressed data, without the header added by `to_bytes`.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Serializes the skeleton for storage or transport: the bone count and motion range as
+    /// little-endian `u32`s, followed by the compressed data.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.data.len());
+        bytes.extend_from_slice(&self.bone_count.to_le_bytes());
+        bytes.extend_from_slice(&(self.motion_range as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
+
+    /// Parses the output of `to_bytes`, failing with `VRInputError::InvalidCompressedData` if it is
+    /// malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_LEN {
+            return Err(VRInputError::InvalidCompressedData);
+        }
+        let field = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap());
+        let motion_range = match field(4) as sys::EVRSkeletalMotionRange {
+            sys::EVRSkeletalMotionRange_VRSkeletalMotionRange_WithController => {
+                SkeletalMotionRange::WithController
+            }
+            sys::EVRSkeletalMotionRange_VRSkeletalMotionRange_WithoutController => {
+                SkeletalMotionRange::WithoutController
+            }
+            _ => return Err(VRInputError::InvalidCompressedData),
+        };
+        Ok(CompressedSkeleton::new(
+            field(0),
+            motion_range,
+            bytes[HEADER_LEN..].to_vec(),
+        ))
+    }
+}
+
+impl Input<'_> {
+    pub fn get_skeletal_action_data(
+        &mut self,
+        action: VRActionHandle,
+    ) -> Result<SkeletalActionData> {
+        let mut data = sys::InputSkeletalActionData_t::default();
+        let err = unsafe {
+            self.0.GetSkeletalActionData.unwrap()(
+                action.0,
+                &mut data,
+                mem::size_of::<sys::InputSkeletalActionData_t>() as u32,
+            )
+        };
+        VRInputError::new(err)?;
+        Ok(SkeletalActionData {
+            active: data.bActive,
+            active_origin: VRInputValueHandle(data.activeOrigin),
+        })
+    }
+
+    pub fn get_bone_count(&mut self, action: VRActionHandle) -> Result<u32> {
+        let mut count = 0;
+        let err = unsafe { self.0.GetBoneCount.unwrap()(action.0, &mut count) };
+        VRInputError::new(err)?;
+        Ok(count)
+    }
+
+    /// Parent of each bone of the skeleton bound to `action`.
+    pub fn get_bone_hierarchy(&mut self, action: VRActionHandle) -> Result<Vec<BoneIndex>> {
+        let mut parents = vec![0; self.get_bone_count(action)? as usize];
+        let err = unsafe {
+            self.0.GetBoneHierarchy.unwrap()(action.0, parents.as_mut_ptr(), parents.len() as u32)
+        };
+        VRInputError::new(err)?;
+        Ok(parents)
+    }
+
+    pub fn get_bone_name(&mut self, action: VRActionHandle, bone: BoneIndex) -> Result<String> {
+        let mut name = [0 as std::os::raw::c_char; sys::k_unMaxBoneNameLength as usize];
+        let err = unsafe {
+            self.0.GetBoneName.unwrap()(action.0, bone, name.as_mut_ptr(), name.len() as u32)
+        };
+        VRInputError::new(err)?;
+        let name = unsafe { CStr::from_ptr(name.as_ptr()) };
+        Ok(name.to_string_lossy().into_owned())
+    }
+
+    /// Transforms of a fixed reference pose of the skeleton bound to `action`.
+    pub fn get_skeletal_reference_transforms(
+        &mut self,
+        action: VRActionHandle,
+        space: SkeletalTransformSpace,
+        pose: SkeletalReferencePose,
+    ) -> Result<Vec<BoneTransform>> {
+        let mut bones = vec![BoneTransform::default(); self.get_bone_count(action)? as usize];
+        let err = unsafe {
+            self.0.GetSkeletalReferenceTransforms.unwrap()(
+                action.0,
+                space as sys::EVRSkeletalTransformSpace,
+                pose as sys::EVRSkeletalReferencePose,
+                bones.as_mut_ptr().cast(),
+                bones.len() as u32,
+            )
+        };
+        VRInputError::new(err)?;
+        Ok(bones)
+    }
+
+    pub fn get_skeletal_tracking_level(
+        &mut self,
+        action: VRActionHandle,
+    ) -> Result<SkeletalTrackingLevel> {
+        let mut level = MaybeUninit::uninit();
+        let err = unsafe { self.0.GetSkeletalTrackingLevel.unwrap()(action.0, level.as_mut_ptr()) };
+        VRInputError::new(err)?;
+        Ok(unsafe { level.assume_init() }.into())
+    }
+
+    /// Current pose of every bone of the skeleton bound to `action`, as of the last
+    /// `update_actions`.
+    pub fn get_skeletal_bone_data(
+        &mut self,
+        action: VRActionHandle,
+        space: SkeletalTransformSpace,
+        range: SkeletalMotionRange,
+    ) -> Result<Vec<BoneTransform>> {
+        let mut bones = vec![BoneTransform::default(); self.get_bone_count(action)? as usize];
+        self.get_skeletal_bone_data_into(action, space, range, &mut bones)?;
+        Ok(bones)
+    }
+
+    /// Like `get_skeletal_bone_data`, writing into `bones` to avoid an allocation per frame.
+    ///
+    /// `bones` must have exactly one element per bone, or `VRInputError::InvalidBoneCount` is
+    /// returned.
+    pub fn get_skeletal_bone_data_into(
+        &mut self,
+        action: VRActionHandle,
+        space: SkeletalTransformSpace,
+        range: SkeletalMotionRange,
+        bones: &mut [BoneTransform],
+    ) -> Result<()> {
+        let err = unsafe {
+            self.0.GetSkeletalBoneData.unwrap()(
+                action.0,
+                space as sys::EVRSkeletalTransformSpace,
+                range as sys::EVRSkeletalMotionRange,
+                bones.as_mut_ptr().cast(),
+                bones.len() as u32,
+            )
+        };
+        VRInputError::new(err)
+    }
+
+    /// Current bone data of the skeleton bound to `action` in a compact form, e.g. to send to other
+    /// players.
+    ///
+    /// The data is always in model space. Decode it with `decompress_skeletal_bone_data`.
+    pub fn get_skeletal_bone_data_compressed(
+        &mut self,
+        action: VRActionHandle,
+        range: SkeletalMotionRange,
+    ) -> Result<CompressedSkeleton> {
+        let bone_count = self.get_bone_count(action)?;
+        let mut size = 0;
+        let err = unsafe {
+            self.0.GetSkeletalBoneDataCompressed.unwrap()(
+                action.0,
+                range as sys::EVRSkeletalMotionRange,
+                ptr::null_mut(),
+                0,
+                &mut size,
+            )
+        };
+        match VRInputError::new(err) {
+            Ok(()) | Err(VRInputError::BufferTooSmall) => {}
+            Err(e) => return Err(e),
+        }
+        let mut data = vec![0u8; size as usize];
+        let err = unsafe {
+            self.0.GetSkeletalBoneDataCompressed.unwrap()(
+                action.0,
+                range as sys::EVRSkeletalMotionRange,
+                data.as_mut_ptr().cast(),
+                data.len() as u32,
+                &mut size,
+            )
+        };
+        VRInputError::new(err)?;
+        data.truncate(size as usize);
+        Ok(CompressedSkeleton::new(bone_count, range, data))
+    }
+
+    /// Decodes the output of `get_skeletal_bone_data_compressed` into one transform per bone.
+    pub fn decompress_skeletal_bone_data(
+        &mut self,
+        skeleton: &CompressedSkeleton,
+        space: SkeletalTransformSpace,
+    ) -> Result<Vec<BoneTransform>> {
+        let mut bones = vec![BoneTransform::default(); skeleton.bone_count as usize];
+        self.decompress_skeletal_bone_data_into(&skeleton.data, space, &mut bones)?;
+        Ok(bones)
+    }
+
+    /// Like `decompress_skeletal_bone_data`, taking the raw compressed data and writing into
+    /// `bones`.
+    pub fn decompress_skeletal_bone_data_into(
+        &mut self,
+        data: &[u8],
+        space: SkeletalTransformSpace,
+        bones: &mut [BoneTransform],
+    ) -> Result<()> {
+        // The runtime only reads the buffer; the pointer is mutable because of the C API.
+        let err = unsafe {
+            self.0.DecompressSkeletalBoneData.unwrap()(
+                data.as_ptr().cast_mut().cast(),
+                data.len() as u32,
+                space as sys::EVRSkeletalTransformSpace,
+                bones.as_mut_ptr().cast(),
+                bones.len() as u32,
+            )
+        };
+        VRInputError::new(err)
+    }
+
+    pub fn get_skeletal_summary_data(
+        &mut self,
+        action: VRActionHandle,
+        summary_type: SummaryType,
+    ) -> Result<SkeletalSummary> {
+        let mut data = sys::VRSkeletalSummaryData_t::default();
+        let err = unsafe {
+            self.0.GetSkeletalSummaryData.unwrap()(
+                action.0,
+                summary_type as sys::EVRSummaryType,
+                &mut data,
+            )
+        };
+        VRInputError::new(err)?;
+        Ok(SkeletalSummary {
+            finger_curl: data.flFingerCurl,
+            finger_splay: data.flFingerSplay,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockRuntime, MockSkeleton};
+    use crate::Context;
+
+    #[test]
+    fn reads_mock_skeleton() {
+        let runtime = MockRuntime::new();
+        let mut skeleton =
+            MockSkeleton::new(&[("root", -1), ("wrist_r", 0), ("finger_index_r", 1)]);
+        skeleton.bones[2].position = [0.1, 0.2, 0.3, 1.0];
+        skeleton.summary.finger_curl[1] = 0.75;
+        skeleton.tracking_level = SkeletalTrackingLevel::Partial;
+        runtime.set_skeleton("/actions/main/in/hand_right", skeleton);
+
+        let context = Context::mock(&runtime);
+        let mut input = context.input().unwrap();
+        let action = input
+            .get_action_handle("/actions/main/in/Hand_Right")
+            .unwrap();
+        assert!(input.get_skeletal_action_data(action).unwrap().active);
+        assert_eq!(input.get_bone_count(action), Ok(3));
+        assert_eq!(input.get_bone_hierarchy(action), Ok(vec![-1, 0, 1]));
+        assert_eq!(input.get_bone_name(action, 1).as_deref(), Ok("wrist_r"));
+        assert_eq!(
+            input.get_bone_name(action, 3),
+            Err(VRInputError::InvalidBoneIndex)
+        );
+        assert_eq!(
+            input.get_skeletal_tracking_level(action),
+            Ok(SkeletalTrackingLevel::Partial)
+        );
+
+        let bones = input
+            .get_skeletal_bone_data(
+                action,
+                SkeletalTransformSpace::Model,
+                SkeletalMotionRange::WithController,
+            )
+            .unwrap();
+        assert_eq!(bones[2].position, [0.1, 0.2, 0.3, 1.0]);
+        assert_eq!(bones[0], BoneTransform::default());
+        let mut short = [BoneTransform::default(); 2];
+        assert_eq!(
+            input.get_skeletal_bone_data_into(
+                action,
+                SkeletalTransformSpace::Parent,
+                SkeletalMotionRange::WithoutController,
+                &mut short
+            ),
+            Err(VRInputError::InvalidBoneCount)
+        );
+        let summary = input
+            .get_skeletal_summary_data(action, SummaryType::FromDevice)
+            .unwrap();
+        assert_eq!(summary.finger_curl[1], 0.75);
+
+        runtime.update_skeleton("/actions/main/in/hand_right", |x| x.active = false);
+        assert_eq!(
+            input.get_skeletal_bone_data(
+                action,
+                SkeletalTransformSpace::Model,
+                SkeletalMotionRange::WithController
+            ),
+            Err(VRInputError::NoData)
+        );
+        let other = input
+            .get_action_handle("/actions/main/in/hand_left")
+            .unwrap();
+        assert_eq!(
+            input.get_bone_count(other),
+            Err(VRInputError::InvalidHandle)
+        );
+    }
+
+    #[test]
+    fn compressed_round_trip() {
+        let runtime = MockRuntime::new();
+        let mut skeleton = MockSkeleton::new(&[("root", -1), ("wrist_l", 0)]);
+        skeleton.bones[1].position = [0.0, 1.5, -0.25, 1.0];
+        runtime.set_skeleton("/actions/main/in/hand_left", skeleton);
+
+        let context = Context::mock(&runtime);
+        let mut input = context.input().unwrap();
+        let action = input
+            .get_action_handle("/actions/main/in/hand_left")
+            .unwrap();
+        let compressed = input
+            .get_skeletal_bone_data_compressed(action, SkeletalMotionRange::WithoutController)
+            .unwrap();
+        assert_eq!(compressed.bone_count(), 2);
+
+        let received = CompressedSkeleton::from_bytes(&compressed.to_bytes()).unwrap();
+        assert_eq!(received, compressed);
+        let bones = input
+            .decompress_skeletal_bone_data(&received, SkeletalTransformSpace::Model)
+            .unwrap();
+        assert_eq!(bones[1].position, [0.0, 1.5, -0.25, 1.0]);
+
+        assert_eq!(
+            CompressedSkeleton::from_bytes(&[1, 0, 0]),
+            Err(VRInputError::InvalidCompressedData)
+        );
+        let truncated = CompressedSkeleton::new(
+            2,
+            SkeletalMotionRange::WithController,
+            compressed.data()[..9].to_vec(),
+        );
+        assert_eq!(
+            input.decompress_skeletal_bone_data(&truncated, SkeletalTransformSpace::Parent),
+            Err(VRInputError::InvalidCompressedData)
+        );
+    }
+}
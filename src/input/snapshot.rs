@@ -0,0 +1,282 @@
+//! Reading the state of many actions at once, once per frame.
+
+use std::collections::HashMap;
+
+use openvr_sys as sys;
+
+use super::{ActionManifest, ActionType, Result, VRActionHandle, VRInputValueHandle};
+use crate::{Input, TrackedDevicePose, TrackingUniverseOrigin};
+
+/// Value of an action as of the last `update_actions`.
+#[derive(Debug, Copy, Clone)]
+pub enum ActionValue {
+    /// A `Boolean` action. `changed` is set if `state` differs from the previous update.
+    Digital {
+        state: bool,
+        changed: bool,
+    },
+    /// A `Vector1`, `Vector2` or `Vector3` action. Axes the action doesn't have are 0.
+    Analog {
+        value: [f32; 3],
+        delta: [f32; 3],
+    },
+    Pose(TrackedDevicePose),
+}
+
+/// State of a single action in an `ActionStateSnapshot`.
+#[derive(Debug, Copy, Clone)]
+pub struct ActionState {
+    /// Whether the action is bound and its action set is active. Inactive actions report default
+    /// values.
+    pub active: bool,
+    /// The input source that drives the action, if it is active.
+    pub origin: VRInputValueHandle,
+    /// When the value last changed, in seconds relative to now. Always 0 for poses.
+    pub update_time: f32,
+    pub value: ActionValue,
+}
+
+/// State of a set of actions, read together after `update_actions`.
+///
+/// Accessors return neutral values (not pressed, zero, `None`) for actions that weren't read, are
+/// inactive, or are of a different type, so callers can query input without checking for each of
+/// those cases.
+///
+/// ```no_run
+/// # let context = openvr::init(openvr::ApplicationType::Scene).unwrap();
+/// # let mut input = context.input().unwrap();
+/// use openvr::input::ActionType;
+///
+/// let jump = input.get_action_handle("/actions/main/in/jump").unwrap();
+/// let aim = input.get_action_handle("/actions/main/in/aim").unwrap();
+/// let actions = [(jump, ActionType::Boolean), (aim, ActionType::Pose)];
+/// // Call `update_actions` once per frame, then:
+/// let origin = openvr::TrackingUniverseOrigin::Standing;
+/// let snapshot = input.read_action_state(&actions, origin).unwrap();
+/// if snapshot.just_pressed(jump) {
+///     println!("jump from {:?}", snapshot.origin(jump));
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ActionStateSnapshot {
+    states: HashMap<VRActionHandle, ActionState>,
+}
+
+impl ActionStateSnapshot {
+    pub fn get(&self, action: VRActionHandle) -> Option<&ActionState> {
+        self.states.get(&action)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (VRActionHandle, &ActionState)> {
+        self.states.iter().map(|(k, v)| (*k, v))
+    }
+
+    fn active_state(&self, action: VRActionHandle) -> Option<&ActionState> {
+        self.get(action).filter(|x| x.active)
+    }
+
+    fn digital(&self, action: VRActionHandle) -> Option<(bool, bool)> {
+        match self.active_state(action)?.value {
+            ActionValue::Digital { state, changed } => Some((state, changed)),
+            _ => None,
+        }
+    }
+
+    pub fn active(&self, action: VRActionHandle) -> bool {
+        self.active_state(action).is_some()
+    }
+
+    /// The input source driving `action`, e.g. to look up with
+    /// `Input::get_origin_tracked_device_info`.
+    pub fn origin(&self, action: VRActionHandle) -> Option<VRInputValueHandle> {
+        self.active_state(action).map(|x| x.origin)
+    }
+
+    pub fn pressed(&self, action: VRActionHandle) -> bool {
+        matches!(self.digital(action), Some((true, _)))
+    }
+
+    /// Whether `action` went down in the last `update_actions`.
+    pub fn just_pressed(&self, action: VRActionHandle) -> bool {
+        self.digital(action) == Some((true, true))
+    }
+
+    /// Whether `action` went up in the last `update_actions`.
+    pub fn just_released(&self, action: VRActionHandle) -> bool {
+        self.digital(action) == Some((false, true))
+    }
+
+    /// Value of an analog action. Digital actions read as 1 on the first axis while pressed.
+    pub fn value(&self, action: VRActionHandle) -> [f32; 3] {
+        match self.active_state(action).map(|x| x.value) {
+            Some(ActionValue::Analog { value, .. }) => value,
+            Some(ActionValue::Digital { state: true, .. }) => [1.0, 0.0, 0.0],
+            _ => [0.0; 3],
+        }
+    }
+
+    /// Change in value of an analog action since the previous `update_actions`.
+    pub fn delta(&self, action: VRActionHandle) -> [f32; 3] {
+        match self.active_state(action).map(|x| x.value) {
+            Some(ActionValue::Analog { delta, .. }) => delta,
+            _ => [0.0; 3],
+        }
+    }
+
+    /// Pose of a pose action, if it is active and tracking.
+    pub fn pose(&self, action: VRActionHandle) -> Option<TrackedDevicePose> {
+        match self.active_state(action)?.value {
+            ActionValue::Pose(pose) if pose.pose_is_valid() => Some(pose),
+            _ => None,
+        }
+    }
+}
+
+impl Input<'_> {
+    /// Reads the current state of every action in `actions`. Poses are predicted for now, in
+    /// `universe`.
+    ///
+    /// `Vibration` and `Skeleton` actions are skipped; they have their own functions.
+    pub fn read_action_state(
+        &mut self,
+        actions: &[(VRActionHandle, ActionType)],
+        universe: TrackingUniverseOrigin,
+    ) -> Result<ActionStateSnapshot> {
+        let mut states = HashMap::with_capacity(actions.len());
+        for &(action, ty) in actions {
+            let state = match ty {
+                ActionType::Boolean => {
+                    let x = self.get_digital_action_data(action, VRInputValueHandle(0))?;
+                    ActionState {
+                        active: x.active(),
+                        origin: x.active_origin(),
+                        update_time: x.update_time(),
+                        value: ActionValue::Digital {
+                            state: x.state(),
+                            changed: x.changed(),
+                        },
+                    }
+                }
+                ActionType::Vector1 | ActionType::Vector2 | ActionType::Vector3 => {
+                    let x = self.get_analog_action_data(action, VRInputValueHandle(0))?;
+                    ActionState {
+                        active: x.active(),
+                        origin: x.active_origin(),
+                        update_time: x.update_time(),
+                        value: ActionValue::Analog {
+                            value: x.value(),
+                            delta: x.delta(),
+                        },
+                    }
+                }
+                ActionType::Pose => {
+                    let x = self.get_pose_action_data_relative_to_now(
+                        action,
+                        universe as sys::ETrackingUniverseOrigin,
+                        0.0,
+                        VRInputValueHandle(0),
+                    )?;
+                    ActionState {
+                        active: x.active(),
+                        origin: x.active_origin(),
+                        update_time: 0.0,
+                        value: ActionValue::Pose(x.pose()),
+                    }
+                }
+                ActionType::Vibration | ActionType::Skeleton => continue,
+            };
+            states.insert(action, state);
+        }
+        Ok(ActionStateSnapshot { states })
+    }
+
+    /// Looks up the handle of every action in `manifest`, for `read_action_state`.
+    pub fn action_handles(
+        &mut self,
+        manifest: &ActionManifest,
+    ) -> Result<Vec<(VRActionHandle, ActionType)>> {
+        manifest
+            .actions
+            .iter()
+            .map(|x| Ok((self.get_action_handle(&x.name)?, x.ty)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{Action, ActionSetUsage, VRActiveActionSet};
+    use crate::mock::{identity_pose, MockActionValue, MockRuntime};
+    use crate::Context;
+
+    #[test]
+    fn edges_and_deltas() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let mut input = context.input().unwrap();
+        let manifest = ActionManifest::new()
+            .with_action_set("/actions/main", ActionSetUsage::LeftRight)
+            .with_action(Action::new("/actions/main/in/jump", ActionType::Boolean))
+            .with_action(Action::new("/actions/main/in/move", ActionType::Vector2))
+            .with_action(Action::new("/actions/main/in/aim", ActionType::Pose))
+            .with_action(Action::new("/actions/main/in/unbound", ActionType::Boolean));
+        let actions = input.action_handles(&manifest).unwrap();
+        let [jump, stick, aim, unbound] = [0, 1, 2, 3].map(|i| actions[i].0);
+        let set = input.get_action_set_handle("/actions/main").unwrap();
+        let mut sets = [VRActiveActionSet(sys::VRActiveActionSet_t {
+            ulActionSet: set.0,
+            ..Default::default()
+        })];
+        let mut frame = |input: &mut Input| {
+            input.update_actions(&mut sets).unwrap();
+            input
+                .read_action_state(&actions, TrackingUniverseOrigin::Standing)
+                .unwrap()
+        };
+
+        runtime.set_action_value("/actions/main/in/jump", MockActionValue::Digital(true));
+        runtime.set_action_value(
+            "/actions/main/in/move",
+            MockActionValue::Analog([0.5, -0.25, 0.0]),
+        );
+        runtime.set_action_value(
+            "/actions/main/in/aim",
+            MockActionValue::Pose(identity_pose()),
+        );
+        runtime.set_action_origin("/actions/main/in/jump", VRInputValueHandle(42));
+        let snapshot = frame(&mut input);
+        assert!(snapshot.pressed(jump) && snapshot.just_pressed(jump));
+        assert_eq!(snapshot.origin(jump), Some(VRInputValueHandle(42)));
+        assert_eq!(snapshot.value(stick), [0.5, -0.25, 0.0]);
+        assert_eq!(snapshot.delta(stick), [0.5, -0.25, 0.0]);
+        assert!(snapshot.pose(aim).is_some());
+        assert!(!snapshot.active(unbound) && !snapshot.pressed(unbound));
+        assert_eq!(snapshot.origin(unbound), None);
+
+        runtime.set_action_value(
+            "/actions/main/in/move",
+            MockActionValue::Analog([1.0, -0.25, 0.0]),
+        );
+        let snapshot = frame(&mut input);
+        assert!(snapshot.pressed(jump) && !snapshot.just_pressed(jump));
+        assert_eq!(snapshot.delta(stick), [0.5, 0.0, 0.0]);
+
+        runtime.set_action_value("/actions/main/in/jump", MockActionValue::Digital(false));
+        let snapshot = frame(&mut input);
+        assert!(snapshot.just_released(jump) && !snapshot.pressed(jump));
+        assert_eq!(snapshot.delta(stick), [0.0; 3]);
+        assert_eq!(runtime.action_update_count(), 3);
+
+        // Reading an action as the wrong type is reported rather than silently returning defaults.
+        assert_eq!(
+            input
+                .read_action_state(
+                    &[(jump, ActionType::Vector1)],
+                    TrackingUniverseOrigin::Standing
+                )
+                .unwrap_err(),
+            crate::errors::VRInputError::WrongType
+        );
+    }
+}
@@ -13,7 +13,9 @@ use openvr_sys as sys;
 mod tracking;
 pub mod application;
 pub mod chaperone;
+pub mod chaperone_setup;
 pub mod compositor;
+pub mod controller;
 mod init_error;
 pub mod property;
 pub mod render_models;
@@ -31,36 +33,44 @@ pub mod errors;
 pub mod settings;
 pub mod overlay;
 pub mod pose;
+pub mod recording;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
 
 static INITIALIZED: AtomicBool = AtomicBool::new(false);
 
 /// Initialize OpenVR
 ///
+/// The `Context` should be dropped before shutting down the graphics API, since OpenVR may still
+/// hold references to submitted textures. Interface handles borrow the `Context`, so no OpenVR
+/// calls can be made through them once it is gone.
+///
 /// # Panics
 ///
 /// When the library has already been initialized
-///
-/// # Safety
-///
-/// The `Context` MUST be dropped or shut down with `Context::shutdown` before shutting down the graphics API. No OpenVR
-/// calls may be made on object derived from a `Context` after the `Context` has been dropped or explicitly shut down.
-pub unsafe fn init(ty: ApplicationType) -> Result<Context, InitError> {
-    let mut error = sys::EVRInitError_VRInitError_None;
-    sys::VR_InitInternal(&mut error, ty as sys::EVRApplicationType);
-    if error != sys::EVRInitError_VRInitError_None {
-        return Err(error.into());
-    }
-    if !sys::VR_IsInterfaceVersionValid(sys::IVRSystem_Version.as_ptr() as *const i8) {
-        sys::VR_ShutdownInternal();
-        return Err(InitError::Init_InterfaceNotFound);
-    }
-
+pub fn init(ty: ApplicationType) -> Result<Context, InitError> {
     if INITIALIZED.swap(true, Ordering::Acquire) {
         panic!("OpenVR has already been initialized!");
     }
 
+    let mut error = sys::EVRInitError_VRInitError_None;
+    unsafe {
+        sys::VR_InitInternal(&mut error, ty as sys::EVRApplicationType);
+        if error != sys::EVRInitError_VRInitError_None {
+            INITIALIZED.store(false, Ordering::Release);
+            return Err(error.into());
+        }
+        if !sys::VR_IsInterfaceVersionValid(sys::IVRSystem_Version.as_ptr() as *const i8) {
+            sys::VR_ShutdownInternal();
+            INITIALIZED.store(false, Ordering::Release);
+            return Err(InitError::Init_InterfaceNotFound);
+        }
+    }
+
     Ok(Context {
-        live: AtomicBool::new(true),
+        _private: (),
+        #[cfg(any(test, feature = "mock"))]
+        mock: false,
     })
 }
 pub fn is_hmd_present() -> bool {
@@ -71,22 +81,42 @@ pub fn is_runtime_installed() -> bool {
 }
 
 
-pub struct System(&'static sys::VR_IVRSystem_FnTable);
-pub struct Application(&'static sys::VR_IVRApplications_FnTable);
-pub struct Compositor(&'static sys::VR_IVRCompositor_FnTable);
-pub struct RenderModels(&'static sys::VR_IVRRenderModels_FnTable);
-pub struct Chaperone(&'static sys::VR_IVRChaperone_FnTable);
-pub struct Input(&'static sys::VR_IVRInput_FnTable);
-pub struct Settings(&'static sys::VR_IVRSettings_FnTable);
-pub struct Overlay(&'static sys::VR_IVROverlay_FnTable);
+pub struct System<'c>(&'c sys::VR_IVRSystem_FnTable);
+pub struct Application<'c>(&'c sys::VR_IVRApplications_FnTable);
+pub struct Compositor<'c>(&'c sys::VR_IVRCompositor_FnTable);
+pub struct RenderModels<'c>(&'c sys::VR_IVRRenderModels_FnTable);
+pub struct Chaperone<'c>(&'c sys::VR_IVRChaperone_FnTable);
+pub struct ChaperoneSetup<'c>(&'c sys::VR_IVRChaperoneSetup_FnTable);
+pub struct Input<'c>(&'c sys::VR_IVRInput_FnTable);
+pub struct Settings<'c>(&'c sys::VR_IVRSettings_FnTable);
+pub struct Overlay<'c>(&'c sys::VR_IVROverlay_FnTable);
 
 /// Entry points into OpenVR.
 ///
-/// At most one of this object may exist at a time.
+/// At most one of this object may exist at a time. Interface handles such as `System` borrow it, so
+/// they cannot outlive the runtime they were loaded from:
 ///
-/// See safety notes in `init`.
+/// ```compile_fail
+/// let context = openvr::init(openvr::ApplicationType::Other).unwrap();
+/// let system = context.system().unwrap();
+/// drop(context);
+/// system.recommended_render_target_size();
+/// ```
 pub struct Context {
-    live: AtomicBool,
+    _private: (),
+    /// Interfaces come from `mock::MockRuntime` rather than the OpenVR runtime.
+    #[cfg(any(test, feature = "mock"))]
+    mock: bool,
+}
+
+impl Context {
+    fn load<T>(&self, suffix: &[u8]) -> Result<*const T, InitError> {
+        #[cfg(any(test, feature = "mock"))]
+        if self.mock {
+            return mock::load(suffix);
+        }
+        load(suffix)
+    }
 }
 
 fn load<T>(suffix: &[u8]) -> Result<*const T, InitError> {
@@ -101,57 +131,62 @@ fn load<T>(suffix: &[u8]) -> Result<*const T, InitError> {
 }
 
 impl Context {
-    pub fn system(&self) -> Result<System, InitError> {
-        load(sys::IVRSystem_Version).map(|x| unsafe { System(&*x) })
+    pub fn system(&self) -> Result<System<'_>, InitError> {
+        self.load(sys::IVRSystem_Version)
+            .map(|x| unsafe { System(&*x) })
     }
-    pub fn application(&self) -> Result<Application, InitError> {
-        load(sys::IVRApplications_Version).map(|x| unsafe { Application(&*x) })
+    pub fn application(&self) -> Result<Application<'_>, InitError> {
+        self.load(sys::IVRApplications_Version)
+            .map(|x| unsafe { Application(&*x) })
     }
-    pub fn compositor(&self) -> Result<Compositor, InitError> {
-        load(sys::IVRCompositor_Version).map(|x| unsafe { Compositor(&*x) })
+    pub fn compositor(&self) -> Result<Compositor<'_>, InitError> {
+        self.load(sys::IVRCompositor_Version)
+            .map(|x| unsafe { Compositor(&*x) })
     }
-    pub fn render_models(&self) -> Result<RenderModels, InitError> {
-        load(sys::IVRRenderModels_Version).map(|x| unsafe { RenderModels(&*x) })
+    pub fn render_models(&self) -> Result<RenderModels<'_>, InitError> {
+        self.load(sys::IVRRenderModels_Version)
+            .map(|x| unsafe { RenderModels(&*x) })
     }
-    pub fn chaperone(&self) -> Result<Chaperone, InitError> {
-        load(sys::IVRChaperone_Version).map(|x| unsafe { Chaperone(&*x) })
+    pub fn chaperone(&self) -> Result<Chaperone<'_>, InitError> {
+        self.load(sys::IVRChaperone_Version)
+            .map(|x| unsafe { Chaperone(&*x) })
     }
-    pub fn input(&self) -> Result<Input, InitError> {
-        load(sys::IVRInput_Version).map(|x| unsafe { Input(&*x) })
+    pub fn chaperone_setup(&self) -> Result<ChaperoneSetup<'_>, InitError> {
+        self.load(sys::IVRChaperoneSetup_Version)
+            .map(|x| unsafe { ChaperoneSetup(&*x) })
     }
-    pub fn settings(&self) -> Result<Settings, InitError> {
-        load(sys::IVRSettings_Version).map(|x| unsafe { Settings(&*x) })
+    pub fn input(&self) -> Result<Input<'_>, InitError> {
+        self.load(sys::IVRInput_Version)
+            .map(|x| unsafe { Input(&*x) })
     }
-    pub fn overlay(&self) -> Result<Overlay, InitError> {
-        load(sys::IVROverlay_Version).map(|x| unsafe { Overlay(&*x) })
+    pub fn settings(&self) -> Result<Settings<'_>, InitError> {
+        self.load(sys::IVRSettings_Version)
+            .map(|x| unsafe { Settings(&*x) })
+    }
+    pub fn overlay(&self) -> Result<Overlay<'_>, InitError> {
+        self.load(sys::IVROverlay_Version)
+            .map(|x| unsafe { Overlay(&*x) })
     }
 }
 
 impl Drop for Context {
     fn drop(&mut self) {
-        unsafe { self.shutdown() }
+        #[cfg(any(test, feature = "mock"))]
+        if self.mock {
+            mock::deactivate();
+            return;
+        }
+        unsafe { sys::VR_ShutdownInternal() };
+        INITIALIZED.store(false, Ordering::Release);
     }
 }
 
 impl Context {
-    /// Shut down OpenVR. Repeated calls are safe.
+    /// Shut down OpenVR.
     ///
-    /// Called implicitly by `Context::drop`.
-    ///
-    /// # Safety
-    ///
-    /// This *must* be called *before* shutting down the graphics API, or OpenVR may invoke undefined behavior by
-    /// attempting to free graphics resources.
-    ///
-    /// No calls to other OpenVR methods may be made after this has been called unless a new `Context` is first
-
-    /// constructed.
-    pub unsafe fn shutdown(&self) {
-        if self.live.swap(false,Ordering::Acquire) {
-            sys::VR_ShutdownInternal();
-            INITIALIZED.store(false, Ordering::Release);
-        }
-    }
+    /// Equivalent to dropping the `Context`. This should be done *before* shutting down the
+    /// graphics API, or OpenVR may attempt to free graphics resources that no longer exist.
+    pub fn shutdown(self) {}
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -0,0 +1,322 @@
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use super::{with_state, ChaperoneConfig, ChaperoneState};
+use crate::sys;
+
+lazy_static! {
+    pub(super) static ref TABLE: sys::VR_IVRChaperone_FnTable = sys::VR_IVRChaperone_FnTable {
+        GetCalibrationState: Some(get_calibration_state),
+        GetPlayAreaSize: Some(get_play_area_size),
+        GetPlayAreaRect: Some(get_play_area_rect),
+        AreBoundsVisible: Some(are_bounds_visible),
+        ForceBoundsVisible: Some(force_bounds_visible),
+        ReloadInfo: Some(reload_info),
+        SetSceneColor: Some(set_scene_color),
+        GetBoundsColor: Some(get_bounds_color),
+        ..Default::default()
+    };
+}
+
+unsafe extern "C" fn get_calibration_state() -> sys::ChaperoneCalibrationState {
+    with_state(|s| s.chaperone.calibration_state)
+}
+
+unsafe extern "C" fn get_play_area_size(x: *mut f32, z: *mut f32) -> bool {
+    match with_state(|s| s.chaperone.play_area_size) {
+        Some((size_x, size_z)) => {
+            *x = size_x;
+            *z = size_z;
+            true
+        }
+        None => false,
+    }
+}
+
+unsafe extern "C" fn get_play_area_rect(rect: *mut sys::HmdQuad_t) -> bool {
+    match with_state(|s| s.chaperone.play_area_rect) {
+        Some(corners) => {
+            for (corner, v) in (*rect).vCorners.iter_mut().zip(corners) {
+                corner.v = v;
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+unsafe extern "C" fn are_bounds_visible() -> bool {
+    with_state(|s| s.chaperone.bounds_visible || s.chaperone.bounds_forced)
+}
+
+unsafe extern "C" fn force_bounds_visible(force: bool) {
+    with_state(|s| s.chaperone.bounds_forced = force)
+}
+
+unsafe extern "C" fn reload_info() {}
+
+unsafe extern "C" fn set_scene_color(color: sys::HmdColor_t) {
+    with_state(|s| s.chaperone.scene_color = Some(color.into()))
+}
+
+unsafe extern "C" fn get_bounds_color(
+    colors: *mut sys::HmdColor_t,
+    count: c_int,
+    _fade: f32,
+    camera: *mut sys::HmdColor_t,
+) {
+    let color = with_state(|s| s.chaperone.bounds_color).into();
+    for i in 0..count.max(0) as usize {
+        *colors.add(i) = color;
+    }
+    if !camera.is_null() {
+        *camera = color;
+    }
+}
+
+lazy_static! {
+    pub(super) static ref SETUP_TABLE: sys::VR_IVRChaperoneSetup_FnTable =
+        sys::VR_IVRChaperoneSetup_FnTable {
+            CommitWorkingCopy: Some(commit_working_copy),
+            RevertWorkingCopy: Some(revert_working_copy),
+            ReloadFromDisk: Some(reload_from_disk),
+            GetWorkingPlayAreaSize: Some(get_working_play_area_size),
+            GetWorkingPlayAreaRect: Some(get_working_play_area_rect),
+            SetWorkingPlayAreaSize: Some(set_working_play_area_size),
+            GetWorkingCollisionBoundsInfo: Some(get_working_collision_bounds_info),
+            GetLiveCollisionBoundsInfo: Some(get_live_collision_bounds_info),
+            SetWorkingCollisionBoundsInfo: Some(set_working_collision_bounds_info),
+            SetWorkingPerimeter: Some(set_working_perimeter),
+            GetWorkingSeatedZeroPoseToRawTrackingPose: Some(
+                get_working_seated_zero_pose_to_raw_tracking_pose
+            ),
+            GetWorkingStandingZeroPoseToRawTrackingPose: Some(
+                get_working_standing_zero_pose_to_raw_tracking_pose
+            ),
+            GetLiveSeatedZeroPoseToRawTrackingPose: Some(
+                get_live_seated_zero_pose_to_raw_tracking_pose
+            ),
+            SetWorkingSeatedZeroPoseToRawTrackingPose: Some(
+                set_working_seated_zero_pose_to_raw_tracking_pose
+            ),
+            SetWorkingStandingZeroPoseToRawTrackingPose: Some(
+                set_working_standing_zero_pose_to_raw_tracking_pose
+            ),
+            ExportLiveToBuffer: Some(export_live_to_buffer),
+            ImportFromBufferToWorking: Some(import_from_buffer_to_working),
+            ShowWorkingSetPreview: Some(show_working_set_preview),
+            HideWorkingSetPreview: Some(hide_working_set_preview),
+            RoomSetupStarting: Some(room_setup_starting),
+        };
+}
+
+/// Height given to collision bounds built from a perimeter.
+const WALL_HEIGHT: f32 = 2.5;
+
+/// Clockwise corners of a play area of the given size, centered on the origin.
+fn play_area_rect((x, z): (f32, f32)) -> [[f32; 3]; 4] {
+    let (x, z) = (x / 2.0, z / 2.0);
+    [[-x, 0.0, z], [x, 0.0, z], [x, 0.0, -z], [-x, 0.0, -z]]
+}
+
+fn live_config(c: &ChaperoneState) -> ChaperoneConfig {
+    ChaperoneConfig {
+        play_area_size: c.play_area_size,
+        collision_bounds: c.collision_bounds.clone(),
+        seated_zero_pose: c.seated_zero_pose,
+        standing_zero_pose: c.standing_zero_pose,
+    }
+}
+
+unsafe extern "C" fn commit_working_copy(file: sys::EChaperoneConfigFile) -> bool {
+    with_state(|s| {
+        let c = &mut s.chaperone;
+        if file == sys::EChaperoneConfigFile_Live {
+            c.play_area_size = c.working.play_area_size;
+            c.play_area_rect = c.working.play_area_size.map(play_area_rect);
+            c.collision_bounds = c.working.collision_bounds.clone();
+            c.seated_zero_pose = c.working.seated_zero_pose;
+            c.standing_zero_pose = c.working.standing_zero_pose;
+        }
+        true
+    })
+}
+
+unsafe extern "C" fn revert_working_copy() {
+    with_state(|s| s.chaperone.working = live_config(&s.chaperone))
+}
+
+unsafe extern "C" fn reload_from_disk(_file: sys::EChaperoneConfigFile) {
+    with_state(|s| s.chaperone.working = live_config(&s.chaperone))
+}
+
+unsafe extern "C" fn get_working_play_area_size(x: *mut f32, z: *mut f32) -> bool {
+    match with_state(|s| s.chaperone.working.play_area_size) {
+        Some((size_x, size_z)) => {
+            *x = size_x;
+            *z = size_z;
+            true
+        }
+        None => false,
+    }
+}
+
+unsafe extern "C" fn get_working_play_area_rect(rect: *mut sys::HmdQuad_t) -> bool {
+    match with_state(|s| s.chaperone.working.play_area_size) {
+        Some(size) => {
+            for (corner, v) in (*rect).vCorners.iter_mut().zip(play_area_rect(size)) {
+                corner.v = v;
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+unsafe extern "C" fn set_working_play_area_size(x: f32, z: f32) {
+    with_state(|s| s.chaperone.working.play_area_size = Some((x, z)))
+}
+
+/// Copies `quads` out following the count-then-fill protocol of `Get*CollisionBoundsInfo`.
+unsafe fn copy_quads(
+    quads: &[[[f32; 3]; 4]],
+    buffer: *mut sys::HmdQuad_t,
+    count: *mut u32,
+) -> bool {
+    if buffer.is_null() {
+        *count = quads.len() as u32;
+        return true;
+    }
+    if (*count as usize) < quads.len() {
+        *count = quads.len() as u32;
+        return false;
+    }
+    for (i, quad) in quads.iter().enumerate() {
+        for (corner, v) in (*buffer.add(i)).vCorners.iter_mut().zip(quad) {
+            corner.v = *v;
+        }
+    }
+    *count = quads.len() as u32;
+    true
+}
+
+unsafe extern "C" fn get_working_collision_bounds_info(
+    buffer: *mut sys::HmdQuad_t,
+    count: *mut u32,
+) -> bool {
+    with_state(|s| copy_quads(&s.chaperone.working.collision_bounds, buffer, count))
+}
+
+unsafe extern "C" fn get_live_collision_bounds_info(
+    buffer: *mut sys::HmdQuad_t,
+    count: *mut u32,
+) -> bool {
+    with_state(|s| copy_quads(&s.chaperone.collision_bounds, buffer, count))
+}
+
+unsafe extern "C" fn set_working_collision_bounds_info(buffer: *mut sys::HmdQuad_t, count: u32) {
+    let quads = (0..count as usize)
+        .map(|i| (*buffer.add(i)).vCorners.map(|x| x.v))
+        .collect();
+    with_state(|s| s.chaperone.working.collision_bounds = quads)
+}
+
+unsafe extern "C" fn set_working_perimeter(points: *mut sys::HmdVector2_t, count: u32) {
+    let points: Vec<_> = (0..count as usize).map(|i| (*points.add(i)).v).collect();
+    let quads = (0..points.len())
+        .map(|i| {
+            let ([x0, z0], [x1, z1]) = (points[i], points[(i + 1) % points.len()]);
+            [
+                [x0, 0.0, z0],
+                [x1, 0.0, z1],
+                [x1, WALL_HEIGHT, z1],
+                [x0, WALL_HEIGHT, z0],
+            ]
+        })
+        .collect();
+    with_state(|s| s.chaperone.working.collision_bounds = quads)
+}
+
+unsafe extern "C" fn get_working_seated_zero_pose_to_raw_tracking_pose(
+    pose: *mut sys::HmdMatrix34_t,
+) -> bool {
+    (*pose).m = with_state(|s| s.chaperone.working.seated_zero_pose);
+    true
+}
+
+unsafe extern "C" fn get_working_standing_zero_pose_to_raw_tracking_pose(
+    pose: *mut sys::HmdMatrix34_t,
+) -> bool {
+    (*pose).m = with_state(|s| s.chaperone.working.standing_zero_pose);
+    true
+}
+
+unsafe extern "C" fn get_live_seated_zero_pose_to_raw_tracking_pose(
+    pose: *mut sys::HmdMatrix34_t,
+) -> bool {
+    (*pose).m = with_state(|s| s.chaperone.seated_zero_pose);
+    true
+}
+
+unsafe extern "C" fn set_working_seated_zero_pose_to_raw_tracking_pose(
+    pose: *mut sys::HmdMatrix34_t,
+) {
+    let pose = (*pose).m;
+    with_state(|s| s.chaperone.working.seated_zero_pose = pose)
+}
+
+unsafe extern "C" fn set_working_standing_zero_pose_to_raw_tracking_pose(
+    pose: *mut sys::HmdMatrix34_t,
+) {
+    let pose = (*pose).m;
+    with_state(|s| s.chaperone.working.standing_zero_pose = pose)
+}
+
+/// Exports are stored in the mock and referred to by number, rather than serialized.
+const EXPORT_PREFIX: &str = "mock chaperone ";
+
+unsafe extern "C" fn export_live_to_buffer(buffer: *mut c_char, len: *mut u32) -> bool {
+    with_state(|s| {
+        let text = format!("{}{}\0", EXPORT_PREFIX, s.chaperone.exports.len());
+        let fits = !buffer.is_null() && *len as usize >= text.len();
+        *len = text.len() as u32;
+        if fits {
+            ptr::copy_nonoverlapping(text.as_ptr() as *const c_char, buffer, text.len());
+            let config = live_config(&s.chaperone);
+            s.chaperone.exports.push(config);
+        }
+        fits
+    })
+}
+
+unsafe extern "C" fn import_from_buffer_to_working(buffer: *mut c_char, flags: u32) -> bool {
+    let text = CStr::from_ptr(buffer).to_string_lossy();
+    let Some(index) = text
+        .strip_prefix(EXPORT_PREFIX)
+        .and_then(|x| x.parse::<usize>().ok())
+    else {
+        return false;
+    };
+    with_state(|s| {
+        let Some(config) = s.chaperone.exports.get(index).cloned() else {
+            return false;
+        };
+        if flags & sys::EChaperoneImportFlags_EChaperoneImport_BoundsOnly != 0 {
+            s.chaperone.working.collision_bounds = config.collision_bounds;
+        } else {
+            s.chaperone.working = config;
+        }
+        true
+    })
+}
+
+unsafe extern "C" fn show_working_set_preview() {
+    with_state(|s| s.chaperone.preview_visible = true)
+}
+
+unsafe extern "C" fn hide_working_set_preview() {
+    with_state(|s| s.chaperone.preview_visible = false)
+}
+
+unsafe extern "C" fn room_setup_starting() {}
@@ -0,0 +1,163 @@
+use std::mem;
+
+use super::{with_state, MockCompositorCall};
+use crate::{sys, Eye, MAX_TRACKED_DEVICE_COUNT};
+
+lazy_static! {
+    pub(super) static ref TABLE: sys::VR_IVRCompositor_FnTable = sys::VR_IVRCompositor_FnTable {
+        SetTrackingSpace: Some(set_tracking_space),
+        GetTrackingSpace: Some(get_tracking_space),
+        WaitGetPoses: Some(wait_get_poses),
+        Submit: Some(submit),
+        ClearLastSubmittedFrame: Some(clear_last_submitted_frame),
+        PostPresentHandoff: Some(post_present_handoff),
+        GetFrameTiming: Some(get_frame_timing),
+        GetFrameTimings: Some(get_frame_timings),
+        GetFrameTimeRemaining: Some(get_frame_time_remaining),
+        GetCumulativeStats: Some(get_cumulative_stats),
+        GetLastFrameRenderer: Some(get_last_frame_renderer),
+        IsFullscreen: Some(is_fullscreen),
+        SetExplicitTimingMode: Some(set_explicit_timing_mode),
+        SubmitExplicitTimingData: Some(submit_explicit_timing_data),
+        ..Default::default()
+    };
+}
+
+unsafe extern "C" fn set_tracking_space(origin: sys::ETrackingUniverseOrigin) {
+    with_state(|s| s.compositor.tracking_space = origin)
+}
+
+unsafe extern "C" fn get_tracking_space() -> sys::ETrackingUniverseOrigin {
+    with_state(|s| s.compositor.tracking_space)
+}
+
+unsafe extern "C" fn wait_get_poses(
+    render: *mut sys::TrackedDevicePose_t,
+    render_count: u32,
+    game: *mut sys::TrackedDevicePose_t,
+    game_count: u32,
+) -> sys::EVRCompositorError {
+    with_state(|s| {
+        for (poses, count) in [(render, render_count), (game, game_count)] {
+            if poses.is_null() {
+                continue;
+            }
+            for i in 0..(count as usize).min(MAX_TRACKED_DEVICE_COUNT) {
+                *poses.add(i) = s.pose(i).0;
+            }
+        }
+        s.compositor.frame_count += 1;
+        s.compositor.calls.push(MockCompositorCall::WaitGetPoses);
+    });
+    sys::EVRCompositorError_VRCompositorError_None
+}
+
+unsafe extern "C" fn submit(
+    eye: sys::EVREye,
+    _texture: *mut sys::Texture_t,
+    _bounds: *mut sys::VRTextureBounds_t,
+    _flags: sys::EVRSubmitFlags,
+) -> sys::EVRCompositorError {
+    with_state(|s| {
+        let c = &mut s.compositor;
+        let Some(count) = c.submitted.get_mut(eye as usize) else {
+            return sys::EVRCompositorError_VRCompositorError_RequestFailed;
+        };
+        c.calls
+            .push(MockCompositorCall::Submit(if eye == sys::EVREye_Eye_Left {
+                Eye::Left
+            } else {
+                Eye::Right
+            }));
+        if let Some(error) = c.submit_error.take() {
+            return error;
+        }
+        *count += 1;
+        sys::EVRCompositorError_VRCompositorError_None
+    })
+}
+
+unsafe extern "C" fn clear_last_submitted_frame() {}
+
+unsafe extern "C" fn post_present_handoff() {
+    with_state(|s| {
+        s.compositor.post_present_handoffs += 1;
+        s.compositor
+            .calls
+            .push(MockCompositorCall::PostPresentHandoff);
+    })
+}
+
+const FRAME_TIMING_SIZE: u32 = mem::size_of::<sys::Compositor_FrameTiming>() as u32;
+
+unsafe extern "C" fn get_frame_timing(
+    timing: *mut sys::Compositor_FrameTiming,
+    frames_ago: u32,
+) -> bool {
+    if (*timing).m_nSize != FRAME_TIMING_SIZE {
+        return false;
+    }
+    with_state(|s| {
+        let timings = &s.compositor.timings;
+        let Some(last) = timings.len().checked_sub(1) else {
+            return false;
+        };
+        *timing = timings[last.saturating_sub(frames_ago as usize)];
+        true
+    })
+}
+
+unsafe extern "C" fn get_frame_timings(
+    timings: *mut sys::Compositor_FrameTiming,
+    count: u32,
+) -> u32 {
+    if count == 0 || (*timings).m_nSize != FRAME_TIMING_SIZE {
+        return 0;
+    }
+    with_state(|s| {
+        let history = &s.compositor.timings;
+        let n = history.len().min(count as usize);
+        for (i, timing) in history.iter().skip(history.len() - n).enumerate() {
+            *timings.add(i) = *timing;
+        }
+        n as u32
+    })
+}
+
+unsafe extern "C" fn get_frame_time_remaining() -> f32 {
+    with_state(|s| s.compositor.frame_time_remaining)
+}
+
+unsafe extern "C" fn get_cumulative_stats(stats: *mut sys::Compositor_CumulativeStats, size: u32) {
+    if size as usize == mem::size_of::<sys::Compositor_CumulativeStats>() {
+        *stats = with_state(|s| s.compositor.cumulative_stats);
+    }
+}
+
+unsafe extern "C" fn get_last_frame_renderer() -> u32 {
+    with_state(|s| s.compositor.last_frame_renderer)
+}
+
+unsafe extern "C" fn is_fullscreen() -> bool {
+    false
+}
+
+unsafe extern "C" fn set_explicit_timing_mode(mode: sys::EVRCompositorTimingMode) {
+    with_state(|s| {
+        s.compositor.explicit_timing =
+            mode != sys::EVRCompositorTimingMode_VRCompositorTimingMode_Implicit
+    })
+}
+
+unsafe extern "C" fn submit_explicit_timing_data() -> sys::EVRCompositorError {
+    if with_state(|s| {
+        s.compositor
+            .calls
+            .push(MockCompositorCall::SubmitExplicitTimingData);
+        s.compositor.explicit_timing
+    }) {
+        sys::EVRCompositorError_VRCompositorError_None
+    } else {
+        sys::EVRCompositorError_VRCompositorError_RequestFailed
+    }
+}
@@ -0,0 +1,463 @@
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+use super::{
+    with_state, BindingUiRequest, HapticVibration, MockAction, MockActionValue, MockSkeleton, State,
+};
+use crate::input::BoneTransform;
+use crate::input::{VRActionHandle, VRActionSetHandle, VRInputValueHandle};
+use crate::sys;
+
+lazy_static! {
+    pub(super) static ref TABLE: sys::VR_IVRInput_FnTable = sys::VR_IVRInput_FnTable {
+        SetActionManifestPath: Some(set_action_manifest_path),
+        GetActionSetHandle: Some(get_handle),
+        GetActionHandle: Some(get_handle),
+        GetInputSourceHandle: Some(get_handle),
+        UpdateActionState: Some(update_action_state),
+        GetDigitalActionData: Some(get_digital_action_data),
+        GetAnalogActionData: Some(get_analog_action_data),
+        GetPoseActionDataRelativeToNow: Some(get_pose_action_data_relative_to_now),
+        GetPoseActionDataForNextFrame: Some(get_pose_action_data_for_next_frame),
+        GetSkeletalActionData: Some(get_skeletal_action_data),
+        GetDominantHand: Some(get_dominant_hand),
+        SetDominantHand: Some(set_dominant_hand),
+        GetActionOrigins: Some(get_action_origins),
+        GetActionBindingInfo: Some(get_action_binding_info),
+        IsUsingLegacyInput: Some(is_using_legacy_input),
+        OpenBindingUI: Some(open_binding_ui),
+        TriggerHapticVibrationAction: Some(trigger_haptic_vibration_action),
+        GetBoneCount: Some(get_bone_count),
+        GetBoneHierarchy: Some(get_bone_hierarchy),
+        GetBoneName: Some(get_bone_name),
+        GetSkeletalReferenceTransforms: Some(get_skeletal_reference_transforms),
+        GetSkeletalTrackingLevel: Some(get_skeletal_tracking_level),
+        GetSkeletalBoneData: Some(get_skeletal_bone_data),
+        GetSkeletalSummaryData: Some(get_skeletal_summary_data),
+        GetSkeletalBoneDataCompressed: Some(get_skeletal_bone_data_compressed),
+        DecompressSkeletalBoneData: Some(decompress_skeletal_bone_data),
+        ..Default::default()
+    };
+}
+
+unsafe extern "C" fn set_action_manifest_path(path: *mut c_char) -> sys::EVRInputError {
+    let path = CStr::from_ptr(path).to_string_lossy().into_owned();
+    with_state(|s| s.input.manifest = Some(path.into()));
+    sys::EVRInputError_VRInputError_None
+}
+
+unsafe extern "C" fn get_handle(name: *mut c_char, handle: *mut u64) -> sys::EVRInputError {
+    let name = CStr::from_ptr(name).to_string_lossy();
+    *handle = with_state(|s| s.input_handle(&name));
+    sys::EVRInputError_VRInputError_None
+}
+
+unsafe extern "C" fn update_action_state(
+    _sets: *mut sys::VRActiveActionSet_t,
+    _size: u32,
+    _count: u32,
+) -> sys::EVRInputError {
+    with_state(|s| {
+        s.input.action_updates += 1;
+        for action in s.input.actions.values_mut() {
+            action.previous = action.current;
+            action.current = action.pending;
+        }
+    });
+    sys::EVRInputError_VRInputError_None
+}
+
+/// Runs `f` on the value action `action` has been given, `None` if it is a valid handle that
+/// hasn't, failing with `InvalidHandle` if it isn't a handle at all.
+fn with_action(
+    action: sys::VRActionHandle_t,
+    f: impl FnOnce(Option<&MockAction>) -> sys::EVRInputError,
+) -> sys::EVRInputError {
+    with_state(|s| match s.input.actions.get(&action) {
+        Some(x) => f(Some(x)),
+        None if s.input.handles.values().any(|&x| x == action) => f(None),
+        None => sys::EVRInputError_VRInputError_InvalidHandle,
+    })
+}
+
+unsafe extern "C" fn get_digital_action_data(
+    action: sys::VRActionHandle_t,
+    data: *mut sys::InputDigitalActionData_t,
+    _size: u32,
+    _restrict: sys::VRInputValueHandle_t,
+) -> sys::EVRInputError {
+    with_action(action, |x| {
+        *data = Default::default();
+        if let Some(x) = x {
+            let (MockActionValue::Digital(state), MockActionValue::Digital(previous)) =
+                (x.current, x.previous)
+            else {
+                return sys::EVRInputError_VRInputError_WrongType;
+            };
+            *data = sys::InputDigitalActionData_t {
+                bActive: true,
+                activeOrigin: x.origin.0,
+                bState: state,
+                bChanged: state != previous,
+                fUpdateTime: 0.0,
+            };
+        }
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+unsafe extern "C" fn get_analog_action_data(
+    action: sys::VRActionHandle_t,
+    data: *mut sys::InputAnalogActionData_t,
+    _size: u32,
+    _restrict: sys::VRInputValueHandle_t,
+) -> sys::EVRInputError {
+    with_action(action, |x| {
+        *data = Default::default();
+        if let Some(x) = x {
+            let (MockActionValue::Analog(v), MockActionValue::Analog(p)) = (x.current, x.previous)
+            else {
+                return sys::EVRInputError_VRInputError_WrongType;
+            };
+            *data = sys::InputAnalogActionData_t {
+                bActive: true,
+                activeOrigin: x.origin.0,
+                x: v[0],
+                y: v[1],
+                z: v[2],
+                deltaX: v[0] - p[0],
+                deltaY: v[1] - p[1],
+                deltaZ: v[2] - p[2],
+                fUpdateTime: 0.0,
+            };
+        }
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+unsafe extern "C" fn get_pose_action_data_relative_to_now(
+    action: sys::VRActionHandle_t,
+    origin: sys::ETrackingUniverseOrigin,
+    _seconds_from_now: f32,
+    data: *mut sys::InputPoseActionData_t,
+    size: u32,
+    restrict: sys::VRInputValueHandle_t,
+) -> sys::EVRInputError {
+    get_pose_action_data_for_next_frame(action, origin, data, size, restrict)
+}
+
+unsafe extern "C" fn get_pose_action_data_for_next_frame(
+    action: sys::VRActionHandle_t,
+    _origin: sys::ETrackingUniverseOrigin,
+    data: *mut sys::InputPoseActionData_t,
+    _size: u32,
+    _restrict: sys::VRInputValueHandle_t,
+) -> sys::EVRInputError {
+    with_action(action, |x| {
+        *data = Default::default();
+        if let Some(x) = x {
+            let MockActionValue::Pose(pose) = x.current else {
+                return sys::EVRInputError_VRInputError_WrongType;
+            };
+            *data = sys::InputPoseActionData_t {
+                bActive: true,
+                activeOrigin: x.origin.0,
+                pose: pose.0,
+            };
+        }
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+unsafe extern "C" fn get_dominant_hand(
+    hand: *mut sys::ETrackedControllerRole,
+) -> sys::EVRInputError {
+    *hand = with_state(|s| s.input.dominant_hand);
+    sys::EVRInputError_VRInputError_None
+}
+
+unsafe extern "C" fn set_dominant_hand(hand: sys::ETrackedControllerRole) -> sys::EVRInputError {
+    if hand != sys::ETrackedControllerRole_TrackedControllerRole_LeftHand
+        && hand != sys::ETrackedControllerRole_TrackedControllerRole_RightHand
+    {
+        return sys::EVRInputError_VRInputError_InvalidParam;
+    }
+    with_state(|s| s.input.dominant_hand = hand);
+    sys::EVRInputError_VRInputError_None
+}
+
+unsafe extern "C" fn get_action_origins(
+    _set: sys::VRActionSetHandle_t,
+    action: sys::VRActionHandle_t,
+    origins: *mut sys::VRInputValueHandle_t,
+    count: u32,
+) -> sys::EVRInputError {
+    let bound: Vec<_> = with_state(|s| {
+        s.input
+            .bindings
+            .get(&action)
+            .map(|x| x.iter().map(|b| b.0 .0).collect())
+    })
+    .unwrap_or_default();
+    for i in 0..count as usize {
+        *origins.add(i) = bound
+            .get(i)
+            .copied()
+            .unwrap_or(sys::k_ulInvalidInputValueHandle);
+    }
+    sys::EVRInputError_VRInputError_None
+}
+
+unsafe extern "C" fn get_action_binding_info(
+    action: sys::VRActionHandle_t,
+    info: *mut sys::InputBindingInfo_t,
+    _size: u32,
+    count: u32,
+    returned: *mut u32,
+) -> sys::EVRInputError {
+    let bound: Vec<_> = with_state(|s| s.input.bindings.get(&action).cloned()).unwrap_or_default();
+    *returned = bound.len() as u32;
+    if bound.len() > count as usize {
+        return sys::EVRInputError_VRInputError_BufferTooSmall;
+    }
+    for (i, (_, binding)) in bound.iter().enumerate() {
+        *info.add(i) = binding.into();
+    }
+    sys::EVRInputError_VRInputError_None
+}
+
+unsafe extern "C" fn is_using_legacy_input() -> bool {
+    with_state(|s| s.input.legacy_input)
+}
+
+unsafe extern "C" fn open_binding_ui(
+    app_key: *mut c_char,
+    action_set: sys::VRActionSetHandle_t,
+    input_device: sys::VRInputValueHandle_t,
+    show_on_desktop: bool,
+) -> sys::EVRInputError {
+    let app_key =
+        (!app_key.is_null()).then(|| CStr::from_ptr(app_key).to_string_lossy().into_owned());
+    let request = BindingUiRequest {
+        app_key,
+        action_set: (action_set != sys::k_ulInvalidActionSetHandle)
+            .then_some(VRActionSetHandle(action_set)),
+        input_device: (input_device != sys::k_ulInvalidInputValueHandle)
+            .then_some(VRInputValueHandle(input_device)),
+        show_on_desktop,
+    };
+    with_state(|s| s.input.binding_ui_requests.push(request));
+    sys::EVRInputError_VRInputError_None
+}
+
+unsafe extern "C" fn trigger_haptic_vibration_action(
+    action: sys::VRActionHandle_t,
+    start_seconds_from_now: f32,
+    duration_seconds: f32,
+    frequency: f32,
+    amplitude: f32,
+    restrict: sys::VRInputValueHandle_t,
+) -> sys::EVRInputError {
+    let vibration = HapticVibration {
+        action: VRActionHandle(action),
+        start_seconds_from_now,
+        duration_seconds,
+        frequency,
+        amplitude,
+        restrict: VRInputValueHandle(restrict),
+    };
+    with_state(|s| match s.input.vibration_error.take() {
+        Some(error) => error,
+        None => {
+            s.input.vibrations.push(vibration);
+            sys::EVRInputError_VRInputError_None
+        }
+    })
+}
+
+/// Runs `f` on the skeleton bound to `action`, failing with `InvalidHandle` if there is none.
+fn with_skeleton(
+    action: sys::VRActionHandle_t,
+    f: impl FnOnce(&MockSkeleton) -> sys::EVRInputError,
+) -> sys::EVRInputError {
+    with_state(|s: &mut State| match s.input.skeletons.get(&action) {
+        Some(skeleton) => f(skeleton),
+        None => sys::EVRInputError_VRInputError_InvalidHandle,
+    })
+}
+
+/// Copies `bones` to `out`, which the caller sized for `count` bones.
+unsafe fn copy_bones(
+    bones: &[BoneTransform],
+    out: *mut sys::VRBoneTransform_t,
+    count: u32,
+) -> sys::EVRInputError {
+    if bones.len() != count as usize {
+        return sys::EVRInputError_VRInputError_InvalidBoneCount;
+    }
+    ptr::copy_nonoverlapping(bones.as_ptr().cast(), out, bones.len());
+    sys::EVRInputError_VRInputError_None
+}
+
+unsafe extern "C" fn get_skeletal_action_data(
+    action: sys::VRActionHandle_t,
+    data: *mut sys::InputSkeletalActionData_t,
+    _size: u32,
+) -> sys::EVRInputError {
+    with_skeleton(action, |x| {
+        *data = sys::InputSkeletalActionData_t {
+            bActive: x.active,
+            activeOrigin: x.origin.0,
+        };
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+unsafe extern "C" fn get_bone_count(
+    action: sys::VRActionHandle_t,
+    count: *mut u32,
+) -> sys::EVRInputError {
+    with_skeleton(action, |x| {
+        *count = x.bone_names.len() as u32;
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+unsafe extern "C" fn get_bone_hierarchy(
+    action: sys::VRActionHandle_t,
+    parents: *mut sys::BoneIndex_t,
+    count: u32,
+) -> sys::EVRInputError {
+    with_skeleton(action, |x| {
+        if x.parents.len() != count as usize {
+            return sys::EVRInputError_VRInputError_InvalidBoneCount;
+        }
+        ptr::copy_nonoverlapping(x.parents.as_ptr(), parents, x.parents.len());
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+unsafe extern "C" fn get_bone_name(
+    action: sys::VRActionHandle_t,
+    bone: sys::BoneIndex_t,
+    name: *mut c_char,
+    size: u32,
+) -> sys::EVRInputError {
+    with_skeleton(action, |x| {
+        let Some(bone_name) = usize::try_from(bone).ok().and_then(|i| x.bone_names.get(i)) else {
+            return sys::EVRInputError_VRInputError_InvalidBoneIndex;
+        };
+        if bone_name.len() >= size as usize {
+            return sys::EVRInputError_VRInputError_BufferTooSmall;
+        }
+        ptr::copy_nonoverlapping(bone_name.as_ptr().cast(), name, bone_name.len());
+        *name.add(bone_name.len()) = 0;
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+unsafe extern "C" fn get_skeletal_reference_transforms(
+    action: sys::VRActionHandle_t,
+    _space: sys::EVRSkeletalTransformSpace,
+    _pose: sys::EVRSkeletalReferencePose,
+    bones: *mut sys::VRBoneTransform_t,
+    count: u32,
+) -> sys::EVRInputError {
+    with_skeleton(action, |x| copy_bones(&x.reference, bones, count))
+}
+
+unsafe extern "C" fn get_skeletal_tracking_level(
+    action: sys::VRActionHandle_t,
+    level: *mut sys::EVRSkeletalTrackingLevel,
+) -> sys::EVRInputError {
+    with_skeleton(action, |x| {
+        *level = x.tracking_level as sys::EVRSkeletalTrackingLevel;
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+unsafe extern "C" fn get_skeletal_bone_data(
+    action: sys::VRActionHandle_t,
+    _space: sys::EVRSkeletalTransformSpace,
+    _range: sys::EVRSkeletalMotionRange,
+    bones: *mut sys::VRBoneTransform_t,
+    count: u32,
+) -> sys::EVRInputError {
+    with_skeleton(action, |x| {
+        if !x.active {
+            return sys::EVRInputError_VRInputError_NoData;
+        }
+        copy_bones(&x.bones, bones, count)
+    })
+}
+
+unsafe extern "C" fn get_skeletal_summary_data(
+    action: sys::VRActionHandle_t,
+    _summary_type: sys::EVRSummaryType,
+    data: *mut sys::VRSkeletalSummaryData_t,
+) -> sys::EVRInputError {
+    with_skeleton(action, |x| {
+        if !x.active {
+            return sys::EVRInputError_VRInputError_NoData;
+        }
+        *data = sys::VRSkeletalSummaryData_t {
+            flFingerCurl: x.summary.finger_curl,
+            flFingerSplay: x.summary.finger_splay,
+        };
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+// The mock "compresses" skeletons to the bone count as a little-endian `u32` followed by the raw
+// transforms.
+
+unsafe extern "C" fn get_skeletal_bone_data_compressed(
+    action: sys::VRActionHandle_t,
+    _range: sys::EVRSkeletalMotionRange,
+    data: *mut c_void,
+    size: u32,
+    required: *mut u32,
+) -> sys::EVRInputError {
+    with_skeleton(action, |x| {
+        if !x.active {
+            return sys::EVRInputError_VRInputError_NoData;
+        }
+        let bones = std::slice::from_raw_parts(
+            x.bones.as_ptr().cast::<u8>(),
+            mem::size_of_val(&x.bones[..]),
+        );
+        let len = 4 + bones.len();
+        *required = len as u32;
+        if data.is_null() || (size as usize) < len {
+            return sys::EVRInputError_VRInputError_BufferTooSmall;
+        }
+        let data = data.cast::<u8>();
+        ptr::copy_nonoverlapping((x.bones.len() as u32).to_le_bytes().as_ptr(), data, 4);
+        ptr::copy_nonoverlapping(bones.as_ptr(), data.add(4), bones.len());
+        sys::EVRInputError_VRInputError_None
+    })
+}
+
+unsafe extern "C" fn decompress_skeletal_bone_data(
+    data: *mut c_void,
+    size: u32,
+    _space: sys::EVRSkeletalTransformSpace,
+    bones: *mut sys::VRBoneTransform_t,
+    count: u32,
+) -> sys::EVRInputError {
+    let data = std::slice::from_raw_parts(data.cast::<u8>().cast_const(), size as usize);
+    if data.len() < 4 {
+        return sys::EVRInputError_VRInputError_InvalidCompressedData;
+    }
+    let bone_count = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
+    if data.len() != 4 + bone_count * mem::size_of::<sys::VRBoneTransform_t>() {
+        return sys::EVRInputError_VRInputError_InvalidCompressedData;
+    }
+    if bone_count != count as usize {
+        return sys::EVRInputError_VRInputError_InvalidBoneCount;
+    }
+    ptr::copy_nonoverlapping(data[4..].as_ptr(), bones.cast::<u8>(), data.len() - 4);
+    sys::EVRInputError_VRInputError_None
+}
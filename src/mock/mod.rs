@@ -0,0 +1,1141 @@
+//! In-process fake of the OpenVR runtime for tests and CI.
+//!
+//! A [`MockRuntime`] owns scriptable state (devices, poses, pending events, a settings store,
+//! overlays, chaperone data and skeletal input) and backs a set of function tables that stand in
+//! for the ones normally handed out by `vrclient`. A [`Context`] created with [`Context::mock`]
+//! hands out the usual `System`, `Compositor`, `Settings`, ... wrappers, so code written against
+//! this crate can be exercised without SteamVR or a headset.
+//!
+//! ```
+//! use openvr::mock::{MockDevice, MockRuntime};
+//! use openvr::{Context, TrackedDeviceClass};
+//!
+//! let runtime = MockRuntime::new();
+//! let hmd = runtime.add_device(MockDevice::new(TrackedDeviceClass::HMD));
+//! let context = Context::mock(&runtime);
+//! let system = context.system().unwrap();
+//! assert_eq!(system.tracked_device_class(hmd), TrackedDeviceClass::HMD);
+//! ```
+//!
+//! The runtime is bound to the thread that created the mock `Context`; only one may be active per
+//! thread. Functions that are not faked are left empty in the tables, so calling the corresponding
+//! wrapper panics.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::mem;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use crate::input::{
+    BindingInfo, BoneIndex, BoneTransform, SkeletalSummary, SkeletalTrackingLevel, VRActionHandle,
+    VRActionSetHandle, VRInputValueHandle,
+};
+use crate::property::{ArrayElement, Property};
+use crate::system::DeviceActivityLevel;
+use crate::{
+    sys, ColorTint, Context, ControllerState, Eye, InitError, TrackedControllerRole,
+    TrackedDeviceClass, TrackedDeviceIndex, TrackedDevicePose, MAX_TRACKED_DEVICE_COUNT,
+};
+
+mod chaperone;
+mod compositor;
+mod input;
+mod overlay;
+mod settings;
+mod system;
+
+/// Value stored for a tracked device property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    Bool(bool),
+    Float(f32),
+    Int32(i32),
+    Uint64(u64),
+    Matrix34([[f32; 4]; 3]),
+    String(String),
+    /// Read through `GetArrayTrackedDeviceProperty`; `tag` is one of the `k_un*PropertyTag` values.
+    Array {
+        tag: sys::PropertyTypeTag_t,
+        data: Vec<u8>,
+    },
+}
+
+impl PropertyValue {
+    /// An array value holding `values`, tagged with their element type.
+    pub fn array<T: ArrayElement>(values: Vec<T>) -> Self {
+        let raw: Vec<T::Raw> = values.into_iter().map(T::into_raw).collect();
+        let data = unsafe {
+            std::slice::from_raw_parts(raw.as_ptr() as *const u8, mem::size_of_val(&raw[..]))
+                .to_vec()
+        };
+        PropertyValue::Array { tag: T::TAG, data }
+    }
+
+    /// The value as the runtime hands it out through `GetArrayTrackedDeviceProperty`.
+    fn tagged_bytes(&self) -> (sys::PropertyTypeTag_t, Vec<u8>) {
+        fn tagged<T: Copy>(tag: std::os::raw::c_ulong, x: T) -> (sys::PropertyTypeTag_t, Vec<u8>) {
+            let data = unsafe {
+                std::slice::from_raw_parts(&x as *const T as *const u8, mem::size_of::<T>())
+                    .to_vec()
+            };
+            (tag as sys::PropertyTypeTag_t, data)
+        }
+        match *self {
+            PropertyValue::Bool(x) => tagged(sys::k_unBoolPropertyTag, x),
+            PropertyValue::Float(x) => tagged(sys::k_unFloatPropertyTag, x),
+            PropertyValue::Int32(x) => tagged(sys::k_unInt32PropertyTag, x),
+            PropertyValue::Uint64(x) => tagged(sys::k_unUint64PropertyTag, x),
+            PropertyValue::Matrix34(x) => tagged(sys::k_unHmdMatrix34PropertyTag, x),
+            PropertyValue::String(ref x) => {
+                let mut data = x.clone().into_bytes();
+                data.push(0);
+                (sys::k_unStringPropertyTag as sys::PropertyTypeTag_t, data)
+            }
+            PropertyValue::Array { tag, ref data } => (tag, data.clone()),
+        }
+    }
+}
+
+impl<T: ArrayElement> From<Vec<T>> for PropertyValue {
+    fn from(x: Vec<T>) -> Self {
+        PropertyValue::array(x)
+    }
+}
+
+impl From<[f32; 3]> for PropertyValue {
+    fn from(x: [f32; 3]) -> Self {
+        PropertyValue::array(vec![x])
+    }
+}
+
+macro_rules! impl_property_value {
+    ($($ty:ty => $variant:ident),*) => {
+        $(impl From<$ty> for PropertyValue {
+            fn from(x: $ty) -> Self {
+                PropertyValue::$variant(x)
+            }
+        })*
+    };
+}
+
+impl_property_value!(
+    bool => Bool,
+    f32 => Float,
+    i32 => Int32,
+    u64 => Uint64,
+    [[f32; 4]; 3] => Matrix34,
+    String => String
+);
+
+/// Value stored in the mock settings store.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingValue {
+    Bool(bool),
+    Int32(i32),
+    Float(f32),
+    String(String),
+}
+
+/// A fake tracked device.
+#[derive(Debug, Clone)]
+pub struct MockDevice {
+    pub class: TrackedDeviceClass,
+    pub role: TrackedControllerRole,
+    pub connected: bool,
+    pub pose: TrackedDevicePose,
+    pub properties: HashMap<sys::ETrackedDeviceProperty, PropertyValue>,
+    /// Returned by `GetControllerState`; `None` makes the call fail.
+    pub controller_state: Option<ControllerState>,
+    pub activity_level: DeviceActivityLevel,
+}
+
+impl MockDevice {
+    /// A connected device of the given class, tracking at the origin with identity orientation.
+    pub fn new(class: TrackedDeviceClass) -> Self {
+        MockDevice {
+            class,
+            role: TrackedControllerRole::Invalid,
+            connected: true,
+            pose: identity_pose(),
+            properties: HashMap::new(),
+            controller_state: None,
+            activity_level: DeviceActivityLevel::UserInteraction,
+        }
+    }
+
+    pub fn with_role(mut self, role: TrackedControllerRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    pub fn with_pose(mut self, pose: TrackedDevicePose) -> Self {
+        self.pose = pose;
+        self
+    }
+
+    pub fn with_property<T: Into<PropertyValue>>(
+        mut self,
+        key: Property<T>,
+        value: impl Into<T>,
+    ) -> Self {
+        self.properties.insert(key.raw(), value.into().into());
+        self
+    }
+
+    pub fn with_controller_state(mut self, state: ControllerState) -> Self {
+        self.controller_state = Some(state);
+        self
+    }
+}
+
+/// Snapshot of the state of a mock overlay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockOverlay {
+    pub visible: bool,
+    pub tint: ColorTint,
+    pub width: f32,
+    pub curvature: f32,
+    pub sort_order: u32,
+    pub texel_aspect: f32,
+    pub texture_bounds: [f32; 4],
+    pub transform: OverlayTransform,
+}
+
+/// Where a mock overlay has been placed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OverlayTransform {
+    None,
+    Absolute(sys::ETrackingUniverseOrigin, [[f32; 4]; 3]),
+    TrackedDeviceRelative(TrackedDeviceIndex, [[f32; 4]; 3]),
+}
+
+impl Default for MockOverlay {
+    fn default() -> Self {
+        MockOverlay {
+            visible: false,
+            tint: ColorTint::default(),
+            width: 1.0,
+            curvature: 0.0,
+            sort_order: 0,
+            texel_aspect: 1.0,
+            texture_bounds: [0.0, 0.0, 1.0, 1.0],
+            transform: OverlayTransform::None,
+        }
+    }
+}
+
+/// A fake skeleton returned for a skeletal action.
+///
+/// The same transforms are returned for every transform space, motion range and reference pose.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockSkeleton {
+    pub active: bool,
+    pub origin: VRInputValueHandle,
+    pub bone_names: Vec<String>,
+    /// Parent of each bone; `-1` for the root.
+    pub parents: Vec<BoneIndex>,
+    /// Returned by `GetSkeletalBoneData`.
+    pub bones: Vec<BoneTransform>,
+    /// Returned by `GetSkeletalReferenceTransforms`.
+    pub reference: Vec<BoneTransform>,
+    pub tracking_level: SkeletalTrackingLevel,
+    pub summary: SkeletalSummary,
+}
+
+impl MockSkeleton {
+    /// An active, fully tracked skeleton with the given bones, all at their default transform.
+    pub fn new(bones: &[(&str, BoneIndex)]) -> Self {
+        MockSkeleton {
+            active: true,
+            origin: VRInputValueHandle(0),
+            bone_names: bones.iter().map(|x| x.0.to_owned()).collect(),
+            parents: bones.iter().map(|x| x.1).collect(),
+            bones: vec![BoneTransform::default(); bones.len()],
+            reference: vec![BoneTransform::default(); bones.len()],
+            tracking_level: SkeletalTrackingLevel::Full,
+            summary: SkeletalSummary::default(),
+        }
+    }
+}
+
+/// Value of a mock action, reported from the next `UpdateActionState` on.
+#[derive(Debug, Copy, Clone)]
+pub enum MockActionValue {
+    Digital(bool),
+    Analog([f32; 3]),
+    Pose(TrackedDevicePose),
+}
+
+impl MockActionValue {
+    /// The value of the same kind an unbound action reports.
+    fn neutral(&self) -> Self {
+        match *self {
+            MockActionValue::Digital(_) => MockActionValue::Digital(false),
+            MockActionValue::Analog(_) => MockActionValue::Analog([0.0; 3]),
+            MockActionValue::Pose(_) => MockActionValue::Pose(disconnected_pose()),
+        }
+    }
+}
+
+struct MockAction {
+    origin: VRInputValueHandle,
+    /// Set by the test, latched into `current` by `UpdateActionState`.
+    pending: MockActionValue,
+    current: MockActionValue,
+    previous: MockActionValue,
+}
+
+struct InputState {
+    /// Handles handed out for action, action set and input source paths, by lowercased path.
+    handles: HashMap<String, u64>,
+    skeletons: HashMap<sys::VRActionHandle_t, MockSkeleton>,
+    actions: HashMap<sys::VRActionHandle_t, MockAction>,
+    action_updates: u32,
+    /// Origins and bindings of each action, in the order they are reported.
+    bindings: HashMap<sys::VRActionHandle_t, Vec<(VRInputValueHandle, BindingInfo)>>,
+    dominant_hand: sys::ETrackedControllerRole,
+    legacy_input: bool,
+    binding_ui_requests: Vec<BindingUiRequest>,
+    vibrations: Vec<HapticVibration>,
+    vibration_error: Option<sys::EVRInputError>,
+    manifest: Option<PathBuf>,
+}
+
+/// A call to `OpenBindingUI`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingUiRequest {
+    pub app_key: Option<String>,
+    pub action_set: Option<VRActionSetHandle>,
+    pub input_device: Option<VRInputValueHandle>,
+    pub show_on_desktop: bool,
+}
+
+/// A call to `TriggerHapticVibrationAction`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HapticVibration {
+    pub action: VRActionHandle,
+    pub start_seconds_from_now: f32,
+    pub duration_seconds: f32,
+    pub frequency: f32,
+    pub amplitude: f32,
+    pub restrict: VRInputValueHandle,
+}
+
+const FRAME_TIMING_HISTORY: usize = 128;
+
+/// A compositor call that is part of a frame, as recorded by `MockRuntime::compositor_calls`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MockCompositorCall {
+    WaitGetPoses,
+    SubmitExplicitTimingData,
+    Submit(Eye),
+    PostPresentHandoff,
+}
+
+struct ChaperoneState {
+    calibration_state: sys::ChaperoneCalibrationState,
+    play_area_size: Option<(f32, f32)>,
+    play_area_rect: Option<[[f32; 3]; 4]>,
+    bounds_visible: bool,
+    bounds_forced: bool,
+    collision_bounds: Vec<[[f32; 3]; 4]>,
+    seated_zero_pose: [[f32; 4]; 3],
+    standing_zero_pose: [[f32; 4]; 3],
+    /// The `IVRChaperoneSetup` working copy.
+    working: ChaperoneConfig,
+    preview_visible: bool,
+    scene_color: Option<ColorTint>,
+    bounds_color: ColorTint,
+    /// Configurations handed out by `ExportLiveToBuffer`, indexed by the number in the exported
+    /// text.
+    exports: Vec<ChaperoneConfig>,
+}
+
+/// A chaperone configuration as edited through `IVRChaperoneSetup`.
+#[derive(Clone)]
+struct ChaperoneConfig {
+    play_area_size: Option<(f32, f32)>,
+    collision_bounds: Vec<[[f32; 3]; 4]>,
+    seated_zero_pose: [[f32; 4]; 3],
+    standing_zero_pose: [[f32; 4]; 3],
+}
+
+struct CompositorState {
+    tracking_space: sys::ETrackingUniverseOrigin,
+    frame_count: u32,
+    submitted: [u32; 2],
+    post_present_handoffs: u32,
+    explicit_timing: bool,
+    calls: Vec<MockCompositorCall>,
+    /// Error returned by the next `Submit`.
+    submit_error: Option<sys::EVRCompositorError>,
+    /// Frame timing history, oldest first.
+    timings: VecDeque<sys::Compositor_FrameTiming>,
+    frame_time_remaining: f32,
+    cumulative_stats: sys::Compositor_CumulativeStats,
+    last_frame_renderer: u32,
+}
+
+struct State {
+    render_target_size: (u32, u32),
+    devices: Vec<Option<MockDevice>>,
+    events: VecDeque<sys::VREvent_t>,
+    haptic_pulses: Vec<(TrackedDeviceIndex, u32, u16)>,
+    quit_acknowledged: bool,
+    raw_zero_pose_to_standing: [[f32; 4]; 3],
+    seated_zero_pose_to_standing: [[f32; 4]; 3],
+    settings: HashMap<(String, String), SettingValue>,
+    overlays: BTreeMap<sys::VROverlayHandle_t, MockOverlay>,
+    overlay_events: HashMap<sys::VROverlayHandle_t, VecDeque<sys::VREvent_t>>,
+    next_overlay: sys::VROverlayHandle_t,
+    dashboard_visible: bool,
+    chaperone: ChaperoneState,
+    compositor: CompositorState,
+    input: InputState,
+}
+
+impl State {
+    fn device(&self, index: sys::TrackedDeviceIndex_t) -> Option<&MockDevice> {
+        self.devices.get(index as usize).and_then(|x| x.as_ref())
+    }
+
+    fn device_mut(&mut self, index: sys::TrackedDeviceIndex_t) -> Option<&mut MockDevice> {
+        self.devices
+            .get_mut(index as usize)
+            .and_then(|x| x.as_mut())
+    }
+
+    /// The handle for an input path, allocating one the first time it is asked for.
+    fn input_handle(&mut self, path: &str) -> u64 {
+        let next = self.input.handles.len() as u64 + 1;
+        *self
+            .input
+            .handles
+            .entry(path.to_lowercase())
+            .or_insert(next)
+    }
+
+    fn pose(&self, index: usize) -> TrackedDevicePose {
+        match self.devices[index] {
+            Some(ref device) => device.pose,
+            None => disconnected_pose(),
+        }
+    }
+}
+
+/// Handle to the scriptable state behind a mock [`Context`].
+///
+/// Cloning yields another handle to the same state.
+#[derive(Clone)]
+pub struct MockRuntime(Rc<RefCell<State>>);
+
+impl Default for MockRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockRuntime {
+    /// An empty runtime: no devices, no events, no settings and no overlays.
+    pub fn new() -> Self {
+        MockRuntime(Rc::new(RefCell::new(State {
+            render_target_size: (1512, 1680),
+            devices: vec![None; MAX_TRACKED_DEVICE_COUNT],
+            events: VecDeque::new(),
+            haptic_pulses: Vec::new(),
+            quit_acknowledged: false,
+            raw_zero_pose_to_standing: IDENTITY,
+            seated_zero_pose_to_standing: IDENTITY,
+            settings: HashMap::new(),
+            overlays: BTreeMap::new(),
+            overlay_events: HashMap::new(),
+            next_overlay: 1,
+            dashboard_visible: false,
+            chaperone: ChaperoneState {
+                calibration_state: sys::ChaperoneCalibrationState_OK,
+                play_area_size: None,
+                play_area_rect: None,
+                bounds_visible: false,
+                bounds_forced: false,
+                collision_bounds: Vec::new(),
+                seated_zero_pose: IDENTITY,
+                standing_zero_pose: IDENTITY,
+                working: ChaperoneConfig {
+                    play_area_size: None,
+                    collision_bounds: Vec::new(),
+                    seated_zero_pose: IDENTITY,
+                    standing_zero_pose: IDENTITY,
+                },
+                preview_visible: false,
+                scene_color: None,
+                bounds_color: ColorTint::default(),
+                exports: Vec::new(),
+            },
+            compositor: CompositorState {
+                tracking_space: sys::ETrackingUniverseOrigin_TrackingUniverseStanding,
+                frame_count: 0,
+                submitted: [0; 2],
+                post_present_handoffs: 0,
+                explicit_timing: false,
+                calls: Vec::new(),
+                submit_error: None,
+                timings: VecDeque::new(),
+                frame_time_remaining: 0.0,
+                cumulative_stats: Default::default(),
+                last_frame_renderer: 0,
+            },
+            input: InputState {
+                handles: HashMap::new(),
+                skeletons: HashMap::new(),
+                actions: HashMap::new(),
+                action_updates: 0,
+                bindings: HashMap::new(),
+                dominant_hand: sys::ETrackedControllerRole_TrackedControllerRole_RightHand,
+                legacy_input: false,
+                binding_ui_requests: Vec::new(),
+                vibrations: Vec::new(),
+                vibration_error: None,
+                manifest: None,
+            },
+        })))
+    }
+
+    pub fn set_recommended_render_target_size(&self, width: u32, height: u32) {
+        self.0.borrow_mut().render_target_size = (width, height);
+    }
+
+    /// Adds `device` at the first free index and returns that index.
+    ///
+    /// # Panics
+    ///
+    /// When all `MAX_TRACKED_DEVICE_COUNT` slots are occupied.
+    pub fn add_device(&self, device: MockDevice) -> TrackedDeviceIndex {
+        let mut state = self.0.borrow_mut();
+        let index = state
+            .devices
+            .iter()
+            .position(|x| x.is_none())
+            .expect("no free tracked device slots");
+        state.devices[index] = Some(device);
+        TrackedDeviceIndex(index as u32)
+    }
+
+    /// Places `device` at `index`, replacing whatever was there.
+    pub fn set_device(&self, index: TrackedDeviceIndex, device: MockDevice) {
+        self.0.borrow_mut().devices[index.0 as usize] = Some(device);
+    }
+
+    pub fn remove_device(&self, index: TrackedDeviceIndex) -> Option<MockDevice> {
+        self.0.borrow_mut().devices[index.0 as usize].take()
+    }
+
+    /// Runs `f` on the device at `index`. Returns `None` if there is no such device.
+    pub fn update_device<R>(
+        &self,
+        index: TrackedDeviceIndex,
+        f: impl FnOnce(&mut MockDevice) -> R,
+    ) -> Option<R> {
+        self.0.borrow_mut().device_mut(index.0).map(f)
+    }
+
+    pub fn set_pose(&self, index: TrackedDeviceIndex, pose: TrackedDevicePose) {
+        self.update_device(index, |x| x.pose = pose);
+    }
+
+    pub fn set_property<T: Into<PropertyValue>>(
+        &self,
+        index: TrackedDeviceIndex,
+        key: Property<T>,
+        value: impl Into<T>,
+    ) {
+        let value = value.into().into();
+        self.update_device(index, |x| x.properties.insert(key.raw(), value));
+    }
+
+    pub fn set_controller_state(&self, index: TrackedDeviceIndex, state: ControllerState) {
+        self.update_device(index, |x| x.controller_state = Some(state));
+    }
+
+    /// Queues an event to be returned by `PollNextEvent`.
+    pub fn push_event(
+        &self,
+        index: TrackedDeviceIndex,
+        event_type: sys::EVREventType,
+        data: sys::VREvent_Data_t,
+    ) {
+        self.push_raw_event(sys::VREvent_t {
+            eventType: event_type,
+            trackedDeviceIndex: index.0,
+            eventAgeSeconds: 0.0,
+            data,
+        });
+    }
+
+    pub fn push_raw_event(&self, event: sys::VREvent_t) {
+        self.0.borrow_mut().events.push_back(event);
+    }
+
+    /// Number of queued events that have not been polled yet.
+    pub fn pending_events(&self) -> usize {
+        self.0.borrow().events.len()
+    }
+
+    /// Haptic pulses triggered through `System::trigger_haptic_pulse`, as `(device, axis,
+    /// duration_us)`.
+    pub fn haptic_pulses(&self) -> Vec<(TrackedDeviceIndex, u32, u16)> {
+        self.0.borrow().haptic_pulses.clone()
+    }
+
+    pub fn quit_acknowledged(&self) -> bool {
+        self.0.borrow().quit_acknowledged
+    }
+
+    pub fn set_raw_zero_pose_to_standing(&self, transform: [[f32; 4]; 3]) {
+        self.0.borrow_mut().raw_zero_pose_to_standing = transform;
+    }
+
+    pub fn set_seated_zero_pose_to_standing(&self, transform: [[f32; 4]; 3]) {
+        self.0.borrow_mut().seated_zero_pose_to_standing = transform;
+    }
+
+    pub fn set_setting(&self, section: &str, key: &str, value: SettingValue) {
+        self.0
+            .borrow_mut()
+            .settings
+            .insert((section.to_owned(), key.to_owned()), value);
+    }
+
+    pub fn setting(&self, section: &str, key: &str) -> Option<SettingValue> {
+        self.0
+            .borrow()
+            .settings
+            .get(&(section.to_owned(), key.to_owned()))
+            .cloned()
+    }
+
+    pub fn overlay(&self, handle: crate::overlay::OverlayHandle) -> Option<MockOverlay> {
+        self.0.borrow().overlays.get(&handle.0).cloned()
+    }
+
+    /// Queues an event for `Overlay::poll_next_event` on `handle`.
+    pub fn push_overlay_event(
+        &self,
+        handle: crate::overlay::OverlayHandle,
+        index: TrackedDeviceIndex,
+        event_type: sys::EVREventType,
+        data: sys::VREvent_Data_t,
+    ) {
+        self.0
+            .borrow_mut()
+            .overlay_events
+            .entry(handle.0)
+            .or_default()
+            .push_back(sys::VREvent_t {
+                eventType: event_type,
+                trackedDeviceIndex: index.0,
+                eventAgeSeconds: 0.0,
+                data,
+            });
+    }
+
+    pub fn overlay_count(&self) -> usize {
+        self.0.borrow().overlays.len()
+    }
+
+    pub fn set_dashboard_visible(&self, visible: bool) {
+        self.0.borrow_mut().dashboard_visible = visible;
+    }
+
+    pub fn set_calibration_state(&self, state: sys::ChaperoneCalibrationState) {
+        self.0.borrow_mut().chaperone.calibration_state = state;
+    }
+
+    /// Sets the play area size and corners reported by the chaperone. `None` reports that no play
+    /// area is set up.
+    pub fn set_play_area(&self, size: Option<(f32, f32)>, rect: Option<[[f32; 3]; 4]>) {
+        let mut state = self.0.borrow_mut();
+        state.chaperone.play_area_size = size;
+        state.chaperone.play_area_rect = rect;
+    }
+
+    pub fn set_bounds_visible(&self, visible: bool) {
+        self.0.borrow_mut().chaperone.bounds_visible = visible;
+    }
+
+    /// Sets the live collision bounds.
+    pub fn set_collision_bounds(&self, quads: Vec<[[f32; 3]; 4]>) {
+        self.0.borrow_mut().chaperone.collision_bounds = quads;
+    }
+
+    /// Color last passed to `Chaperone::set_scene_color`.
+    pub fn scene_color(&self) -> Option<ColorTint> {
+        self.0.borrow().chaperone.scene_color
+    }
+
+    /// Color returned for the bounds and camera by `Chaperone::get_bounds_color`.
+    pub fn bounds_color(&self) -> ColorTint {
+        self.0.borrow().chaperone.bounds_color
+    }
+
+    pub fn set_bounds_color(&self, color: ColorTint) {
+        self.0.borrow_mut().chaperone.bounds_color = color;
+    }
+
+    /// Whether the chaperone setup working copy is being previewed.
+    pub fn working_set_preview_visible(&self) -> bool {
+        self.0.borrow().chaperone.preview_visible
+    }
+
+    /// Whether the application has forced the bounds to be shown.
+    pub fn bounds_forced(&self) -> bool {
+        self.0.borrow().chaperone.bounds_forced
+    }
+
+    /// Path last passed to `SetActionManifestPath`.
+    pub fn action_manifest(&self) -> Option<PathBuf> {
+        self.0.borrow().input.manifest.clone()
+    }
+
+    /// Sets the value of the action at `action`, e.g. `/actions/main/in/jump`. Like real input, the
+    /// new value is only seen after the next `Input::update_actions`.
+    pub fn set_action_value(&self, action: &str, value: MockActionValue) {
+        let mut state = self.0.borrow_mut();
+        let handle = state.input_handle(action);
+        state
+            .input
+            .actions
+            .entry(handle)
+            .or_insert_with(|| MockAction {
+                origin: VRInputValueHandle(0),
+                pending: value,
+                current: value.neutral(),
+                previous: value.neutral(),
+            })
+            .pending = value;
+    }
+
+    /// Makes `action` report itself as inactive again, as if it were unbound.
+    pub fn clear_action_value(&self, action: &str) {
+        let mut state = self.0.borrow_mut();
+        let handle = state.input_handle(action);
+        state.input.actions.remove(&handle);
+    }
+
+    /// Sets the input source reported as driving `action`.
+    pub fn set_action_origin(&self, action: &str, origin: VRInputValueHandle) {
+        let mut state = self.0.borrow_mut();
+        let handle = state.input_handle(action);
+        if let Some(x) = state.input.actions.get_mut(&handle) {
+            x.origin = origin;
+        }
+    }
+
+    /// Number of times `UpdateActionState` has been called.
+    pub fn action_update_count(&self) -> u32 {
+        self.0.borrow().input.action_updates
+    }
+
+    /// Sets the origins `action` is bound to and the binding reported for each.
+    pub fn set_action_bindings(
+        &self,
+        action: &str,
+        bindings: Vec<(VRInputValueHandle, BindingInfo)>,
+    ) {
+        let mut state = self.0.borrow_mut();
+        let handle = state.input_handle(action);
+        state.input.bindings.insert(handle, bindings);
+    }
+
+    pub fn dominant_hand(&self) -> crate::input::TrackedControllerRole {
+        self.0.borrow().input.dominant_hand.into()
+    }
+
+    pub fn set_using_legacy_input(&self, legacy: bool) {
+        self.0.borrow_mut().input.legacy_input = legacy;
+    }
+
+    /// Calls made to `OpenBindingUI`, oldest first.
+    pub fn binding_ui_requests(&self) -> Vec<BindingUiRequest> {
+        self.0.borrow().input.binding_ui_requests.clone()
+    }
+
+    /// Calls made to `TriggerHapticVibrationAction`, oldest first.
+    pub fn haptic_vibrations(&self) -> Vec<HapticVibration> {
+        self.0.borrow().input.vibrations.clone()
+    }
+
+    /// Makes the next `TriggerHapticVibrationAction` fail with `error`, without recording the
+    /// vibration.
+    pub fn fail_next_haptic_vibration(&self, error: sys::EVRInputError) {
+        self.0.borrow_mut().input.vibration_error = Some(error);
+    }
+
+    /// Binds `skeleton` to the skeletal action at `action`, e.g. `/actions/main/in/hand_left`.
+    pub fn set_skeleton(&self, action: &str, skeleton: MockSkeleton) {
+        let mut state = self.0.borrow_mut();
+        let handle = state.input_handle(action);
+        state.input.skeletons.insert(handle, skeleton);
+    }
+
+    /// Runs `f` on the skeleton bound to `action`. Returns `None` if there is none.
+    pub fn update_skeleton<R>(
+        &self,
+        action: &str,
+        f: impl FnOnce(&mut MockSkeleton) -> R,
+    ) -> Option<R> {
+        let mut state = self.0.borrow_mut();
+        let handle = state.input_handle(action);
+        state.input.skeletons.get_mut(&handle).map(f)
+    }
+
+    /// Number of times `WaitGetPoses` has been called.
+    pub fn frame_count(&self) -> u32 {
+        self.0.borrow().compositor.frame_count
+    }
+
+    /// Number of frames submitted for `eye`.
+    pub fn submit_count(&self, eye: Eye) -> u32 {
+        self.0.borrow().compositor.submitted[eye as usize]
+    }
+
+    pub fn post_present_handoff_count(&self) -> u32 {
+        self.0.borrow().compositor.post_present_handoffs
+    }
+
+    pub fn tracking_space(&self) -> sys::ETrackingUniverseOrigin {
+        self.0.borrow().compositor.tracking_space
+    }
+
+    pub fn explicit_timing(&self) -> bool {
+        self.0.borrow().compositor.explicit_timing
+    }
+
+    /// Frame calls made to the compositor, in order.
+    pub fn compositor_calls(&self) -> Vec<MockCompositorCall> {
+        self.0.borrow().compositor.calls.clone()
+    }
+
+    pub fn clear_compositor_calls(&self) {
+        self.0.borrow_mut().compositor.calls.clear();
+    }
+
+    /// Makes the next `Submit` fail with `error`.
+    pub fn fail_next_submit(&self, error: sys::EVRCompositorError) {
+        self.0.borrow_mut().compositor.submit_error = Some(error);
+    }
+
+    /// Adds the timing of a new latest frame to the history. Like the runtime, the mock keeps the
+    /// last 128 frames.
+    pub fn push_frame_timing(&self, timing: sys::Compositor_FrameTiming) {
+        let timings = &mut self.0.borrow_mut().compositor.timings;
+        if timings.len() == FRAME_TIMING_HISTORY {
+            timings.pop_front();
+        }
+        timings.push_back(timing);
+    }
+
+    /// Sets the value returned by `GetFrameTimeRemaining`, in seconds.
+    pub fn set_frame_time_remaining(&self, seconds: f32) {
+        self.0.borrow_mut().compositor.frame_time_remaining = seconds;
+    }
+
+    pub fn set_cumulative_stats(&self, stats: sys::Compositor_CumulativeStats) {
+        self.0.borrow_mut().compositor.cumulative_stats = stats;
+    }
+
+    /// Sets the process returned by `GetLastFrameRenderer`, 0 for the compositor.
+    pub fn set_last_frame_renderer(&self, pid: u32) {
+        self.0.borrow_mut().compositor.last_frame_renderer = pid;
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<MockRuntime>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` on the state of the runtime active on this thread.
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    let runtime = CURRENT
+        .with(|x| x.borrow().clone())
+        .expect("no mock OpenVR runtime is active on this thread");
+    let mut state = runtime.0.borrow_mut();
+    f(&mut state)
+}
+
+pub(crate) fn activate(runtime: &MockRuntime) {
+    CURRENT.with(|x| {
+        let mut current = x.borrow_mut();
+        if current.is_some() {
+            panic!("a mock OpenVR runtime is already active on this thread!");
+        }
+        *current = Some(runtime.clone());
+    });
+}
+
+pub(crate) fn deactivate() {
+    CURRENT.with(|x| x.borrow_mut().take());
+}
+
+lazy_static! {
+    static ref APPLICATIONS: sys::VR_IVRApplications_FnTable = Default::default();
+    static ref RENDER_MODELS: sys::VR_IVRRenderModels_FnTable = Default::default();
+}
+
+/// Mock counterpart of `VR_GetGenericInterface`.
+pub(crate) fn load<T>(suffix: &[u8]) -> Result<*const T, InitError> {
+    let table: *const () = if suffix == sys::IVRSystem_Version.as_ref() {
+        &*system::TABLE as *const _ as *const ()
+    } else if suffix == sys::IVRCompositor_Version.as_ref() {
+        &*compositor::TABLE as *const _ as *const ()
+    } else if suffix == sys::IVRSettings_Version.as_ref() {
+        &*settings::TABLE as *const _ as *const ()
+    } else if suffix == sys::IVROverlay_Version.as_ref() {
+        &*overlay::TABLE as *const _ as *const ()
+    } else if suffix == sys::IVRChaperone_Version.as_ref() {
+        &*chaperone::TABLE as *const _ as *const ()
+    } else if suffix == sys::IVRChaperoneSetup_Version.as_ref() {
+        &*chaperone::SETUP_TABLE as *const _ as *const ()
+    } else if suffix == sys::IVRApplications_Version.as_ref() {
+        &*APPLICATIONS as *const _ as *const ()
+    } else if suffix == sys::IVRRenderModels_Version.as_ref() {
+        &*RENDER_MODELS as *const _ as *const ()
+    } else if suffix == sys::IVRInput_Version.as_ref() {
+        &*input::TABLE as *const _ as *const ()
+    } else {
+        return Err(InitError::Init_InterfaceNotFound);
+    };
+    Ok(table as *const T)
+}
+
+impl Context {
+    /// Creates a context backed by `runtime` instead of the real OpenVR runtime.
+    ///
+    /// # Panics
+    ///
+    /// When a mock context is already active on this thread.
+    pub fn mock(runtime: &MockRuntime) -> Context {
+        activate(runtime);
+        Context {
+            _private: (),
+            mock: true,
+        }
+    }
+}
+
+const IDENTITY: [[f32; 4]; 3] = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+];
+
+/// A valid, connected pose at the origin.
+pub fn identity_pose() -> TrackedDevicePose {
+    TrackedDevicePose(sys::TrackedDevicePose_t {
+        mDeviceToAbsoluteTracking: sys::HmdMatrix34_t { m: IDENTITY },
+        eTrackingResult: sys::ETrackingResult_TrackingResult_Running_OK,
+        bPoseIsValid: true,
+        bDeviceIsConnected: true,
+        ..Default::default()
+    })
+}
+
+pub(crate) fn disconnected_pose() -> TrackedDevicePose {
+    TrackedDevicePose(sys::TrackedDevicePose_t {
+        eTrackingResult: sys::ETrackingResult_TrackingResult_Uninitialized,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property;
+    use crate::system::event::Event;
+    use crate::tracked_device_index;
+    use crate::TrackingUniverseOrigin;
+    use std::ffi::CString;
+
+    #[test]
+    fn devices_and_properties() {
+        let runtime = MockRuntime::new();
+        let hmd = runtime.add_device(
+            MockDevice::new(TrackedDeviceClass::HMD)
+                .with_property(property::SerialNumber_String, "LHR-1")
+                .with_property(property::DisplayFrequency_Float, 90.0),
+        );
+        let right = runtime.add_device(
+            MockDevice::new(TrackedDeviceClass::Controller)
+                .with_role(TrackedControllerRole::RightHand),
+        );
+        assert_eq!(hmd, tracked_device_index::HMD);
+
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        assert_eq!(system.recommended_render_target_size(), (1512, 1680));
+        assert_eq!(
+            system.tracked_device_class(right),
+            TrackedDeviceClass::Controller
+        );
+        assert_eq!(
+            system.tracked_device_class(TrackedDeviceIndex(5)),
+            TrackedDeviceClass::Invalid
+        );
+        assert!(!system.is_tracked_device_connected(TrackedDeviceIndex(5)));
+        assert_eq!(
+            system.tracked_device_index_for_controller_role(TrackedControllerRole::RightHand),
+            Some(right)
+        );
+        assert_eq!(
+            system.tracked_device_index_for_controller_role(TrackedControllerRole::LeftHand),
+            None
+        );
+
+        assert_eq!(
+            system.property(hmd, property::SerialNumber_String),
+            Ok("LHR-1".to_owned())
+        );
+        assert_eq!(
+            system.property(hmd, property::DisplayFrequency_Float),
+            Ok(90.0)
+        );
+        assert_eq!(
+            system.property(
+                hmd,
+                Property::<f32>::new(property::SerialNumber_String.raw())
+            ),
+            Err(crate::system::tracked_property_error::WRONG_DATA_TYPE)
+        );
+        assert_eq!(
+            system.property(hmd, property::WillDriftInYaw_Bool),
+            Err(crate::system::tracked_property_error::UNKNOWN_PROPERTY)
+        );
+        assert_eq!(
+            system.property(TrackedDeviceIndex(7), property::ModelNumber_String),
+            Err(crate::system::tracked_property_error::INVALID_DEVICE)
+        );
+    }
+
+    #[test]
+    fn array_properties() {
+        let runtime = MockRuntime::new();
+        let hmd = runtime.add_device(
+            MockDevice::new(TrackedDeviceClass::HMD)
+                .with_property(
+                    property::DisplayAvailableFrameRates_Float_Array,
+                    vec![90.0, 120.0, 144.0],
+                )
+                .with_property(property::DisplayColorMultLeft_Vector3, [1.0, 0.5, 0.25])
+                .with_property(property::CameraWhiteBalance_Vector4_Array, Vec::new()),
+        );
+
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        assert_eq!(
+            system.property(hmd, property::DisplayAvailableFrameRates_Float_Array),
+            Ok(vec![90.0, 120.0, 144.0])
+        );
+        assert_eq!(
+            system.property(hmd, property::DisplayColorMultLeft_Vector3),
+            Ok([1.0, 0.5, 0.25])
+        );
+        assert_eq!(
+            system.property(hmd, property::CameraWhiteBalance_Vector4_Array),
+            Ok(Vec::new())
+        );
+        // The tag has to match the element type that was stored.
+        assert_eq!(
+            system.property(
+                hmd,
+                Property::<Vec<i32>>::new(property::DisplayAvailableFrameRates_Float_Array.raw())
+            ),
+            Err(crate::system::tracked_property_error::WRONG_DATA_TYPE)
+        );
+        assert_eq!(
+            system.property(hmd, property::CameraToHeadTransforms_Matrix34_Array),
+            Err(crate::system::tracked_property_error::UNKNOWN_PROPERTY)
+        );
+    }
+
+    #[test]
+    fn events_are_polled_in_order() {
+        let runtime = MockRuntime::new();
+        let hmd = runtime.add_device(MockDevice::new(TrackedDeviceClass::HMD));
+        runtime.push_event(
+            hmd,
+            sys::EVREventType_VREvent_TrackedDeviceActivated,
+            Default::default(),
+        );
+        runtime.push_event(hmd, sys::EVREventType_VREvent_Quit, Default::default());
+
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        assert!(matches!(
+            system.poll_next_event().unwrap().event,
+            Event::TrackedDeviceActivated
+        ));
+        let (event, pose) = system
+            .poll_next_event_with_pose(TrackingUniverseOrigin::Standing)
+            .unwrap();
+        assert!(matches!(event.event, Event::Quit(_)));
+        assert!(pose.pose_is_valid());
+        assert!(system.poll_next_event().is_none());
+        assert_eq!(runtime.pending_events(), 0);
+    }
+
+    #[test]
+    fn compositor_records_frames() {
+        let runtime = MockRuntime::new();
+        runtime.add_device(MockDevice::new(TrackedDeviceClass::HMD));
+        let context = Context::mock(&runtime);
+        let compositor = context.compositor().unwrap();
+        let poses = compositor.wait_get_poses().unwrap();
+        assert!(poses.render[0].pose_is_valid());
+        assert!(!poses.render[1].device_is_connected());
+        compositor.post_present_handoff();
+        assert_eq!(runtime.frame_count(), 1);
+        assert_eq!(runtime.post_present_handoff_count(), 1);
+    }
+
+    #[test]
+    fn settings_round_trip() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let mut settings = context.settings().unwrap();
+        let section = CString::new("steamvr").unwrap();
+        let key = CString::new("supersampleScale").unwrap();
+        assert!(settings.get_float(&section, &key).is_err());
+        settings.set_float(&section, &key, 1.5).unwrap();
+        assert_eq!(settings.get_float(&section, &key), Ok(1.5));
+        assert_eq!(
+            runtime.setting("steamvr", "supersampleScale"),
+            Some(SettingValue::Float(1.5))
+        );
+        settings.remove_section(&section).unwrap();
+        assert_eq!(runtime.setting("steamvr", "supersampleScale"), None);
+    }
+
+    #[test]
+    fn overlays() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let mut overlay = context.overlay().unwrap();
+        let handle = overlay.create_overlay("key", "name").unwrap();
+        overlay.set_visibility(handle, true).unwrap();
+        overlay.set_width(handle, 0.5).unwrap();
+        assert!(overlay.is_visible(handle));
+        assert_eq!(overlay.width(handle), Ok(0.5));
+        assert!(overlay.width(crate::overlay::OverlayHandle(1234)).is_err());
+        let snapshot = runtime.overlay(handle).unwrap();
+        assert!(snapshot.visible);
+        assert_eq!(snapshot.width, 0.5);
+    }
+
+    #[test]
+    fn shutdown_releases_runtime() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        drop(context);
+        let _context = Context::mock(&runtime);
+    }
+}
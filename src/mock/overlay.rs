@@ -0,0 +1,302 @@
+use std::os::raw::c_char;
+use std::{mem, ptr};
+
+use super::{with_state, MockOverlay, OverlayTransform};
+use crate::{sys, TrackedDeviceIndex};
+
+lazy_static! {
+    pub(super) static ref TABLE: sys::VR_IVROverlay_FnTable = sys::VR_IVROverlay_FnTable {
+        CreateOverlay: Some(create_overlay),
+        DestroyOverlay: Some(destroy_overlay),
+        ShowOverlay: Some(show_overlay),
+        HideOverlay: Some(hide_overlay),
+        IsOverlayVisible: Some(is_overlay_visible),
+        SetOverlayColor: Some(set_overlay_color),
+        GetOverlayColor: Some(get_overlay_color),
+        SetOverlayAlpha: Some(set_overlay_alpha),
+        GetOverlayAlpha: Some(get_overlay_alpha),
+        SetOverlayTexelAspect: Some(set_overlay_texel_aspect),
+        GetOverlayTexelAspect: Some(get_overlay_texel_aspect),
+        SetOverlaySortOrder: Some(set_overlay_sort_order),
+        GetOverlaySortOrder: Some(get_overlay_sort_order),
+        SetOverlayWidthInMeters: Some(set_overlay_width_in_meters),
+        GetOverlayWidthInMeters: Some(get_overlay_width_in_meters),
+        SetOverlayCurvature: Some(set_overlay_curvature),
+        GetOverlayCurvature: Some(get_overlay_curvature),
+        SetOverlayTextureBounds: Some(set_overlay_texture_bounds),
+        GetOverlayTextureBounds: Some(get_overlay_texture_bounds),
+        SetOverlayTransformAbsolute: Some(set_overlay_transform_absolute),
+        GetOverlayTransformAbsolute: Some(get_overlay_transform_absolute),
+        SetOverlayTransformTrackedDeviceRelative: Some(
+            set_overlay_transform_tracked_device_relative
+        ),
+        GetOverlayTransformTrackedDeviceRelative: Some(
+            get_overlay_transform_tracked_device_relative
+        ),
+        IsDashboardVisible: Some(is_dashboard_visible),
+        PollNextOverlayEvent: Some(poll_next_overlay_event),
+        ..Default::default()
+    };
+}
+
+/// Runs `f` on the overlay behind `handle`, or reports `UnknownOverlay`.
+fn with_overlay(
+    handle: sys::VROverlayHandle_t,
+    f: impl FnOnce(&mut MockOverlay),
+) -> sys::EVROverlayError {
+    with_state(|s| match s.overlays.get_mut(&handle) {
+        Some(overlay) => {
+            f(overlay);
+            sys::EVROverlayError_VROverlayError_None
+        }
+        None => sys::EVROverlayError_VROverlayError_UnknownOverlay,
+    })
+}
+
+// The key and name are not read: `Overlay::create_overlay` does not nul-terminate them.
+unsafe extern "C" fn create_overlay(
+    _key: *mut c_char,
+    _name: *mut c_char,
+    handle: *mut sys::VROverlayHandle_t,
+) -> sys::EVROverlayError {
+    *handle = with_state(|s| {
+        let handle = s.next_overlay;
+        s.next_overlay += 1;
+        s.overlays.insert(handle, MockOverlay::default());
+        handle
+    });
+    sys::EVROverlayError_VROverlayError_None
+}
+
+unsafe extern "C" fn destroy_overlay(handle: sys::VROverlayHandle_t) -> sys::EVROverlayError {
+    match with_state(|s| {
+        s.overlay_events.remove(&handle);
+        s.overlays.remove(&handle)
+    }) {
+        Some(_) => sys::EVROverlayError_VROverlayError_None,
+        None => sys::EVROverlayError_VROverlayError_UnknownOverlay,
+    }
+}
+
+unsafe extern "C" fn show_overlay(handle: sys::VROverlayHandle_t) -> sys::EVROverlayError {
+    with_overlay(handle, |x| x.visible = true)
+}
+
+unsafe extern "C" fn hide_overlay(handle: sys::VROverlayHandle_t) -> sys::EVROverlayError {
+    with_overlay(handle, |x| x.visible = false)
+}
+
+unsafe extern "C" fn is_overlay_visible(handle: sys::VROverlayHandle_t) -> bool {
+    with_state(|s| s.overlays.get(&handle).is_some_and(|x| x.visible))
+}
+
+unsafe extern "C" fn set_overlay_color(
+    handle: sys::VROverlayHandle_t,
+    r: f32,
+    g: f32,
+    b: f32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| {
+        x.tint.r = r;
+        x.tint.g = g;
+        x.tint.b = b;
+    })
+}
+
+unsafe extern "C" fn get_overlay_color(
+    handle: sys::VROverlayHandle_t,
+    r: *mut f32,
+    g: *mut f32,
+    b: *mut f32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| {
+        *r = x.tint.r;
+        *g = x.tint.g;
+        *b = x.tint.b;
+    })
+}
+
+unsafe extern "C" fn set_overlay_alpha(
+    handle: sys::VROverlayHandle_t,
+    alpha: f32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| x.tint.a = alpha)
+}
+
+unsafe extern "C" fn get_overlay_alpha(
+    handle: sys::VROverlayHandle_t,
+    alpha: *mut f32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| *alpha = x.tint.a)
+}
+
+unsafe extern "C" fn set_overlay_texel_aspect(
+    handle: sys::VROverlayHandle_t,
+    aspect: f32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| x.texel_aspect = aspect)
+}
+
+unsafe extern "C" fn get_overlay_texel_aspect(
+    handle: sys::VROverlayHandle_t,
+    aspect: *mut f32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| *aspect = x.texel_aspect)
+}
+
+unsafe extern "C" fn set_overlay_sort_order(
+    handle: sys::VROverlayHandle_t,
+    order: u32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| x.sort_order = order)
+}
+
+unsafe extern "C" fn get_overlay_sort_order(
+    handle: sys::VROverlayHandle_t,
+    order: *mut u32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| *order = x.sort_order)
+}
+
+unsafe extern "C" fn set_overlay_width_in_meters(
+    handle: sys::VROverlayHandle_t,
+    width: f32,
+) -> sys::EVROverlayError {
+    if width <= 0.0 {
+        return sys::EVROverlayError_VROverlayError_InvalidParameter;
+    }
+    with_overlay(handle, |x| x.width = width)
+}
+
+unsafe extern "C" fn get_overlay_width_in_meters(
+    handle: sys::VROverlayHandle_t,
+    width: *mut f32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| *width = x.width)
+}
+
+unsafe extern "C" fn set_overlay_curvature(
+    handle: sys::VROverlayHandle_t,
+    curvature: f32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| x.curvature = curvature)
+}
+
+unsafe extern "C" fn get_overlay_curvature(
+    handle: sys::VROverlayHandle_t,
+    curvature: *mut f32,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| *curvature = x.curvature)
+}
+
+unsafe extern "C" fn set_overlay_texture_bounds(
+    handle: sys::VROverlayHandle_t,
+    bounds: *mut sys::VRTextureBounds_t,
+) -> sys::EVROverlayError {
+    let b = *bounds;
+    with_overlay(handle, |x| {
+        x.texture_bounds = [b.uMin, b.vMin, b.uMax, b.vMax]
+    })
+}
+
+unsafe extern "C" fn get_overlay_texture_bounds(
+    handle: sys::VROverlayHandle_t,
+    bounds: *mut sys::VRTextureBounds_t,
+) -> sys::EVROverlayError {
+    with_overlay(handle, |x| {
+        let [u_min, v_min, u_max, v_max] = x.texture_bounds;
+        *bounds = sys::VRTextureBounds_t {
+            uMin: u_min,
+            vMin: v_min,
+            uMax: u_max,
+            vMax: v_max,
+        };
+    })
+}
+
+unsafe extern "C" fn set_overlay_transform_absolute(
+    handle: sys::VROverlayHandle_t,
+    origin: sys::ETrackingUniverseOrigin,
+    transform: *mut sys::HmdMatrix34_t,
+) -> sys::EVROverlayError {
+    let m = (*transform).m;
+    with_overlay(handle, |x| {
+        x.transform = OverlayTransform::Absolute(origin, m)
+    })
+}
+
+unsafe extern "C" fn get_overlay_transform_absolute(
+    handle: sys::VROverlayHandle_t,
+    origin: *mut sys::ETrackingUniverseOrigin,
+    transform: *mut sys::HmdMatrix34_t,
+) -> sys::EVROverlayError {
+    let mut result = sys::EVROverlayError_VROverlayError_None;
+    let e = with_overlay(handle, |x| match x.transform {
+        OverlayTransform::Absolute(o, m) => {
+            *origin = o;
+            (*transform).m = m;
+        }
+        _ => result = sys::EVROverlayError_VROverlayError_InvalidParameter,
+    });
+    if e == sys::EVROverlayError_VROverlayError_None {
+        result
+    } else {
+        e
+    }
+}
+
+unsafe extern "C" fn set_overlay_transform_tracked_device_relative(
+    handle: sys::VROverlayHandle_t,
+    device: sys::TrackedDeviceIndex_t,
+    transform: *mut sys::HmdMatrix34_t,
+) -> sys::EVROverlayError {
+    let m = (*transform).m;
+    with_overlay(handle, |x| {
+        x.transform = OverlayTransform::TrackedDeviceRelative(TrackedDeviceIndex(device), m)
+    })
+}
+
+unsafe extern "C" fn get_overlay_transform_tracked_device_relative(
+    handle: sys::VROverlayHandle_t,
+    device: *mut sys::TrackedDeviceIndex_t,
+    transform: *mut sys::HmdMatrix34_t,
+) -> sys::EVROverlayError {
+    let mut result = sys::EVROverlayError_VROverlayError_None;
+    let e = with_overlay(handle, |x| match x.transform {
+        OverlayTransform::TrackedDeviceRelative(d, m) => {
+            *device = d.0;
+            (*transform).m = m;
+        }
+        _ => result = sys::EVROverlayError_VROverlayError_InvalidParameter,
+    });
+    if e == sys::EVROverlayError_VROverlayError_None {
+        result
+    } else {
+        e
+    }
+}
+
+unsafe extern "C" fn is_dashboard_visible() -> bool {
+    with_state(|s| s.dashboard_visible)
+}
+
+unsafe extern "C" fn poll_next_overlay_event(
+    handle: sys::VROverlayHandle_t,
+    event: *mut sys::VREvent_t,
+    size: u32,
+) -> bool {
+    if (size as usize) < mem::size_of::<sys::VREvent_t>() {
+        return false;
+    }
+    match with_state(|s| {
+        s.overlay_events
+            .get_mut(&handle)
+            .and_then(|x| x.pop_front())
+    }) {
+        Some(next) => {
+            ptr::write_unaligned(event, next);
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,175 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr;
+
+use super::{with_state, SettingValue};
+use crate::sys;
+
+lazy_static! {
+    pub(super) static ref TABLE: sys::VR_IVRSettings_FnTable = sys::VR_IVRSettings_FnTable {
+        SetBool: Some(set_bool),
+        SetInt32: Some(set_int32),
+        SetFloat: Some(set_float),
+        SetString: Some(set_string),
+        GetBool: Some(get_bool),
+        GetInt32: Some(get_int32),
+        GetFloat: Some(get_float),
+        GetString: Some(get_string),
+        RemoveSection: Some(remove_section),
+        RemoveKeyInSection: Some(remove_key_in_section),
+        ..Default::default()
+    };
+}
+
+unsafe fn key(section: *mut c_char, key: *mut c_char) -> (String, String) {
+    (
+        CStr::from_ptr(section).to_string_lossy().into_owned(),
+        CStr::from_ptr(key).to_string_lossy().into_owned(),
+    )
+}
+
+unsafe fn report(error: *mut sys::EVRSettingsError, value: sys::EVRSettingsError) {
+    if !error.is_null() {
+        *error = value;
+    }
+}
+
+unsafe fn set(
+    section: *mut c_char,
+    settings_key: *mut c_char,
+    value: SettingValue,
+    error: *mut sys::EVRSettingsError,
+) {
+    let key = key(section, settings_key);
+    with_state(|s| s.settings.insert(key, value));
+    report(error, sys::EVRSettingsError_VRSettingsError_None);
+}
+
+/// Reads a setting. Missing keys and keys holding a different type both report
+/// `UnsetSettingHasNoDefault`.
+unsafe fn get<T: Default>(
+    section: *mut c_char,
+    settings_key: *mut c_char,
+    error: *mut sys::EVRSettingsError,
+    f: impl FnOnce(&SettingValue) -> Option<T>,
+) -> T {
+    let key = key(section, settings_key);
+    match with_state(|s| s.settings.get(&key).and_then(f)) {
+        Some(x) => {
+            report(error, sys::EVRSettingsError_VRSettingsError_None);
+            x
+        }
+        None => {
+            report(
+                error,
+                sys::EVRSettingsError_VRSettingsError_UnsetSettingHasNoDefault,
+            );
+            T::default()
+        }
+    }
+}
+
+unsafe extern "C" fn set_bool(
+    section: *mut c_char,
+    key: *mut c_char,
+    value: bool,
+    error: *mut sys::EVRSettingsError,
+) {
+    set(section, key, SettingValue::Bool(value), error)
+}
+
+unsafe extern "C" fn set_int32(
+    section: *mut c_char,
+    key: *mut c_char,
+    value: i32,
+    error: *mut sys::EVRSettingsError,
+) {
+    set(section, key, SettingValue::Int32(value), error)
+}
+
+unsafe extern "C" fn set_float(
+    section: *mut c_char,
+    key: *mut c_char,
+    value: f32,
+    error: *mut sys::EVRSettingsError,
+) {
+    set(section, key, SettingValue::Float(value), error)
+}
+
+unsafe extern "C" fn set_string(
+    section: *mut c_char,
+    key: *mut c_char,
+    value: *mut c_char,
+    error: *mut sys::EVRSettingsError,
+) {
+    let value = CStr::from_ptr(value).to_string_lossy().into_owned();
+    set(section, key, SettingValue::String(value), error)
+}
+
+unsafe extern "C" fn get_bool(
+    section: *mut c_char,
+    key: *mut c_char,
+    error: *mut sys::EVRSettingsError,
+) -> bool {
+    get(section, key, error, |x| match *x {
+        SettingValue::Bool(x) => Some(x),
+        _ => None,
+    })
+}
+
+unsafe extern "C" fn get_int32(
+    section: *mut c_char,
+    key: *mut c_char,
+    error: *mut sys::EVRSettingsError,
+) -> i32 {
+    get(section, key, error, |x| match *x {
+        SettingValue::Int32(x) => Some(x),
+        _ => None,
+    })
+}
+
+unsafe extern "C" fn get_float(
+    section: *mut c_char,
+    key: *mut c_char,
+    error: *mut sys::EVRSettingsError,
+) -> f32 {
+    get(section, key, error, |x| match *x {
+        SettingValue::Float(x) => Some(x),
+        _ => None,
+    })
+}
+
+unsafe extern "C" fn get_string(
+    section: *mut c_char,
+    key: *mut c_char,
+    buffer: *mut c_char,
+    size: u32,
+    error: *mut sys::EVRSettingsError,
+) {
+    let value = get(section, key, error, |x| match *x {
+        SettingValue::String(ref x) => Some(x.clone()),
+        _ => None,
+    });
+    if buffer.is_null() || size == 0 {
+        return;
+    }
+    let n = value.len().min(size as usize - 1);
+    ptr::copy_nonoverlapping(value.as_ptr(), buffer as *mut u8, n);
+    *buffer.add(n) = 0;
+}
+
+unsafe extern "C" fn remove_section(section: *mut c_char, error: *mut sys::EVRSettingsError) {
+    let section = CStr::from_ptr(section).to_string_lossy().into_owned();
+    with_state(|s| s.settings.retain(|(x, _), _| *x != section));
+    report(error, sys::EVRSettingsError_VRSettingsError_None);
+}
+
+unsafe extern "C" fn remove_key_in_section(
+    section: *mut c_char,
+    key: *mut c_char,
+    error: *mut sys::EVRSettingsError,
+) {
+    let key = self::key(section, key);
+    with_state(|s| s.settings.remove(&key));
+    report(error, sys::EVRSettingsError_VRSettingsError_None);
+}
@@ -0,0 +1,378 @@
+use std::os::raw::{c_char, c_void};
+use std::{mem, ptr};
+
+use super::{with_state, PropertyValue, State};
+use crate::{sys, ControllerState, TrackedDeviceIndex, MAX_TRACKED_DEVICE_COUNT};
+
+lazy_static! {
+    pub(super) static ref TABLE: sys::VR_IVRSystem_FnTable = sys::VR_IVRSystem_FnTable {
+        GetRecommendedRenderTargetSize: Some(get_recommended_render_target_size),
+        GetDeviceToAbsoluteTrackingPose: Some(get_device_to_absolute_tracking_pose),
+        GetRawZeroPoseToStandingAbsoluteTrackingPose: Some(
+            get_raw_zero_pose_to_standing_absolute_tracking_pose
+        ),
+        GetSeatedZeroPoseToStandingAbsoluteTrackingPose: Some(
+            get_seated_zero_pose_to_standing_absolute_tracking_pose
+        ),
+        GetTrackedDeviceIndexForControllerRole: Some(get_tracked_device_index_for_controller_role),
+        GetControllerRoleForTrackedDeviceIndex: Some(get_controller_role_for_tracked_device_index),
+        GetTrackedDeviceClass: Some(get_tracked_device_class),
+        IsTrackedDeviceConnected: Some(is_tracked_device_connected),
+        GetTrackedDeviceActivityLevel: Some(get_tracked_device_activity_level),
+        GetBoolTrackedDeviceProperty: Some(get_bool_tracked_device_property),
+        GetFloatTrackedDeviceProperty: Some(get_float_tracked_device_property),
+        GetInt32TrackedDeviceProperty: Some(get_int32_tracked_device_property),
+        GetUint64TrackedDeviceProperty: Some(get_uint64_tracked_device_property),
+        GetMatrix34TrackedDeviceProperty: Some(get_matrix34_tracked_device_property),
+        GetStringTrackedDeviceProperty: Some(get_string_tracked_device_property),
+        GetArrayTrackedDeviceProperty: Some(get_array_tracked_device_property),
+        PollNextEvent: Some(poll_next_event),
+        PollNextEventWithPose: Some(poll_next_event_with_pose),
+        GetControllerState: Some(get_controller_state),
+        GetControllerStateWithPose: Some(get_controller_state_with_pose),
+        TriggerHapticPulse: Some(trigger_haptic_pulse),
+        AcknowledgeQuit_Exiting: Some(acknowledge_quit_exiting),
+        GetButtonIdNameFromEnum: Some(get_button_id_name_from_enum),
+        GetControllerAxisTypeNameFromEnum: Some(get_controller_axis_type_name_from_enum),
+        ..Default::default()
+    };
+}
+
+unsafe extern "C" fn get_recommended_render_target_size(width: *mut u32, height: *mut u32) {
+    let (w, h) = with_state(|s| s.render_target_size);
+    *width = w;
+    *height = h;
+}
+
+unsafe extern "C" fn get_device_to_absolute_tracking_pose(
+    _origin: sys::ETrackingUniverseOrigin,
+    _seconds_to_photons: f32,
+    poses: *mut sys::TrackedDevicePose_t,
+    count: u32,
+) {
+    with_state(|s| {
+        for i in 0..(count as usize).min(MAX_TRACKED_DEVICE_COUNT) {
+            *poses.add(i) = s.pose(i).0;
+        }
+    })
+}
+
+unsafe extern "C" fn get_raw_zero_pose_to_standing_absolute_tracking_pose() -> sys::HmdMatrix34_t {
+    sys::HmdMatrix34_t {
+        m: with_state(|s| s.raw_zero_pose_to_standing),
+    }
+}
+
+unsafe extern "C" fn get_seated_zero_pose_to_standing_absolute_tracking_pose() -> sys::HmdMatrix34_t
+{
+    sys::HmdMatrix34_t {
+        m: with_state(|s| s.seated_zero_pose_to_standing),
+    }
+}
+
+unsafe extern "C" fn get_tracked_device_index_for_controller_role(
+    role: sys::ETrackedControllerRole,
+) -> sys::TrackedDeviceIndex_t {
+    with_state(|s| {
+        s.devices
+            .iter()
+            .position(
+                |x| matches!(x, Some(device) if device.role as sys::ETrackedControllerRole == role),
+            )
+            .map_or(sys::k_unTrackedDeviceIndexInvalid as u32, |x| x as u32)
+    })
+}
+
+unsafe extern "C" fn get_controller_role_for_tracked_device_index(
+    index: sys::TrackedDeviceIndex_t,
+) -> sys::ETrackedControllerRole {
+    with_state(|s| {
+        s.device(index).map_or(
+            sys::ETrackedControllerRole_TrackedControllerRole_Invalid,
+            |x| x.role as sys::ETrackedControllerRole,
+        )
+    })
+}
+
+unsafe extern "C" fn get_tracked_device_class(
+    index: sys::TrackedDeviceIndex_t,
+) -> sys::ETrackedDeviceClass {
+    with_state(|s| {
+        s.device(index)
+            .map_or(sys::ETrackedDeviceClass_TrackedDeviceClass_Invalid, |x| {
+                x.class as sys::ETrackedDeviceClass
+            })
+    })
+}
+
+unsafe extern "C" fn is_tracked_device_connected(index: sys::TrackedDeviceIndex_t) -> bool {
+    with_state(|s| s.device(index).is_some_and(|x| x.connected))
+}
+
+unsafe extern "C" fn get_tracked_device_activity_level(
+    index: sys::TrackedDeviceIndex_t,
+) -> sys::EDeviceActivityLevel {
+    with_state(|s| {
+        s.device(index).map_or(
+            sys::EDeviceActivityLevel_k_EDeviceActivityLevel_Unknown,
+            |x| x.activity_level as sys::EDeviceActivityLevel,
+        )
+    })
+}
+
+/// Looks up a property and reports the outcome through `error`, mirroring the runtime's error
+/// codes.
+unsafe fn property<T: Default>(
+    index: sys::TrackedDeviceIndex_t,
+    prop: sys::ETrackedDeviceProperty,
+    error: *mut sys::ETrackedPropertyError,
+    f: impl FnOnce(&PropertyValue) -> Option<T>,
+) -> T {
+    let (value, e) = with_state(|s: &mut State| match s.device(index) {
+        None => (
+            T::default(),
+            sys::ETrackedPropertyError_TrackedProp_InvalidDevice,
+        ),
+        Some(device) => match device.properties.get(&prop) {
+            None => (
+                T::default(),
+                sys::ETrackedPropertyError_TrackedProp_UnknownProperty,
+            ),
+            Some(value) => match f(value) {
+                Some(x) => (x, sys::ETrackedPropertyError_TrackedProp_Success),
+                None => (
+                    T::default(),
+                    sys::ETrackedPropertyError_TrackedProp_WrongDataType,
+                ),
+            },
+        },
+    });
+    if !error.is_null() {
+        *error = e;
+    }
+    value
+}
+
+unsafe extern "C" fn get_bool_tracked_device_property(
+    index: sys::TrackedDeviceIndex_t,
+    prop: sys::ETrackedDeviceProperty,
+    error: *mut sys::ETrackedPropertyError,
+) -> bool {
+    property(index, prop, error, |x| match *x {
+        PropertyValue::Bool(x) => Some(x),
+        _ => None,
+    })
+}
+
+unsafe extern "C" fn get_float_tracked_device_property(
+    index: sys::TrackedDeviceIndex_t,
+    prop: sys::ETrackedDeviceProperty,
+    error: *mut sys::ETrackedPropertyError,
+) -> f32 {
+    property(index, prop, error, |x| match *x {
+        PropertyValue::Float(x) => Some(x),
+        _ => None,
+    })
+}
+
+unsafe extern "C" fn get_int32_tracked_device_property(
+    index: sys::TrackedDeviceIndex_t,
+    prop: sys::ETrackedDeviceProperty,
+    error: *mut sys::ETrackedPropertyError,
+) -> i32 {
+    property(index, prop, error, |x| match *x {
+        PropertyValue::Int32(x) => Some(x),
+        _ => None,
+    })
+}
+
+unsafe extern "C" fn get_uint64_tracked_device_property(
+    index: sys::TrackedDeviceIndex_t,
+    prop: sys::ETrackedDeviceProperty,
+    error: *mut sys::ETrackedPropertyError,
+) -> u64 {
+    property(index, prop, error, |x| match *x {
+        PropertyValue::Uint64(x) => Some(x),
+        _ => None,
+    })
+}
+
+unsafe extern "C" fn get_matrix34_tracked_device_property(
+    index: sys::TrackedDeviceIndex_t,
+    prop: sys::ETrackedDeviceProperty,
+    error: *mut sys::ETrackedPropertyError,
+) -> sys::HmdMatrix34_t {
+    property(index, prop, error, |x| match *x {
+        PropertyValue::Matrix34(m) => Some(sys::HmdMatrix34_t { m }),
+        _ => None,
+    })
+}
+
+unsafe extern "C" fn get_string_tracked_device_property(
+    index: sys::TrackedDeviceIndex_t,
+    prop: sys::ETrackedDeviceProperty,
+    buffer: *mut c_char,
+    size: u32,
+    error: *mut sys::ETrackedPropertyError,
+) -> u32 {
+    let value = property(index, prop, error, |x| match *x {
+        PropertyValue::String(ref x) => Some(x.clone()),
+        _ => None,
+    });
+    if !error.is_null() && *error != sys::ETrackedPropertyError_TrackedProp_Success {
+        return 0;
+    }
+    let required = value.len() as u32 + 1;
+    if size < required {
+        if !error.is_null() {
+            *error = sys::ETrackedPropertyError_TrackedProp_BufferTooSmall;
+        }
+    } else {
+        ptr::copy_nonoverlapping(value.as_ptr(), buffer as *mut u8, value.len());
+        *buffer.add(value.len()) = 0;
+    }
+    required
+}
+
+unsafe extern "C" fn get_array_tracked_device_property(
+    index: sys::TrackedDeviceIndex_t,
+    prop: sys::ETrackedDeviceProperty,
+    tag: sys::PropertyTypeTag_t,
+    buffer: *mut c_void,
+    size: u32,
+    error: *mut sys::ETrackedPropertyError,
+) -> u32 {
+    let mut e = sys::ETrackedPropertyError_TrackedProp_Success;
+    let data = property(index, prop, &mut e, |x| match x.tagged_bytes() {
+        (t, data) if t == tag => Some(data),
+        _ => None,
+    });
+    if e == sys::ETrackedPropertyError_TrackedProp_Success && (size as usize) < data.len() {
+        e = sys::ETrackedPropertyError_TrackedProp_BufferTooSmall;
+    } else if e == sys::ETrackedPropertyError_TrackedProp_Success {
+        ptr::copy_nonoverlapping(data.as_ptr(), buffer as *mut u8, data.len());
+    }
+    if !error.is_null() {
+        *error = e;
+    }
+    data.len() as u32
+}
+
+/// Pops the next queued event into `event`, returning the index of the device it concerns.
+unsafe fn pop_event(s: &mut State, event: *mut sys::VREvent_t, size: u32) -> Option<usize> {
+    if (size as usize) < mem::size_of::<sys::VREvent_t>() {
+        return None;
+    }
+    let next = s.events.pop_front()?;
+    ptr::write_unaligned(event, next);
+    Some(next.trackedDeviceIndex as usize)
+}
+
+unsafe extern "C" fn poll_next_event(event: *mut sys::VREvent_t, size: u32) -> bool {
+    with_state(|s| pop_event(s, event, size).is_some())
+}
+
+unsafe extern "C" fn poll_next_event_with_pose(
+    _origin: sys::ETrackingUniverseOrigin,
+    event: *mut sys::VREvent_t,
+    size: u32,
+    pose: *mut sys::TrackedDevicePose_t,
+) -> bool {
+    with_state(|s| match pop_event(s, event, size) {
+        Some(index) => {
+            if !pose.is_null() {
+                *pose = if index < MAX_TRACKED_DEVICE_COUNT {
+                    s.pose(index).0
+                } else {
+                    super::disconnected_pose().0
+                };
+            }
+            true
+        }
+        None => false,
+    })
+}
+
+unsafe fn controller_state(
+    s: &State,
+    index: sys::TrackedDeviceIndex_t,
+    state: *mut sys::VRControllerState_t,
+    size: u32,
+) -> bool {
+    if (size as usize) < mem::size_of::<ControllerState>() {
+        return false;
+    }
+    match s.device(index).and_then(|x| x.controller_state) {
+        Some(x) => {
+            *(state as *mut ControllerState) = x;
+            true
+        }
+        None => false,
+    }
+}
+
+unsafe extern "C" fn get_controller_state(
+    index: sys::TrackedDeviceIndex_t,
+    state: *mut sys::VRControllerState_t,
+    size: u32,
+) -> bool {
+    with_state(|s| controller_state(s, index, state, size))
+}
+
+unsafe extern "C" fn get_controller_state_with_pose(
+    _origin: sys::ETrackingUniverseOrigin,
+    index: sys::TrackedDeviceIndex_t,
+    state: *mut sys::VRControllerState_t,
+    size: u32,
+    pose: *mut sys::TrackedDevicePose_t,
+) -> bool {
+    with_state(|s| {
+        if !controller_state(s, index, state, size) {
+            return false;
+        }
+        if !pose.is_null() {
+            *pose = s.pose(index as usize).0;
+        }
+        true
+    })
+}
+
+unsafe extern "C" fn trigger_haptic_pulse(
+    index: sys::TrackedDeviceIndex_t,
+    axis: u32,
+    duration: u16,
+) {
+    with_state(|s| {
+        s.haptic_pulses
+            .push((TrackedDeviceIndex(index), axis, duration))
+    })
+}
+
+unsafe extern "C" fn acknowledge_quit_exiting() {
+    with_state(|s| s.quit_acknowledged = true)
+}
+
+unsafe extern "C" fn get_button_id_name_from_enum(id: sys::EVRButtonId) -> *mut c_char {
+    let name: &'static [u8] = match id {
+        sys::EVRButtonId_k_EButton_System => b"k_EButton_System\0",
+        sys::EVRButtonId_k_EButton_ApplicationMenu => b"k_EButton_ApplicationMenu\0",
+        sys::EVRButtonId_k_EButton_Grip => b"k_EButton_Grip\0",
+        sys::EVRButtonId_k_EButton_Axis0 => b"k_EButton_Axis0\0",
+        sys::EVRButtonId_k_EButton_Axis1 => b"k_EButton_Axis1\0",
+        _ => b"Unknown EVRButtonId\0",
+    };
+    name.as_ptr() as *mut c_char
+}
+
+unsafe extern "C" fn get_controller_axis_type_name_from_enum(
+    ty: sys::EVRControllerAxisType,
+) -> *mut c_char {
+    let name: &'static [u8] = match ty {
+        sys::EVRControllerAxisType_k_eControllerAxis_None => b"k_eControllerAxis_None\0",
+        sys::EVRControllerAxisType_k_eControllerAxis_TrackPad => b"k_eControllerAxis_TrackPad\0",
+        sys::EVRControllerAxisType_k_eControllerAxis_Joystick => b"k_eControllerAxis_Joystick\0",
+        sys::EVRControllerAxisType_k_eControllerAxis_Trigger => b"k_eControllerAxis_Trigger\0",
+        _ => b"Unknown EVRControllerAxisType\0",
+    };
+    name.as_ptr() as *mut c_char
+}
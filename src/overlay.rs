@@ -1,14 +1,16 @@
 
 pub use crate::errors::VROverlayError;
 use crate::pose::Matrix3x4;
+use crate::system::EventInfo;
 use crate::Overlay;
 use crate::TextureBounds;
 use crate::TrackingUniverseOrigin;
 use crate::{sys, ColorTint, TrackedDeviceIndex};
+use std::mem;
 
 
 
-impl Overlay {
+impl Overlay<'_> {
 
     pub fn create_overlay(
         &mut self,
@@ -319,9 +321,46 @@ impl Overlay {
     pub fn is_dashboard_visible(&mut self) -> bool {
         unsafe { self.0.IsDashboardVisible.unwrap()() }
     }
+
+    /// Returns the next pending event for `overlay`, such as mouse and scroll events.
+    pub fn poll_next_event(&mut self, overlay: OverlayHandle) -> Option<EventInfo> {
+        let mut event = mem::MaybeUninit::uninit();
+        if unsafe {
+            self.0.PollNextOverlayEvent.unwrap()(
+                overlay.0,
+                event.as_mut_ptr(),
+                mem::size_of_val(&event) as u32,
+            )
+        } {
+            unsafe { Some(event.assume_init().into()) }
+        } else {
+            None
+        }
+    }
+
+    /// Drains the pending events for `overlay`.
+    pub fn events(&mut self, overlay: OverlayHandle) -> OverlayEvents<'_> {
+        OverlayEvents {
+            overlay: Overlay(self.0),
+            handle: overlay,
+        }
+    }
 }
-unsafe impl Send for Overlay {}
-unsafe impl Sync for Overlay {}
+unsafe impl Send for Overlay<'_> {}
+unsafe impl Sync for Overlay<'_> {}
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct OverlayHandle(pub sys::VROverlayHandle_t);
+
+/// Iterator over the pending events of an overlay, returned by `Overlay::events`.
+pub struct OverlayEvents<'a> {
+    overlay: Overlay<'a>,
+    handle: OverlayHandle,
+}
+
+impl Iterator for OverlayEvents<'_> {
+    type Item = EventInfo;
+    fn next(&mut self) -> Option<EventInfo> {
+        self.overlay.poll_next_event(self.handle)
+    }
+}
@@ -48,6 +48,49 @@ impl From<Matrix3x4> for sys::HmdMatrix34_t {
     }
 }
 
+/// Rotation quaternion, laid out like `HmdQuaternionf_t`
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion {
+        w: 1.,
+        x: 0.,
+        y: 0.,
+        z: 0.,
+    };
+}
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+impl From<sys::HmdQuaternionf_t> for Quaternion {
+    fn from(x: sys::HmdQuaternionf_t) -> Self {
+        Quaternion {
+            w: x.w,
+            x: x.x,
+            y: x.y,
+            z: x.z,
+        }
+    }
+}
+impl From<Quaternion> for sys::HmdQuaternionf_t {
+    fn from(x: Quaternion) -> Self {
+        sys::HmdQuaternionf_t {
+            w: x.w,
+            x: x.x,
+            y: x.y,
+            z: x.z,
+        }
+    }
+}
+
 pub use sys::ETrackingUniverseOrigin as TrackingUniverseOrigin;
 
 #[cfg(test)]
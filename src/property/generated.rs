@@ -0,0 +1,475 @@
+// Generated by scripts/gen_properties.py from openvr_api.json. Do not edit by hand.
+#![allow(non_upper_case_globals)]
+
+use super::Property;
+use crate::pose::Matrix3x4;
+use crate::TrackedDeviceProperty;
+use openvr_sys as sys;
+pub const Invalid: TrackedDeviceProperty =
+    TrackedDeviceProperty(sys::ETrackedDeviceProperty_Prop_Invalid);
+pub const TrackingSystemName_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_TrackingSystemName_String);
+pub const ModelNumber_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ModelNumber_String);
+pub const SerialNumber_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_SerialNumber_String);
+pub const RenderModelName_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_RenderModelName_String);
+pub const WillDriftInYaw_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_WillDriftInYaw_Bool);
+pub const ManufacturerName_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ManufacturerName_String);
+pub const TrackingFirmwareVersion_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_TrackingFirmwareVersion_String);
+pub const HardwareRevision_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HardwareRevision_String);
+pub const AllWirelessDongleDescriptions_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_AllWirelessDongleDescriptions_String);
+pub const ConnectedWirelessDongle_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ConnectedWirelessDongle_String);
+pub const DeviceIsWireless_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DeviceIsWireless_Bool);
+pub const DeviceIsCharging_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DeviceIsCharging_Bool);
+pub const DeviceBatteryPercentage_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DeviceBatteryPercentage_Float);
+pub const StatusDisplayTransform_Matrix34: Property<[[f32; 4]; 3]> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_StatusDisplayTransform_Matrix34);
+pub const Firmware_UpdateAvailable_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Firmware_UpdateAvailable_Bool);
+pub const Firmware_ManualUpdate_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Firmware_ManualUpdate_Bool);
+pub const Firmware_ManualUpdateURL_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Firmware_ManualUpdateURL_String);
+pub const HardwareRevision_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HardwareRevision_Uint64);
+pub const FirmwareVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_FirmwareVersion_Uint64);
+pub const FPGAVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_FPGAVersion_Uint64);
+pub const VRCVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_VRCVersion_Uint64);
+pub const RadioVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_RadioVersion_Uint64);
+pub const DongleVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DongleVersion_Uint64);
+pub const BlockServerShutdown_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_BlockServerShutdown_Bool);
+pub const CanUnifyCoordinateSystemWithHmd_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CanUnifyCoordinateSystemWithHmd_Bool);
+pub const ContainsProximitySensor_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ContainsProximitySensor_Bool);
+pub const DeviceProvidesBatteryStatus_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DeviceProvidesBatteryStatus_Bool);
+pub const DeviceCanPowerOff_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DeviceCanPowerOff_Bool);
+pub const Firmware_ProgrammingTarget_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Firmware_ProgrammingTarget_String);
+pub const DeviceClass_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DeviceClass_Int32);
+pub const HasCamera_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HasCamera_Bool);
+pub const DriverVersion_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverVersion_String);
+pub const Firmware_ForceUpdateRequired_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Firmware_ForceUpdateRequired_Bool);
+pub const ViveSystemButtonFixRequired_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ViveSystemButtonFixRequired_Bool);
+pub const ParentDriver_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ParentDriver_Uint64);
+pub const ResourceRoot_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ResourceRoot_String);
+pub const RegisteredDeviceType_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_RegisteredDeviceType_String);
+pub const InputProfilePath_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_InputProfilePath_String);
+pub const NeverTracked_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NeverTracked_Bool);
+pub const NumCameras_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NumCameras_Int32);
+pub const CameraFrameLayout_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraFrameLayout_Int32);
+pub const CameraStreamFormat_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraStreamFormat_Int32);
+pub const AdditionalDeviceSettingsPath_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_AdditionalDeviceSettingsPath_String);
+pub const Identifiable_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Identifiable_Bool);
+pub const BootloaderVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_BootloaderVersion_Uint64);
+pub const AdditionalSystemReportData_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_AdditionalSystemReportData_String);
+pub const CompositeFirmwareVersion_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CompositeFirmwareVersion_String);
+pub const Firmware_RemindUpdate_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Firmware_RemindUpdate_Bool);
+pub const PeripheralApplicationVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_PeripheralApplicationVersion_Uint64);
+pub const ManufacturerSerialNumber_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ManufacturerSerialNumber_String);
+pub const ComputedSerialNumber_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ComputedSerialNumber_String);
+pub const EstimatedDeviceFirstUseTime_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_EstimatedDeviceFirstUseTime_Int32);
+pub const DevicePowerUsage_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DevicePowerUsage_Float);
+pub const IgnoreMotionForStandby_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_IgnoreMotionForStandby_Bool);
+pub const ActualTrackingSystemName_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ActualTrackingSystemName_String);
+pub const AllowCameraToggle_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_AllowCameraToggle_Bool);
+pub const AllowLightSourceFrequency_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_AllowLightSourceFrequency_Bool);
+pub const SteamRemoteClientID_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_SteamRemoteClientID_Uint64);
+pub const ReportsTimeSinceVSync_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ReportsTimeSinceVSync_Bool);
+pub const SecondsFromVsyncToPhotons_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_SecondsFromVsyncToPhotons_Float);
+pub const DisplayFrequency_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayFrequency_Float);
+pub const UserIpdMeters_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_UserIpdMeters_Float);
+pub const CurrentUniverseId_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CurrentUniverseId_Uint64);
+#[deprecated(note = "OpenVR deprecated this property; openvr_sys now maps it to Prop_Invalid.")]
+pub const PreviousUniverseId_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_PreviousUniverseId_Uint64_deprecated);
+pub const DisplayFirmwareVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayFirmwareVersion_Uint64);
+pub const IsOnDesktop_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_IsOnDesktop_Bool);
+pub const DisplayMCType_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMCType_Int32);
+pub const DisplayMCOffset_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMCOffset_Float);
+pub const DisplayMCScale_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMCScale_Float);
+pub const EdidVendorID_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_EdidVendorID_Int32);
+pub const DisplayMCImageLeft_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMCImageLeft_String);
+pub const DisplayMCImageRight_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMCImageRight_String);
+pub const DisplayGCBlackClamp_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayGCBlackClamp_Float);
+pub const EdidProductID_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_EdidProductID_Int32);
+pub const CameraToHeadTransform_Matrix34: Property<[[f32; 4]; 3]> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraToHeadTransform_Matrix34);
+pub const DisplayGCType_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayGCType_Int32);
+pub const DisplayGCOffset_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayGCOffset_Float);
+pub const DisplayGCScale_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayGCScale_Float);
+pub const DisplayGCPrescale_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayGCPrescale_Float);
+pub const DisplayGCImage_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayGCImage_String);
+pub const LensCenterLeftU_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_LensCenterLeftU_Float);
+pub const LensCenterLeftV_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_LensCenterLeftV_Float);
+pub const LensCenterRightU_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_LensCenterRightU_Float);
+pub const LensCenterRightV_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_LensCenterRightV_Float);
+pub const UserHeadToEyeDepthMeters_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_UserHeadToEyeDepthMeters_Float);
+pub const CameraFirmwareVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraFirmwareVersion_Uint64);
+pub const CameraFirmwareDescription_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraFirmwareDescription_String);
+pub const DisplayFPGAVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayFPGAVersion_Uint64);
+pub const DisplayBootloaderVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayBootloaderVersion_Uint64);
+pub const DisplayHardwareVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayHardwareVersion_Uint64);
+pub const AudioFirmwareVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_AudioFirmwareVersion_Uint64);
+pub const CameraCompatibilityMode_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraCompatibilityMode_Int32);
+pub const ScreenshotHorizontalFieldOfViewDegrees_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ScreenshotHorizontalFieldOfViewDegrees_Float);
+pub const ScreenshotVerticalFieldOfViewDegrees_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ScreenshotVerticalFieldOfViewDegrees_Float);
+pub const DisplaySuppressed_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplaySuppressed_Bool);
+pub const DisplayAllowNightMode_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayAllowNightMode_Bool);
+pub const DisplayMCImageWidth_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMCImageWidth_Int32);
+pub const DisplayMCImageHeight_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMCImageHeight_Int32);
+pub const DisplayMCImageNumChannels_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMCImageNumChannels_Int32);
+pub const DisplayMCImageData_Binary: TrackedDeviceProperty =
+    TrackedDeviceProperty(sys::ETrackedDeviceProperty_Prop_DisplayMCImageData_Binary);
+pub const SecondsFromPhotonsToVblank_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_SecondsFromPhotonsToVblank_Float);
+pub const DriverDirectModeSendsVsyncEvents_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverDirectModeSendsVsyncEvents_Bool);
+pub const DisplayDebugMode_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayDebugMode_Bool);
+pub const GraphicsAdapterLuid_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_GraphicsAdapterLuid_Uint64);
+pub const DriverProvidedChaperonePath_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverProvidedChaperonePath_String);
+pub const ExpectedTrackingReferenceCount_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ExpectedTrackingReferenceCount_Int32);
+pub const ExpectedControllerCount_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ExpectedControllerCount_Int32);
+pub const NamedIconPathControllerLeftDeviceOff_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathControllerLeftDeviceOff_String);
+pub const NamedIconPathControllerRightDeviceOff_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathControllerRightDeviceOff_String);
+pub const NamedIconPathTrackingReferenceDeviceOff_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathTrackingReferenceDeviceOff_String);
+pub const DoNotApplyPrediction_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DoNotApplyPrediction_Bool);
+pub const CameraToHeadTransforms_Matrix34_Array: Property<Vec<Matrix3x4>> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraToHeadTransforms_Matrix34_Array);
+pub const DistortionMeshResolution_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DistortionMeshResolution_Int32);
+pub const DriverIsDrawingControllers_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverIsDrawingControllers_Bool);
+pub const DriverRequestsApplicationPause_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestsApplicationPause_Bool);
+pub const DriverRequestsReducedRendering_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestsReducedRendering_Bool);
+pub const MinimumIpdStepMeters_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_MinimumIpdStepMeters_Float);
+pub const AudioBridgeFirmwareVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_AudioBridgeFirmwareVersion_Uint64);
+pub const ImageBridgeFirmwareVersion_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ImageBridgeFirmwareVersion_Uint64);
+pub const ImuToHeadTransform_Matrix34: Property<[[f32; 4]; 3]> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ImuToHeadTransform_Matrix34);
+pub const ImuFactoryGyroBias_Vector3: Property<[f32; 3]> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ImuFactoryGyroBias_Vector3);
+pub const ImuFactoryGyroScale_Vector3: Property<[f32; 3]> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ImuFactoryGyroScale_Vector3);
+pub const ImuFactoryAccelerometerBias_Vector3: Property<[f32; 3]> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ImuFactoryAccelerometerBias_Vector3);
+pub const ImuFactoryAccelerometerScale_Vector3: Property<[f32; 3]> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ImuFactoryAccelerometerScale_Vector3);
+pub const ConfigurationIncludesLighthouse20Features_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ConfigurationIncludesLighthouse20Features_Bool);
+pub const AdditionalRadioFeatures_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_AdditionalRadioFeatures_Uint64);
+pub const CameraWhiteBalance_Vector4_Array: Property<Vec<[f32; 4]>> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraWhiteBalance_Vector4_Array);
+pub const CameraDistortionFunction_Int32_Array: Property<Vec<i32>> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraDistortionFunction_Int32_Array);
+pub const CameraDistortionCoefficients_Float_Array: Property<Vec<f64>> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraDistortionCoefficients_Float_Array);
+pub const ExpectedControllerType_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ExpectedControllerType_String);
+pub const HmdTrackingStyle_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HmdTrackingStyle_Int32);
+pub const DriverProvidedChaperoneVisibility_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverProvidedChaperoneVisibility_Bool);
+pub const HmdColumnCorrectionSettingPrefix_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HmdColumnCorrectionSettingPrefix_String);
+pub const CameraSupportsCompatibilityModes_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraSupportsCompatibilityModes_Bool);
+pub const SupportsRoomViewDepthProjection_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_SupportsRoomViewDepthProjection_Bool);
+pub const DisplayAvailableFrameRates_Float_Array: Property<Vec<f32>> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayAvailableFrameRates_Float_Array);
+pub const DisplaySupportsMultipleFramerates_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplaySupportsMultipleFramerates_Bool);
+pub const DisplayColorMultLeft_Vector3: Property<[f32; 3]> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayColorMultLeft_Vector3);
+pub const DisplayColorMultRight_Vector3: Property<[f32; 3]> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayColorMultRight_Vector3);
+pub const DisplaySupportsRuntimeFramerateChange_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplaySupportsRuntimeFramerateChange_Bool);
+pub const DisplaySupportsAnalogGain_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplaySupportsAnalogGain_Bool);
+pub const DisplayMinAnalogGain_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMinAnalogGain_Float);
+pub const DisplayMaxAnalogGain_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMaxAnalogGain_Float);
+pub const CameraExposureTime_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraExposureTime_Float);
+pub const CameraGlobalGain_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CameraGlobalGain_Float);
+pub const DashboardScale_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DashboardScale_Float);
+pub const Hmd_SupportsHDR10_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_SupportsHDR10_Bool);
+pub const Hmd_EnableParallelRenderCameras_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_EnableParallelRenderCameras_Bool);
+pub const DriverProvidedChaperoneJson_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverProvidedChaperoneJson_String);
+pub const ForceSystemLayerUseAppPoses_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ForceSystemLayerUseAppPoses_Bool);
+pub const DashboardLinkSupport_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DashboardLinkSupport_Int32);
+pub const DisplayMinUIAnalogGain_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DisplayMinUIAnalogGain_Float);
+pub const IpdUIRangeMinMeters_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_IpdUIRangeMinMeters_Float);
+pub const IpdUIRangeMaxMeters_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_IpdUIRangeMaxMeters_Float);
+pub const Hmd_SupportsHDCP14LegacyCompat_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_SupportsHDCP14LegacyCompat_Bool);
+pub const Hmd_SupportsMicMonitoring_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_SupportsMicMonitoring_Bool);
+pub const Hmd_SupportsDisplayPortTrainingMode_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_SupportsDisplayPortTrainingMode_Bool);
+pub const Hmd_SupportsRoomViewDirect_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_SupportsRoomViewDirect_Bool);
+pub const Hmd_SupportsAppThrottling_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_SupportsAppThrottling_Bool);
+pub const Hmd_SupportsGpuBusMonitoring_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_SupportsGpuBusMonitoring_Bool);
+pub const DriverDisplaysIPDChanges_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverDisplaysIPDChanges_Bool);
+pub const Hmd_MaxDistortedTextureWidth_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_MaxDistortedTextureWidth_Int32);
+pub const Hmd_MaxDistortedTextureHeight_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_MaxDistortedTextureHeight_Int32);
+pub const Hmd_AllowSupersampleFiltering_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_AllowSupersampleFiltering_Bool);
+pub const Hmd_AllowsClientToControlTextureIndex: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Hmd_AllowsClientToControlTextureIndex);
+pub const DriverRequestedMuraCorrectionMode_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestedMuraCorrectionMode_Int32);
+pub const DriverRequestedMuraFeather_InnerLeft_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestedMuraFeather_InnerLeft_Int32);
+pub const DriverRequestedMuraFeather_InnerRight_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestedMuraFeather_InnerRight_Int32);
+pub const DriverRequestedMuraFeather_InnerTop_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestedMuraFeather_InnerTop_Int32);
+pub const DriverRequestedMuraFeather_InnerBottom_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestedMuraFeather_InnerBottom_Int32);
+pub const DriverRequestedMuraFeather_OuterLeft_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestedMuraFeather_OuterLeft_Int32);
+pub const DriverRequestedMuraFeather_OuterRight_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestedMuraFeather_OuterRight_Int32);
+pub const DriverRequestedMuraFeather_OuterTop_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestedMuraFeather_OuterTop_Int32);
+pub const DriverRequestedMuraFeather_OuterBottom_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DriverRequestedMuraFeather_OuterBottom_Int32);
+pub const Audio_DefaultPlaybackDeviceId_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_DefaultPlaybackDeviceId_String);
+pub const Audio_DefaultRecordingDeviceId_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_DefaultRecordingDeviceId_String);
+pub const Audio_DefaultPlaybackDeviceVolume_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_DefaultPlaybackDeviceVolume_Float);
+pub const Audio_SupportsDualSpeakerAndJackOutput_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_SupportsDualSpeakerAndJackOutput_Bool);
+pub const Audio_DriverManagesPlaybackVolumeControl_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_DriverManagesPlaybackVolumeControl_Bool);
+pub const Audio_DriverPlaybackVolume_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_DriverPlaybackVolume_Float);
+pub const Audio_DriverPlaybackMute_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_DriverPlaybackMute_Bool);
+pub const Audio_DriverManagesRecordingVolumeControl_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_DriverManagesRecordingVolumeControl_Bool);
+pub const Audio_DriverRecordingVolume_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_DriverRecordingVolume_Float);
+pub const Audio_DriverRecordingMute_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_DriverRecordingMute_Bool);
+pub const Audio_PipewirePlaybackNode_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_PipewirePlaybackNode_Int32);
+pub const Audio_PipewireRecordingNode_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Audio_PipewireRecordingNode_Int32);
+pub const AttachedDeviceId_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_AttachedDeviceId_String);
+pub const SupportedButtons_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_SupportedButtons_Uint64);
+pub const Axis0Type_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Axis0Type_Int32);
+pub const Axis1Type_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Axis1Type_Int32);
+pub const Axis2Type_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Axis2Type_Int32);
+pub const Axis3Type_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Axis3Type_Int32);
+pub const Axis4Type_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_Axis4Type_Int32);
+pub const ControllerRoleHint_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ControllerRoleHint_Int32);
+pub const FieldOfViewLeftDegrees_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_FieldOfViewLeftDegrees_Float);
+pub const FieldOfViewRightDegrees_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_FieldOfViewRightDegrees_Float);
+pub const FieldOfViewTopDegrees_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_FieldOfViewTopDegrees_Float);
+pub const FieldOfViewBottomDegrees_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_FieldOfViewBottomDegrees_Float);
+pub const TrackingRangeMinimumMeters_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_TrackingRangeMinimumMeters_Float);
+pub const TrackingRangeMaximumMeters_Float: Property<f32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_TrackingRangeMaximumMeters_Float);
+pub const ModeLabel_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ModeLabel_String);
+pub const CanWirelessIdentify_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_CanWirelessIdentify_Bool);
+pub const Nonce_Int32: Property<i32> = Property::new(sys::ETrackedDeviceProperty_Prop_Nonce_Int32);
+pub const IconPathName_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_IconPathName_String);
+pub const NamedIconPathDeviceOff_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathDeviceOff_String);
+pub const NamedIconPathDeviceSearching_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathDeviceSearching_String);
+pub const NamedIconPathDeviceSearchingAlert_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathDeviceSearchingAlert_String);
+pub const NamedIconPathDeviceReady_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathDeviceReady_String);
+pub const NamedIconPathDeviceReadyAlert_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathDeviceReadyAlert_String);
+pub const NamedIconPathDeviceNotReady_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathDeviceNotReady_String);
+pub const NamedIconPathDeviceStandby_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathDeviceStandby_String);
+pub const NamedIconPathDeviceAlertLow_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathDeviceAlertLow_String);
+pub const NamedIconPathDeviceStandbyAlert_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_NamedIconPathDeviceStandbyAlert_String);
+pub const DisplayHiddenArea_Binary_Start: TrackedDeviceProperty =
+    TrackedDeviceProperty(sys::ETrackedDeviceProperty_Prop_DisplayHiddenArea_Binary_Start);
+pub const DisplayHiddenArea_Binary_End: TrackedDeviceProperty =
+    TrackedDeviceProperty(sys::ETrackedDeviceProperty_Prop_DisplayHiddenArea_Binary_End);
+pub const ParentContainer: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ParentContainer);
+pub const OverrideContainer_Uint64: Property<u64> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_OverrideContainer_Uint64);
+pub const UserConfigPath_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_UserConfigPath_String);
+pub const InstallPath_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_InstallPath_String);
+pub const HasDisplayComponent_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HasDisplayComponent_Bool);
+pub const HasControllerComponent_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HasControllerComponent_Bool);
+pub const HasCameraComponent_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HasCameraComponent_Bool);
+pub const HasDriverDirectModeComponent_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HasDriverDirectModeComponent_Bool);
+pub const HasVirtualDisplayComponent_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HasVirtualDisplayComponent_Bool);
+pub const HasSpatialAnchorsSupport_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_HasSpatialAnchorsSupport_Bool);
+pub const SupportsXrTextureSets_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_SupportsXrTextureSets_Bool);
+pub const SupportsXrEyeGazeInteraction_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_SupportsXrEyeGazeInteraction_Bool);
+pub const DeviceHasNoIMU_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_DeviceHasNoIMU_Bool);
+pub const UseAdvancedPrediction_Bool: Property<bool> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_UseAdvancedPrediction_Bool);
+pub const ControllerType_String: Property<String> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ControllerType_String);
+pub const ControllerHandSelectionPriority_Int32: Property<i32> =
+    Property::new(sys::ETrackedDeviceProperty_Prop_ControllerHandSelectionPriority_Int32);
+pub const VendorSpecific_Reserved_Start: TrackedDeviceProperty =
+    TrackedDeviceProperty(sys::ETrackedDeviceProperty_Prop_VendorSpecific_Reserved_Start);
+pub const VendorSpecific_Reserved_End: TrackedDeviceProperty =
+    TrackedDeviceProperty(sys::ETrackedDeviceProperty_Prop_VendorSpecific_Reserved_End);
@@ -0,0 +1,305 @@
+//! Tracked device property keys.
+//!
+//! Each key carries the Rust type its value is read as, so `System::property` returns the right
+//! type without a getter per value type:
+//!
+//! ```no_run
+//! # let context = openvr::init(openvr::ApplicationType::Other).unwrap();
+//! # let system = context.system().unwrap();
+//! use openvr::{property, tracked_device_index};
+//!
+//! let hmd = tracked_device_index::HMD;
+//! let serial: String = system.property(hmd, property::SerialNumber_String).unwrap();
+//! let refresh_rate: f32 = system.property(hmd, property::DisplayFrequency_Float).unwrap();
+//! ```
+//!
+//! `_Array` properties read as a `Vec` of their element type (see `ArrayElement`), and `_Vector3`
+//! properties as `[f32; 3]`. Both go through `GetArrayTrackedDeviceProperty`.
+//!
+//! The constants are generated from the OpenVR API description by `scripts/gen_properties.py`.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::{mem, ptr};
+
+use crate::pose::Matrix3x4;
+use crate::system::TrackedPropertyError;
+use crate::{sys, System, TrackedDeviceIndex, TrackedDeviceProperty};
+
+mod generated;
+pub use self::generated::*;
+
+/// A tracked device property whose value is read as `T`.
+pub struct Property<T> {
+    raw: sys::ETrackedDeviceProperty,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> Property<T> {
+    /// Wraps a raw property id. Nothing checks that `T` matches the type OpenVR stores for it.
+    pub const fn new(raw: sys::ETrackedDeviceProperty) -> Self {
+        Property {
+            raw,
+            _value: PhantomData,
+        }
+    }
+
+    pub fn raw(&self) -> sys::ETrackedDeviceProperty {
+        self.raw
+    }
+}
+
+impl<T> Clone for Property<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Property<T> {}
+
+impl<T> PartialEq for Property<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+impl<T> Eq for Property<T> {}
+
+impl<T> fmt::Debug for Property<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Property").field(&self.raw).finish()
+    }
+}
+
+impl<T> From<Property<T>> for TrackedDeviceProperty {
+    fn from(x: Property<T>) -> Self {
+        TrackedDeviceProperty(x.raw)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Types a tracked device property value can be read as.
+pub trait PropertyType: Sized + sealed::Sealed {
+    #[doc(hidden)]
+    fn get(
+        system: &System,
+        device: TrackedDeviceIndex,
+        property: sys::ETrackedDeviceProperty,
+    ) -> Result<Self, TrackedPropertyError>;
+}
+
+/// Calls a scalar property getter, turning its out-parameter error into a `Result`.
+fn scalar<T>(
+    f: impl FnOnce(*mut sys::ETrackedPropertyError) -> T,
+) -> Result<T, TrackedPropertyError> {
+    let mut error = sys::ETrackedPropertyError_TrackedProp_Success;
+    let r = f(&mut error);
+    if error == sys::ETrackedPropertyError_TrackedProp_Success {
+        Ok(r)
+    } else {
+        Err(TrackedPropertyError(error))
+    }
+}
+
+macro_rules! impl_scalar {
+    ($ty:ty, $getter:ident) => {
+        impl sealed::Sealed for $ty {}
+        impl PropertyType for $ty {
+            fn get(
+                system: &System,
+                device: TrackedDeviceIndex,
+                property: sys::ETrackedDeviceProperty,
+            ) -> Result<Self, TrackedPropertyError> {
+                scalar(|error| unsafe { system.0.$getter.unwrap()(device.0, property, error) })
+            }
+        }
+    };
+}
+
+impl_scalar!(bool, GetBoolTrackedDeviceProperty);
+impl_scalar!(f32, GetFloatTrackedDeviceProperty);
+impl_scalar!(i32, GetInt32TrackedDeviceProperty);
+impl_scalar!(u64, GetUint64TrackedDeviceProperty);
+
+impl sealed::Sealed for [[f32; 4]; 3] {}
+impl PropertyType for [[f32; 4]; 3] {
+    fn get(
+        system: &System,
+        device: TrackedDeviceIndex,
+        property: sys::ETrackedDeviceProperty,
+    ) -> Result<Self, TrackedPropertyError> {
+        scalar(|error| unsafe {
+            system.0.GetMatrix34TrackedDeviceProperty.unwrap()(device.0, property, error).m
+        })
+    }
+}
+
+impl sealed::Sealed for String {}
+impl PropertyType for String {
+    fn get(
+        system: &System,
+        device: TrackedDeviceIndex,
+        property: sys::ETrackedDeviceProperty,
+    ) -> Result<Self, TrackedPropertyError> {
+        let f = system.0.GetStringTrackedDeviceProperty.unwrap();
+        let mut error = sys::ETrackedPropertyError_TrackedProp_Success;
+        let n = unsafe { f(device.0, property, ptr::null_mut(), 0, &mut error) };
+        if n == 0 {
+            return Err(TrackedPropertyError(error));
+        }
+        let mut buffer = vec![0u8; n as usize];
+        let n = unsafe {
+            f(
+                device.0,
+                property,
+                buffer.as_mut_ptr().cast(),
+                n,
+                &mut error,
+            )
+        };
+        if error != sys::ETrackedPropertyError_TrackedProp_Success {
+            return Err(TrackedPropertyError(error));
+        }
+        buffer.truncate(n.saturating_sub(1) as usize); // Strip trailing null
+        Ok(String::from_utf8_lossy(&buffer).into_owned())
+    }
+}
+
+/// Element types of array-valued properties, read with `GetArrayTrackedDeviceProperty`.
+///
+/// Each element type has an OpenVR property type tag. The runtime refuses to read a property stored
+/// under a different tag, reporting `tracked_property_error::WRONG_DATA_TYPE`.
+pub trait ArrayElement: Sized + sealed::Sealed {
+    #[doc(hidden)]
+    type Raw: Copy + Default;
+    /// Property type tag (`k_un*PropertyTag`) the values are stored under.
+    const TAG: sys::PropertyTypeTag_t;
+    #[doc(hidden)]
+    fn from_raw(raw: Self::Raw) -> Self;
+    #[doc(hidden)]
+    fn into_raw(self) -> Self::Raw;
+}
+
+macro_rules! impl_array_element {
+    ($ty:ty, $raw:ty, $tag:expr, |$x:ident| $from:expr, |$y:ident| $into:expr) => {
+        impl ArrayElement for $ty {
+            type Raw = $raw;
+            const TAG: sys::PropertyTypeTag_t = $tag as sys::PropertyTypeTag_t;
+            fn from_raw($x: $raw) -> Self {
+                $from
+            }
+            fn into_raw(self) -> $raw {
+                let $y = self;
+                $into
+            }
+        }
+    };
+}
+
+impl sealed::Sealed for f64 {}
+impl sealed::Sealed for Matrix3x4 {}
+impl sealed::Sealed for [f32; 2] {}
+impl sealed::Sealed for [f32; 3] {}
+impl sealed::Sealed for [f32; 4] {}
+
+impl_array_element!(f32, f32, sys::k_unFloatPropertyTag, |x| x, |x| x);
+impl_array_element!(f64, f64, sys::k_unDoublePropertyTag, |x| x, |x| x);
+impl_array_element!(i32, i32, sys::k_unInt32PropertyTag, |x| x, |x| x);
+impl_array_element!(u64, u64, sys::k_unUint64PropertyTag, |x| x, |x| x);
+impl_array_element!(
+    Matrix3x4,
+    sys::HmdMatrix34_t,
+    sys::k_unHmdMatrix34PropertyTag,
+    |x| x.into(),
+    |x| x.into()
+);
+impl_array_element!(
+    [f32; 2],
+    sys::HmdVector2_t,
+    sys::k_unHmdVector2PropertyTag,
+    |x| x.v,
+    |x| sys::HmdVector2_t { v: x }
+);
+impl_array_element!(
+    [f32; 3],
+    sys::HmdVector3_t,
+    sys::k_unHmdVector3PropertyTag,
+    |x| x.v,
+    |x| sys::HmdVector3_t { v: x }
+);
+impl_array_element!(
+    [f32; 4],
+    sys::HmdVector4_t,
+    sys::k_unHmdVector4PropertyTag,
+    |x| x.v,
+    |x| sys::HmdVector4_t { v: x }
+);
+
+/// Reads an array property, checking that its size is a whole number of `T`s.
+fn array<T: ArrayElement>(
+    system: &System,
+    device: TrackedDeviceIndex,
+    property: sys::ETrackedDeviceProperty,
+) -> Result<Vec<T>, TrackedPropertyError> {
+    let f = system.0.GetArrayTrackedDeviceProperty.unwrap();
+    let size = mem::size_of::<T::Raw>();
+    let mut error = sys::ETrackedPropertyError_TrackedProp_Success;
+    let n = unsafe { f(device.0, property, T::TAG, ptr::null_mut(), 0, &mut error) };
+    if error != sys::ETrackedPropertyError_TrackedProp_Success
+        && error != sys::ETrackedPropertyError_TrackedProp_BufferTooSmall
+    {
+        return Err(TrackedPropertyError(error));
+    }
+    let count = n as usize / size;
+    if count * size != n as usize {
+        return Err(TrackedPropertyError(
+            sys::ETrackedPropertyError_TrackedProp_WrongDataType,
+        ));
+    }
+    let mut buffer = vec![T::Raw::default(); count];
+    if !buffer.is_empty() {
+        let n = unsafe {
+            f(
+                device.0,
+                property,
+                T::TAG,
+                buffer.as_mut_ptr().cast(),
+                n,
+                &mut error,
+            )
+        };
+        if error != sys::ETrackedPropertyError_TrackedProp_Success {
+            return Err(TrackedPropertyError(error));
+        }
+        buffer.truncate(n as usize / size);
+    }
+    Ok(buffer.into_iter().map(T::from_raw).collect())
+}
+
+impl<T: ArrayElement> sealed::Sealed for Vec<T> {}
+impl<T: ArrayElement> PropertyType for Vec<T> {
+    fn get(
+        system: &System,
+        device: TrackedDeviceIndex,
+        property: sys::ETrackedDeviceProperty,
+    ) -> Result<Self, TrackedPropertyError> {
+        array(system, device, property)
+    }
+}
+
+/// `_Vector3` properties are stored as a single-element array.
+impl PropertyType for [f32; 3] {
+    fn get(
+        system: &System,
+        device: TrackedDeviceIndex,
+        property: sys::ETrackedDeviceProperty,
+    ) -> Result<Self, TrackedPropertyError> {
+        match array(system, device, property)?[..] {
+            [x] => Ok(x),
+            _ => Err(TrackedPropertyError(
+                sys::ETrackedPropertyError_TrackedProp_WrongDataType,
+            )),
+        }
+    }
+}
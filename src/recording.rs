@@ -0,0 +1,800 @@
+//! Recording per-frame input to a file and replaying it without hardware, for reproducing bugs.
+//!
+//! A `Recorder` captures, once per frame, the poses returned by `wait_get_poses`, the events polled
+//! from `System`, the state of every connected controller and the data of a list of actions. The
+//! resulting `Recording` is saved in a compact binary format with `Recording::save`.
+//!
+//! With the `mock` feature, a `Replayer` feeds a `Recording` back through a
+//! [`MockRuntime`](crate::mock::MockRuntime), so the same `Input`, `System` and `Compositor` calls
+//! that read the original session see the recorded data again. The mock derives `changed` and
+//! `delta` from consecutive frames and reports an update time of 0, so replayed action data matches
+//! the recording as long as every frame was recorded.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::{mem, ptr, slice};
+
+use openvr_sys as sys;
+
+use crate::compositor::WaitPoses;
+use crate::errors::VRInputError;
+use crate::input::{
+    ActionManifest, ActionType, VRActionHandle, VRAnalogActionData, VRDigitalActionData,
+    VRInputValueHandle, VRPoseActionData,
+};
+use crate::system::EventInfo;
+use crate::{
+    ControllerAxis, ControllerState, Input, System, TrackedControllerRole, TrackedDeviceClass,
+    TrackedDeviceIndex, TrackedDevicePose, TrackingUniverseOrigin,
+};
+
+const MAGIC: &[u8; 6] = b"OVRREC";
+const VERSION: u16 = 1;
+
+/// Data of one action in a `RecordedFrame`.
+#[derive(Clone, Copy)]
+pub enum RecordedAction {
+    Digital(VRDigitalActionData),
+    Analog(VRAnalogActionData),
+    Pose(VRPoseActionData),
+}
+
+/// A device that was connected at some point during a recording.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecordedDevice {
+    pub index: TrackedDeviceIndex,
+    pub class: TrackedDeviceClass,
+    pub role: TrackedControllerRole,
+}
+
+/// An event as returned by the runtime. Kept undecoded so it can be queued again on replay.
+#[derive(Clone, Copy)]
+pub struct RecordedEvent(pub sys::VREvent_t);
+
+impl RecordedEvent {
+    pub fn info(&self) -> EventInfo {
+        self.0.into()
+    }
+}
+
+/// Input seen during one frame.
+#[derive(Clone, Default)]
+pub struct RecordedFrame {
+    /// Render poses of the connected devices.
+    pub poses: Vec<(TrackedDeviceIndex, TrackedDevicePose)>,
+    /// State of the connected devices that have one.
+    pub controllers: Vec<(TrackedDeviceIndex, ControllerState)>,
+    pub events: Vec<RecordedEvent>,
+    /// Data of each of `Recording::actions`, in the same order.
+    pub actions: Vec<RecordedAction>,
+}
+
+/// A sequence of recorded frames.
+#[derive(Clone, Default)]
+pub struct Recording {
+    /// Paths and types of the recorded actions.
+    pub actions: Vec<(String, ActionType)>,
+    pub devices: Vec<RecordedDevice>,
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// Records input frame by frame, passing it through to the application.
+///
+/// ```no_run
+/// # let context = openvr::init(openvr::ApplicationType::Scene).unwrap();
+/// # let system = context.system().unwrap();
+/// # let compositor = context.compositor().unwrap();
+/// # let mut input = context.input().unwrap();
+/// # let manifest = openvr::input::ActionManifest::new();
+/// # let mut sets = [];
+/// use openvr::recording::Recorder;
+///
+/// let origin = openvr::TrackingUniverseOrigin::Standing;
+/// let mut recorder = Recorder::from_manifest(&mut input, &manifest, origin).unwrap();
+/// # for _ in 0..1 {
+/// let poses = compositor.wait_get_poses().unwrap();
+/// input.update_actions(&mut sets).unwrap();
+/// for event in recorder.record_frame(&poses, &system, &mut input).unwrap() {
+///     // Handle `event` as if it came from `system.events()`.
+/// }
+/// # }
+/// recorder.finish().save("session.ovrrec").unwrap();
+/// ```
+pub struct Recorder {
+    recording: Recording,
+    handles: Vec<VRActionHandle>,
+    universe: TrackingUniverseOrigin,
+}
+
+impl Recorder {
+    /// Records the data of `actions`, given as paths and types. Poses are predicted for the next
+    /// frame in `universe`, like `Input::get_pose_action_data_for_next_frame`. `Vibration` and
+    /// `Skeleton` actions are skipped.
+    pub fn new(
+        input: &mut Input,
+        actions: &[(&str, ActionType)],
+        universe: TrackingUniverseOrigin,
+    ) -> Result<Self, VRInputError> {
+        let mut recorder = Recorder {
+            recording: Recording::default(),
+            handles: Vec::new(),
+            universe,
+        };
+        for &(path, ty) in actions {
+            if matches!(ty, ActionType::Vibration | ActionType::Skeleton) {
+                continue;
+            }
+            recorder.handles.push(input.get_action_handle(path)?);
+            recorder.recording.actions.push((path.to_owned(), ty));
+        }
+        Ok(recorder)
+    }
+
+    /// Records every action in `manifest`.
+    pub fn from_manifest(
+        input: &mut Input,
+        manifest: &ActionManifest,
+        universe: TrackingUniverseOrigin,
+    ) -> Result<Self, VRInputError> {
+        let actions: Vec<_> = manifest
+            .actions
+            .iter()
+            .map(|x| (x.name.as_str(), x.ty))
+            .collect();
+        Self::new(input, &actions, universe)
+    }
+
+    /// Records a frame: `poses` as returned by `Compositor::wait_get_poses`, the state of the
+    /// connected controllers, the pending events and the action data. Call it after
+    /// `Input::update_actions`.
+    ///
+    /// The events are polled from `system`, so they are returned for the application to handle.
+    pub fn record_frame(
+        &mut self,
+        poses: &WaitPoses,
+        system: &System,
+        input: &mut Input,
+    ) -> Result<Vec<EventInfo>, VRInputError> {
+        let mut frame = RecordedFrame::default();
+        for (i, pose) in poses.render.iter().enumerate() {
+            if !pose.device_is_connected() {
+                continue;
+            }
+            let index = TrackedDeviceIndex(i as sys::TrackedDeviceIndex_t);
+            if !self.recording.devices.iter().any(|x| x.index == index) {
+                self.recording.devices.push(RecordedDevice {
+                    index,
+                    class: system.tracked_device_class(index),
+                    role: system
+                        .get_controller_role_for_tracked_device_index(index)
+                        .unwrap_or(TrackedControllerRole::Invalid),
+                });
+            }
+            frame.poses.push((index, *pose));
+            if let Some(state) = system.controller_state(index) {
+                frame.controllers.push((index, state));
+            }
+        }
+        while let Some(event) = system.poll_next_raw_event() {
+            frame.events.push(RecordedEvent(event));
+        }
+        for (&action, &(_, ty)) in self.handles.iter().zip(&self.recording.actions) {
+            let none = VRInputValueHandle(0);
+            frame.actions.push(match ty {
+                ActionType::Boolean => {
+                    RecordedAction::Digital(input.get_digital_action_data(action, none)?)
+                }
+                ActionType::Pose => {
+                    RecordedAction::Pose(input.get_pose_action_data_for_next_frame(
+                        action,
+                        self.universe as sys::ETrackingUniverseOrigin,
+                        none,
+                    )?)
+                }
+                _ => RecordedAction::Analog(input.get_analog_action_data(action, none)?),
+            });
+        }
+        let events = frame.events.iter().map(RecordedEvent::info).collect();
+        self.recording.frames.push(frame);
+        Ok(events)
+    }
+
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    pub fn finish(self) -> Recording {
+        self.recording
+    }
+}
+
+impl Recording {
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut w = BufWriter::new(File::create(path)?);
+        self.write_to(&mut w)?;
+        w.flush()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read_from(&mut BufReader::new(File::open(path)?))
+    }
+
+    /// Writes the recording in a little-endian binary format. Only connected devices are stored for
+    /// each frame.
+    ///
+    /// Fails with `InvalidInput`, before writing anything, if a frame doesn't have one value per
+    /// recorded action.
+    pub fn write_to(&self, w: &mut impl Write) -> io::Result<()> {
+        if self
+            .frames
+            .iter()
+            .any(|x| x.actions.len() != self.actions.len())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame doesn't match the recorded actions",
+            ));
+        }
+        w.write_all(MAGIC)?;
+        w.write_all(&VERSION.to_le_bytes())?;
+        write_u32(w, self.actions.len() as u32)?;
+        for (path, ty) in &self.actions {
+            write_u32(w, path.len() as u32)?;
+            w.write_all(path.as_bytes())?;
+            w.write_all(&[action_type_tag(*ty)])?;
+        }
+        write_u32(w, self.devices.len() as u32)?;
+        for x in &self.devices {
+            write_u32(w, x.index.0)?;
+            write_u32(w, x.class as u32)?;
+            write_u32(w, x.role as u32)?;
+        }
+        write_u32(w, self.frames.len() as u32)?;
+        for frame in &self.frames {
+            write_u32(w, frame.poses.len() as u32)?;
+            for (index, pose) in &frame.poses {
+                write_u32(w, index.0)?;
+                write_pose(w, pose)?;
+            }
+            write_u32(w, frame.controllers.len() as u32)?;
+            for (index, state) in &frame.controllers {
+                write_u32(w, index.0)?;
+                write_u32(w, state.packet_num)?;
+                w.write_all(&state.button_pressed.to_le_bytes())?;
+                w.write_all(&state.button_touched.to_le_bytes())?;
+                write_f32s(w, state.axis.iter().flat_map(|x| [x.x, x.y]))?;
+            }
+            write_u32(w, frame.events.len() as u32)?;
+            for event in &frame.events {
+                let event = event.0;
+                let data = event.data;
+                write_u32(w, event.eventType)?;
+                write_u32(w, event.trackedDeviceIndex)?;
+                write_f32s(w, [event.eventAgeSeconds])?;
+                // The data is a C union of plain structs, so its bytes are written as they are.
+                let bytes = unsafe {
+                    slice::from_raw_parts(
+                        &data as *const _ as *const u8,
+                        mem::size_of::<sys::VREvent_Data_t>(),
+                    )
+                };
+                w.write_all(bytes)?;
+            }
+            for action in &frame.actions {
+                match *action {
+                    RecordedAction::Digital(x) => {
+                        w.write_all(&[x.active() as u8, x.state() as u8, x.changed() as u8])?;
+                        w.write_all(&x.active_origin().0.to_le_bytes())?;
+                        write_f32s(w, [x.update_time()])?;
+                    }
+                    RecordedAction::Analog(x) => {
+                        w.write_all(&[x.active() as u8])?;
+                        w.write_all(&x.active_origin().0.to_le_bytes())?;
+                        write_f32s(
+                            w,
+                            x.value()
+                                .into_iter()
+                                .chain(x.delta())
+                                .chain([x.update_time()]),
+                        )?;
+                    }
+                    RecordedAction::Pose(x) => {
+                        w.write_all(&[x.active() as u8])?;
+                        w.write_all(&x.active_origin().0.to_le_bytes())?;
+                        write_pose(w, &x.pose())?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a recording written by `write_to`.
+    pub fn read_from(r: &mut impl Read) -> io::Result<Self> {
+        let mut magic = [0; 6];
+        r.read_exact(&mut magic)?;
+        let mut version = [0; 2];
+        r.read_exact(&mut version)?;
+        if &magic != MAGIC || u16::from_le_bytes(version) != VERSION {
+            return Err(invalid("not a recording, or from an unsupported version"));
+        }
+        let mut recording = Recording::default();
+        for _ in 0..read_u32(r)? {
+            // Read through `take` rather than into a buffer of the stored length, which may be
+            // corrupt.
+            let len = read_u32(r)? as u64;
+            let mut path = Vec::new();
+            r.by_ref().take(len).read_to_end(&mut path)?;
+            if path.len() as u64 != len {
+                return Err(invalid("action path is truncated"));
+            }
+            let path = String::from_utf8(path).map_err(|_| invalid("action path is not UTF-8"))?;
+            let ty = action_type_from_tag(read_u8(r)?)?;
+            recording.actions.push((path, ty));
+        }
+        for _ in 0..read_u32(r)? {
+            recording.devices.push(RecordedDevice {
+                index: TrackedDeviceIndex(read_u32(r)?),
+                class: read_u32(r)?.into(),
+                role: read_u32(r)?.into(),
+            });
+        }
+        for _ in 0..read_u32(r)? {
+            let mut frame = RecordedFrame::default();
+            for _ in 0..read_u32(r)? {
+                frame
+                    .poses
+                    .push((TrackedDeviceIndex(read_u32(r)?), read_pose(r)?));
+            }
+            for _ in 0..read_u32(r)? {
+                let index = TrackedDeviceIndex(read_u32(r)?);
+                let packet_num = read_u32(r)?;
+                let button_pressed = read_u64(r)?;
+                let button_touched = read_u64(r)?;
+                let axis = read_f32s::<10>(r)?;
+                let axis = [0, 1, 2, 3, 4].map(|i| ControllerAxis {
+                    x: axis[2 * i],
+                    y: axis[2 * i + 1],
+                });
+                frame.controllers.push((
+                    index,
+                    ControllerState {
+                        packet_num,
+                        button_pressed,
+                        button_touched,
+                        axis,
+                    },
+                ));
+            }
+            for _ in 0..read_u32(r)? {
+                let event_type = read_u32(r)?;
+                let tracked_device_index = read_u32(r)?;
+                let [age] = read_f32s(r)?;
+                let mut bytes = vec![0; mem::size_of::<sys::VREvent_Data_t>()];
+                r.read_exact(&mut bytes)?;
+                let data =
+                    unsafe { ptr::read_unaligned(bytes.as_ptr() as *const sys::VREvent_Data_t) };
+                frame.events.push(RecordedEvent(sys::VREvent_t {
+                    eventType: event_type,
+                    trackedDeviceIndex: tracked_device_index,
+                    eventAgeSeconds: age,
+                    data,
+                }));
+            }
+            for &(_, ty) in &recording.actions {
+                frame.actions.push(match ty {
+                    ActionType::Boolean => {
+                        let [active, state, changed] = [read_u8(r)?, read_u8(r)?, read_u8(r)?];
+                        let origin = read_u64(r)?;
+                        let [update_time] = read_f32s(r)?;
+                        RecordedAction::Digital(VRDigitalActionData(
+                            sys::InputDigitalActionData_t {
+                                bActive: active != 0,
+                                activeOrigin: origin,
+                                bState: state != 0,
+                                bChanged: changed != 0,
+                                fUpdateTime: update_time,
+                            },
+                        ))
+                    }
+                    ActionType::Pose => {
+                        let active = read_u8(r)? != 0;
+                        let origin = read_u64(r)?;
+                        RecordedAction::Pose(VRPoseActionData(sys::InputPoseActionData_t {
+                            bActive: active,
+                            activeOrigin: origin,
+                            pose: read_pose(r)?.0,
+                        }))
+                    }
+                    _ => {
+                        let active = read_u8(r)? != 0;
+                        let origin = read_u64(r)?;
+                        let [x, y, z, dx, dy, dz, update_time] = read_f32s(r)?;
+                        RecordedAction::Analog(VRAnalogActionData(sys::InputAnalogActionData_t {
+                            bActive: active,
+                            activeOrigin: origin,
+                            x,
+                            y,
+                            z,
+                            deltaX: dx,
+                            deltaY: dy,
+                            deltaZ: dz,
+                            fUpdateTime: update_time,
+                        }))
+                    }
+                });
+            }
+            recording.frames.push(frame);
+        }
+        Ok(recording)
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn action_type_tag(ty: ActionType) -> u8 {
+    match ty {
+        ActionType::Boolean => 0,
+        ActionType::Vector1 => 1,
+        ActionType::Vector2 => 2,
+        ActionType::Vector3 => 3,
+        ActionType::Vibration => 4,
+        ActionType::Pose => 5,
+        ActionType::Skeleton => 6,
+    }
+}
+
+fn action_type_from_tag(tag: u8) -> io::Result<ActionType> {
+    Ok(match tag {
+        0 => ActionType::Boolean,
+        1 => ActionType::Vector1,
+        2 => ActionType::Vector2,
+        3 => ActionType::Vector3,
+        5 => ActionType::Pose,
+        _ => return Err(invalid("unsupported action type")),
+    })
+}
+
+fn write_u32(w: &mut impl Write, x: u32) -> io::Result<()> {
+    w.write_all(&x.to_le_bytes())
+}
+
+fn write_f32s(w: &mut impl Write, xs: impl IntoIterator<Item = f32>) -> io::Result<()> {
+    for x in xs {
+        w.write_all(&x.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+fn write_pose(w: &mut impl Write, pose: &TrackedDevicePose) -> io::Result<()> {
+    let m = pose.device_to_absolute_tracking();
+    write_f32s(w, m.iter().flatten().copied())?;
+    write_f32s(
+        w,
+        pose.velocity()
+            .iter()
+            .chain(pose.angular_velocity())
+            .copied(),
+    )?;
+    write_u32(w, pose.0.eTrackingResult)?;
+    w.write_all(&[pose.pose_is_valid() as u8, pose.device_is_connected() as u8])
+}
+
+fn read_u8(r: &mut impl Read) -> io::Result<u8> {
+    let mut x = [0; 1];
+    r.read_exact(&mut x)?;
+    Ok(x[0])
+}
+
+fn read_u32(r: &mut impl Read) -> io::Result<u32> {
+    let mut x = [0; 4];
+    r.read_exact(&mut x)?;
+    Ok(u32::from_le_bytes(x))
+}
+
+fn read_u64(r: &mut impl Read) -> io::Result<u64> {
+    let mut x = [0; 8];
+    r.read_exact(&mut x)?;
+    Ok(u64::from_le_bytes(x))
+}
+
+fn read_f32s<const N: usize>(r: &mut impl Read) -> io::Result<[f32; N]> {
+    let mut xs = [0.0; N];
+    for x in &mut xs {
+        *x = f32::from_bits(read_u32(r)?);
+    }
+    Ok(xs)
+}
+
+fn read_pose(r: &mut impl Read) -> io::Result<TrackedDevicePose> {
+    let m = read_f32s::<12>(r)?;
+    let [vx, vy, vz, ax, ay, az] = read_f32s(r)?;
+    let tracking_result = read_u32(r)?;
+    let [valid, connected] = [read_u8(r)?, read_u8(r)?];
+    Ok(TrackedDevicePose(sys::TrackedDevicePose_t {
+        mDeviceToAbsoluteTracking: sys::HmdMatrix34_t {
+            m: [0, 1, 2].map(|i| [m[4 * i], m[4 * i + 1], m[4 * i + 2], m[4 * i + 3]]),
+        },
+        vVelocity: sys::HmdVector3_t { v: [vx, vy, vz] },
+        vAngularVelocity: sys::HmdVector3_t { v: [ax, ay, az] },
+        eTrackingResult: tracking_result,
+        bPoseIsValid: valid != 0,
+        bDeviceIsConnected: connected != 0,
+    }))
+}
+
+#[cfg(any(test, feature = "mock"))]
+pub use self::replay::Replayer;
+
+#[cfg(any(test, feature = "mock"))]
+mod replay {
+    use super::*;
+    use crate::mock::{disconnected_pose, MockActionValue, MockDevice, MockRuntime};
+
+    /// Plays a `Recording` back through a `MockRuntime`, one frame at a time.
+    ///
+    /// Each `advance` queues the next frame's poses, controller states, events and action values,
+    /// which the application then reads through a mock `Context` as it would from the runtime:
+    /// `wait_get_poses`, polling events, `update_actions` and reading actions.
+    ///
+    /// ```
+    /// # let recording = openvr::recording::Recording::default();
+    /// use openvr::mock::MockRuntime;
+    /// use openvr::recording::Replayer;
+    ///
+    /// let runtime = MockRuntime::new();
+    /// let mut replayer = Replayer::new(recording, &runtime);
+    /// let context = openvr::Context::mock(&runtime);
+    /// let compositor = context.compositor().unwrap();
+    /// while replayer.advance() {
+    ///     let poses = compositor.wait_get_poses().unwrap();
+    ///     // ...
+    /// }
+    /// ```
+    pub struct Replayer {
+        recording: Recording,
+        runtime: MockRuntime,
+        next: usize,
+    }
+
+    impl Replayer {
+        /// Adds the recorded devices to `runtime`, replacing any at the same indices.
+        pub fn new(recording: Recording, runtime: &MockRuntime) -> Self {
+            for x in &recording.devices {
+                runtime.set_device(x.index, MockDevice::new(x.class).with_role(x.role));
+            }
+            Replayer {
+                recording,
+                runtime: runtime.clone(),
+                next: 0,
+            }
+        }
+
+        pub fn recording(&self) -> &Recording {
+            &self.recording
+        }
+
+        /// Index of the next frame `advance` will queue.
+        pub fn position(&self) -> usize {
+            self.next
+        }
+
+        /// Queues the next frame. Returns `false`, changing nothing, once all frames have been
+        /// played.
+        pub fn advance(&mut self) -> bool {
+            let Some(frame) = self.recording.frames.get(self.next) else {
+                return false;
+            };
+            self.next += 1;
+            let runtime = &self.runtime;
+            for x in &self.recording.devices {
+                let pose = frame
+                    .poses
+                    .iter()
+                    .find(|p| p.0 == x.index)
+                    .map_or_else(disconnected_pose, |p| p.1);
+                let state = frame
+                    .controllers
+                    .iter()
+                    .find(|c| c.0 == x.index)
+                    .map(|c| c.1);
+                runtime.update_device(x.index, |device| {
+                    device.pose = pose;
+                    device.controller_state = state;
+                });
+            }
+            for event in &frame.events {
+                runtime.push_raw_event(event.0);
+            }
+            for ((path, _), action) in self.recording.actions.iter().zip(&frame.actions) {
+                let (active, origin, value) = match *action {
+                    RecordedAction::Digital(x) => (
+                        x.active(),
+                        x.active_origin(),
+                        MockActionValue::Digital(x.state()),
+                    ),
+                    RecordedAction::Analog(x) => (
+                        x.active(),
+                        x.active_origin(),
+                        MockActionValue::Analog(x.value()),
+                    ),
+                    RecordedAction::Pose(x) => (
+                        x.active(),
+                        x.active_origin(),
+                        MockActionValue::Pose(x.pose()),
+                    ),
+                };
+                if active {
+                    runtime.set_action_value(path, value);
+                    runtime.set_action_origin(path, origin);
+                } else {
+                    runtime.clear_action_value(path);
+                }
+            }
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::VRActiveActionSet;
+    use crate::mock::{identity_pose, MockActionValue, MockDevice, MockRuntime};
+    use crate::system::Event;
+    use crate::Context;
+
+    fn controller(packet_num: u32) -> ControllerState {
+        ControllerState {
+            packet_num,
+            button_pressed: 1 << crate::button_id::GRIP,
+            button_touched: 0,
+            axis: [ControllerAxis { x: 0.5, y: -0.5 }; 5],
+        }
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let actions = [
+            ("/actions/main/in/jump", ActionType::Boolean),
+            ("/actions/main/in/move", ActionType::Vector2),
+            ("/actions/main/in/aim", ActionType::Pose),
+        ];
+
+        // Record two frames from a scripted runtime.
+        let runtime = MockRuntime::new();
+        let hand = runtime.add_device(
+            MockDevice::new(TrackedDeviceClass::Controller)
+                .with_role(TrackedControllerRole::LeftHand)
+                .with_pose(identity_pose()),
+        );
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        let compositor = context.compositor().unwrap();
+        let mut input = context.input().unwrap();
+        let mut sets: [VRActiveActionSet; 0] = [];
+        let mut recorder =
+            Recorder::new(&mut input, &actions, TrackingUniverseOrigin::Standing).unwrap();
+        for i in 0..2 {
+            runtime.set_controller_state(hand, controller(i));
+            runtime.set_action_value("/actions/main/in/jump", MockActionValue::Digital(i == 1));
+            runtime.set_action_value(
+                "/actions/main/in/move",
+                MockActionValue::Analog([0.25 * i as f32, 1.0, 0.0]),
+            );
+            if i == 1 {
+                runtime.set_action_value(
+                    "/actions/main/in/aim",
+                    MockActionValue::Pose(identity_pose()),
+                );
+                runtime.push_event(
+                    hand,
+                    sys::EVREventType_VREvent_TrackedDeviceActivated,
+                    unsafe { mem::zeroed() },
+                );
+            }
+            let poses = compositor.wait_get_poses().unwrap();
+            input.update_actions(&mut sets).unwrap();
+            let events = recorder.record_frame(&poses, &system, &mut input).unwrap();
+            assert_eq!(events.len(), i as usize);
+        }
+        let mut bytes = Vec::new();
+        recorder.finish().write_to(&mut bytes).unwrap();
+        drop(input);
+        drop(context);
+
+        // Replay them on an empty runtime.
+        let recording = Recording::read_from(&mut &bytes[..]).unwrap();
+        assert_eq!(recording.devices.len(), 1);
+        assert_eq!(recording.devices[0].role, TrackedControllerRole::LeftHand);
+        let runtime = MockRuntime::new();
+        let mut replayer = Replayer::new(recording, &runtime);
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        let compositor = context.compositor().unwrap();
+        let mut input = context.input().unwrap();
+        let jump = input.get_action_handle("/actions/main/in/jump").unwrap();
+        let stick = input.get_action_handle("/actions/main/in/move").unwrap();
+        let aim = input.get_action_handle("/actions/main/in/aim").unwrap();
+        let mut frame = 0;
+        while replayer.advance() {
+            let poses = compositor.wait_get_poses().unwrap();
+            input.update_actions(&mut sets).unwrap();
+            assert!(poses.render[hand.0 as usize].pose_is_valid());
+            assert_eq!(system.controller_state(hand).unwrap().packet_num, frame);
+            let digital = input
+                .get_digital_action_data(jump, VRInputValueHandle(0))
+                .unwrap();
+            assert_eq!(
+                (digital.state(), digital.changed()),
+                (frame == 1, frame == 1)
+            );
+            let analog = input
+                .get_analog_action_data(stick, VRInputValueHandle(0))
+                .unwrap();
+            assert_eq!(analog.value(), [0.25 * frame as f32, 1.0, 0.0]);
+            let pose = input
+                .get_pose_action_data_for_next_frame(
+                    aim,
+                    sys::ETrackingUniverseOrigin_TrackingUniverseStanding,
+                    VRInputValueHandle(0),
+                )
+                .unwrap();
+            assert_eq!(pose.active(), frame == 1);
+            let events: Vec<_> = system.events().collect();
+            assert_eq!(events.len(), frame as usize);
+            if frame == 1 {
+                assert!(matches!(events[0].event, Event::TrackedDeviceActivated));
+            }
+            frame += 1;
+        }
+        assert_eq!(frame, 2);
+        assert_eq!(replayer.position(), 2);
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(
+            Recording::read_from(&mut &b"OVRREC\x02\x00"[..])
+                .err()
+                .map(|e| e.kind()),
+            Some(io::ErrorKind::InvalidData)
+        );
+        // An action path claiming to be 4GB long, with three bytes behind it.
+        let truncated = b"OVRREC\x01\x00\x01\x00\x00\x00\xff\xff\xff\xffabc";
+        assert_eq!(
+            Recording::read_from(&mut &truncated[..])
+                .err()
+                .map(|e| e.kind()),
+            Some(io::ErrorKind::InvalidData)
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_frames_before_writing() {
+        let recording = Recording {
+            actions: vec![("/actions/main/in/jump".to_owned(), ActionType::Boolean)],
+            devices: Vec::new(),
+            frames: vec![RecordedFrame::default()],
+        };
+        let mut bytes = Vec::new();
+        assert_eq!(
+            recording.write_to(&mut bytes).err().map(|e| e.kind()),
+            Some(io::ErrorKind::InvalidInput)
+        );
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn rejects_paths_with_nul() {
+        let runtime = MockRuntime::new();
+        let context = Context::mock(&runtime);
+        let mut input = context.input().unwrap();
+        let actions = [("/actions/main/in/ju\0mp", ActionType::Boolean)];
+        let recorder = Recorder::new(&mut input, &actions, TrackingUniverseOrigin::Standing);
+        assert_eq!(recorder.err(), Some(VRInputError::InvalidParam));
+    }
+}
@@ -4,7 +4,7 @@ use std::{ffi::CStr, mem::MaybeUninit};
 
 
 
-impl Settings {
+impl Settings<'_> {
 
 	pub fn remove_section<'ret, 'manager: 'ret>(
 		&'manager mut self,
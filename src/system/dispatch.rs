@@ -0,0 +1,315 @@
+//! Routing of polled events to handlers registered by the parts of an application that care about
+//! them.
+
+use super::event::{Controller, Event, EventInfo, Mouse, Process, Property};
+use crate::overlay::OverlayHandle;
+use crate::{Overlay, System, TrackedDeviceIndex};
+
+/// Which events a handler registered with `EventDispatcher` receives.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EventFilter {
+    Any,
+    /// Events whose `tracked_device_index` is the given device.
+    Device(TrackedDeviceIndex),
+    /// Events polled from the given overlay, or whose payload names it (e.g. `FocusEnter`).
+    Overlay(OverlayHandle),
+}
+
+impl EventFilter {
+    fn matches(&self, source: Option<OverlayHandle>, event: &EventInfo) -> bool {
+        match *self {
+            EventFilter::Any => true,
+            EventFilter::Device(index) => event.tracked_device_index == index,
+            EventFilter::Overlay(handle) => {
+                source == Some(handle) || payload_overlay(&event.event) == Some(handle)
+            }
+        }
+    }
+}
+
+fn payload_overlay(event: &Event) -> Option<OverlayHandle> {
+    match *event {
+        Event::FocusEnter(x) | Event::FocusLeave(x) | Event::OverlayFocusChanged(x) => {
+            Some(OverlayHandle(x.overlay_handle))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ButtonEventKind {
+    Press,
+    Unpress,
+    Touch,
+    Untouch,
+}
+
+/// A `ButtonPress`, `ButtonUnpress`, `ButtonTouch` or `ButtonUntouch` event.
+#[derive(Debug, Copy, Clone)]
+pub struct ButtonEvent {
+    pub device: TrackedDeviceIndex,
+    pub kind: ButtonEventKind,
+    pub button: u32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MouseEventKind {
+    Move,
+    ButtonDown,
+    ButtonUp,
+}
+
+/// A `MouseMove`, `MouseButtonDown` or `MouseButtonUp` event.
+#[derive(Debug, Copy, Clone)]
+pub struct MouseEvent {
+    /// Overlay the event was polled from, if it was dispatched with `dispatch_overlay`.
+    pub overlay: Option<OverlayHandle>,
+    pub kind: MouseEventKind,
+    pub mouse: Mouse,
+}
+
+type Handler<'a> = Box<dyn FnMut(Option<OverlayHandle>, &EventInfo) + 'a>;
+
+/// Routes events to typed handlers, so subsystems don't each have to match on `Event`.
+///
+/// Handlers run in registration order. Events nobody asked for are dropped.
+///
+/// ```no_run
+/// # let context = openvr::init(openvr::ApplicationType::Other).unwrap();
+/// # let system = context.system().unwrap();
+/// use openvr::system::{EventDispatcher, EventFilter};
+///
+/// let mut quit = false;
+/// let mut dispatcher = EventDispatcher::new();
+/// dispatcher.on_button(EventFilter::Any, |x| println!("{:?} on {:?}", x.kind, x.device));
+/// dispatcher.on_quit(|_| quit = true);
+/// dispatcher.drain(&system);
+/// ```
+#[derive(Default)]
+pub struct EventDispatcher<'a> {
+    handlers: Vec<(EventFilter, Handler<'a>)>,
+}
+
+impl<'a> EventDispatcher<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calls `f` with every event matching `filter`.
+    pub fn on_event(
+        &mut self,
+        filter: EventFilter,
+        mut f: impl FnMut(&EventInfo) + 'a,
+    ) -> &mut Self {
+        self.handlers.push((filter, Box::new(move |_, x| f(x))));
+        self
+    }
+
+    /// Calls `f` with button press and touch events matching `filter`.
+    pub fn on_button(
+        &mut self,
+        filter: EventFilter,
+        mut f: impl FnMut(ButtonEvent) + 'a,
+    ) -> &mut Self {
+        self.handlers.push((
+            filter,
+            Box::new(move |_, x| {
+                let (kind, Controller { button }) = match x.event {
+                    Event::ButtonPress(c) => (ButtonEventKind::Press, c),
+                    Event::ButtonUnpress(c) => (ButtonEventKind::Unpress, c),
+                    Event::ButtonTouch(c) => (ButtonEventKind::Touch, c),
+                    Event::ButtonUntouch(c) => (ButtonEventKind::Untouch, c),
+                    _ => return,
+                };
+                f(ButtonEvent {
+                    device: x.tracked_device_index,
+                    kind,
+                    button,
+                })
+            }),
+        ));
+        self
+    }
+
+    /// Calls `f` with simulated mouse events matching `filter`, typically `EventFilter::Overlay`.
+    pub fn on_mouse(
+        &mut self,
+        filter: EventFilter,
+        mut f: impl FnMut(MouseEvent) + 'a,
+    ) -> &mut Self {
+        self.handlers.push((
+            filter,
+            Box::new(move |overlay, x| {
+                let (kind, mouse) = match x.event {
+                    Event::MouseMove(m) => (MouseEventKind::Move, m),
+                    Event::MouseButtonDown(m) => (MouseEventKind::ButtonDown, m),
+                    Event::MouseButtonUp(m) => (MouseEventKind::ButtonUp, m),
+                    _ => return,
+                };
+                f(MouseEvent {
+                    overlay,
+                    kind,
+                    mouse,
+                })
+            }),
+        ));
+        self
+    }
+
+    /// Calls `f` when the application is asked to quit.
+    pub fn on_quit(&mut self, mut f: impl FnMut(Process) + 'a) -> &mut Self {
+        self.handlers.push((
+            EventFilter::Any,
+            Box::new(move |_, x| {
+                if let Event::Quit(p) = x.event {
+                    f(p)
+                }
+            }),
+        ));
+        self
+    }
+
+    /// Calls `f` with property change events matching `filter`.
+    pub fn on_property_changed(
+        &mut self,
+        filter: EventFilter,
+        mut f: impl FnMut(TrackedDeviceIndex, Property) + 'a,
+    ) -> &mut Self {
+        self.handlers.push((
+            filter,
+            Box::new(move |_, x| {
+                if let Event::PropertyChanged(p) = x.event {
+                    f(x.tracked_device_index, p)
+                }
+            }),
+        ));
+        self
+    }
+
+    /// Passes an event polled from `System` to the matching handlers.
+    pub fn dispatch(&mut self, event: &EventInfo) {
+        self.dispatch_from(None, event)
+    }
+
+    /// Passes an event polled from `overlay` to the matching handlers.
+    pub fn dispatch_overlay(&mut self, overlay: OverlayHandle, event: &EventInfo) {
+        self.dispatch_from(Some(overlay), event)
+    }
+
+    fn dispatch_from(&mut self, source: Option<OverlayHandle>, event: &EventInfo) {
+        for (filter, handler) in &mut self.handlers {
+            if filter.matches(source, event) {
+                handler(source, event);
+            }
+        }
+    }
+
+    /// Dispatches every pending `System` event, returning how many there were.
+    pub fn drain(&mut self, system: &System) -> usize {
+        system.events().map(|x| self.dispatch(&x)).count()
+    }
+
+    /// Dispatches every pending event of `handle`, returning how many there were.
+    pub fn drain_overlay(&mut self, overlay: &mut Overlay, handle: OverlayHandle) -> usize {
+        overlay
+            .events(handle)
+            .map(|x| self.dispatch_overlay(handle, &x))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockDevice, MockRuntime};
+    use crate::{sys, Context, TrackedDeviceClass};
+    use std::cell::RefCell;
+
+    fn button(button: u32) -> sys::VREvent_Data_t {
+        sys::VREvent_Data_t {
+            controller: sys::VREvent_Controller_t { button },
+        }
+    }
+
+    #[test]
+    fn routes_by_device_and_overlay() {
+        let runtime = MockRuntime::new();
+        let left = runtime.add_device(MockDevice::new(TrackedDeviceClass::Controller));
+        let right = runtime.add_device(MockDevice::new(TrackedDeviceClass::Controller));
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        let mut overlay = context.overlay().unwrap();
+        let panel = overlay.create_overlay("panel", "Panel").unwrap();
+        let other = overlay.create_overlay("other", "Other").unwrap();
+
+        let buttons = RefCell::new(Vec::new());
+        let mice = RefCell::new(Vec::new());
+        let all = RefCell::new(0);
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher
+            .on_button(EventFilter::Device(left), |x| {
+                buttons.borrow_mut().push((x.kind, x.button))
+            })
+            .on_mouse(EventFilter::Overlay(panel), |x| {
+                mice.borrow_mut().push((x.overlay, x.kind))
+            })
+            .on_event(EventFilter::Any, |_| *all.borrow_mut() += 1);
+
+        runtime.push_event(left, sys::EVREventType_VREvent_ButtonPress, button(33));
+        runtime.push_event(right, sys::EVREventType_VREvent_ButtonPress, button(2));
+        runtime.push_event(left, sys::EVREventType_VREvent_ButtonUntouch, button(33));
+        assert_eq!(dispatcher.drain(&system), 3);
+        assert_eq!(runtime.pending_events(), 0);
+        assert_eq!(
+            *buttons.borrow(),
+            [(ButtonEventKind::Press, 33), (ButtonEventKind::Untouch, 33)]
+        );
+
+        let data = sys::VREvent_Data_t {
+            mouse: sys::VREvent_Mouse_t {
+                x: 0.5,
+                y: 0.5,
+                button: 0,
+                cursorIndex: 0,
+            },
+        };
+        runtime.push_overlay_event(panel, right, sys::EVREventType_VREvent_MouseMove, data);
+        runtime.push_overlay_event(other, right, sys::EVREventType_VREvent_MouseMove, data);
+        dispatcher.drain_overlay(&mut overlay, panel);
+        dispatcher.drain_overlay(&mut overlay, other);
+        assert_eq!(*mice.borrow(), [(Some(panel), MouseEventKind::Move)]);
+        assert_eq!(*all.borrow(), 5);
+    }
+
+    #[test]
+    fn events_iterator_drains_queue() {
+        let runtime = MockRuntime::new();
+        let hmd = runtime.add_device(MockDevice::new(TrackedDeviceClass::HMD));
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        runtime.push_event(
+            hmd,
+            sys::EVREventType_VREvent_IpdChanged,
+            Default::default(),
+        );
+        runtime.push_event(hmd, sys::EVREventType_VREvent_Quit, Default::default());
+        let events: Vec<_> = system
+            .events_with_pose(crate::TrackingUniverseOrigin::Standing)
+            .collect();
+        assert!(matches!(
+            events[..],
+            [
+                (_, _),
+                (
+                    EventInfo {
+                        event: Event::Quit(_),
+                        ..
+                    },
+                    _
+                )
+            ]
+        ));
+        assert!(events[1].1.pose_is_valid());
+        assert!(system.events().next().is_none());
+    }
+}
@@ -0,0 +1,839 @@
+// Generated by scripts/gen_events.py from openvr_api.json. Do not edit by hand.
+
+use super::*;
+
+#[allow(non_camel_case_types, deprecated)]
+#[derive(Debug, Copy, Clone)]
+pub enum Event {
+    TrackedDeviceActivated,
+    TrackedDeviceDeactivated,
+    TrackedDeviceUpdated,
+    TrackedDeviceUserInteractionStarted,
+    TrackedDeviceUserInteractionEnded,
+    IpdChanged(Ipd),
+    EnterStandbyMode,
+    LeaveStandbyMode,
+    TrackedDeviceRoleChanged,
+    WatchdogWakeUpRequested,
+    LensDistortionChanged,
+    PropertyChanged(Property),
+    WirelessDisconnect,
+    WirelessReconnect,
+    ButtonPress(Controller),
+    ButtonUnpress(Controller),
+    ButtonTouch(Controller),
+    ButtonUntouch(Controller),
+    Modal_Cancel,
+    MouseMove(Mouse),
+    MouseButtonDown(Mouse),
+    MouseButtonUp(Mouse),
+    FocusEnter(Overlay),
+    FocusLeave(Overlay),
+    ScrollDiscrete(Scroll),
+    TouchPadMove(TouchPadMove),
+    /// global event
+    OverlayFocusChanged(Overlay),
+    ReloadOverlays,
+    ScrollSmooth(Scroll),
+    LockMousePosition(Mouse),
+    UnlockMousePosition(Mouse),
+    #[deprecated]
+    InputFocusCaptured(Process),
+    #[deprecated]
+    InputFocusReleased(Process),
+    /// The App actually drawing the scene changed (usually to or from the compositor)
+    SceneApplicationChanged(Process),
+    InputFocusChanged(Process),
+    SceneApplicationUsingWrongGraphicsAdapter(Process),
+    /// The App that action binds reloaded for
+    ActionBindingReloaded(Process),
+    /// Sent to the scene application to request hiding render models temporarily
+    HideRenderModels,
+    /// Sent to the scene application to request restoring render model visibility
+    ShowRenderModels,
+    /// Query `IVRApplications::GetSceneApplicationState` for the new state
+    SceneApplicationStateChanged,
+    /// Called when the scene app's pipe has been closed.
+    SceneAppPipeDisconnected(Process),
+    ConsoleOpened,
+    ConsoleClosed,
+    /// Indicates that an overlay is now visible to someone and should be rendering normally.
+    /// Reflects IVROverlay::IsOverlayVisible() becoming true.
+    OverlayShown,
+    /// Indicates that an overlay is no longer visible to someone and doesn't need to render frames.
+    /// Reflects IVROverlay::IsOverlayVisible() becoming false.
+    OverlayHidden,
+    DashboardActivated,
+    DashboardDeactivated,
+    /// Send to the overlay manager
+    ResetDashboard,
+    /// Sent to overlays when a SetOverlayRaw or SetOverlayFromFile call finishes loading
+    ImageLoaded,
+    /// Sent to keyboard renderer in the dashboard to invoke it
+    ShowKeyboard,
+    /// Sent to keyboard renderer in the dashboard to hide it
+    HideKeyboard,
+    /// Sent to an overlay when IVROverlay::SetFocusOverlay is called on it
+    OverlayGamepadFocusGained,
+    /// Send to an overlay when it previously had focus and IVROverlay::SetFocusOverlay is called on
+    /// something else
+    OverlayGamepadFocusLost,
+    OverlaySharedTextureChanged,
+    /// Screenshot button combo was pressed, Dashboard should request a screenshot
+    ScreenshotTriggered,
+    /// Sent to overlays when a SetOverlayRaw or SetOverlayfromFail fails to load
+    ImageFailed,
+    DashboardOverlayCreated,
+    SwitchGamepadFocus,
+    /// Sent by vrclient application to compositor to take a screenshot
+    RequestScreenshot(Screenshot),
+    /// Sent by compositor to the application that the screenshot has been taken
+    ScreenshotTaken(Screenshot),
+    /// Sent by compositor to the application that the screenshot failed to be taken
+    ScreenshotFailed(Screenshot),
+    /// Sent by compositor to the dashboard that a completed screenshot was submitted
+    SubmitScreenshotToDashboard(Screenshot),
+    /// Sent by compositor to the dashboard that a completed screenshot was submitted
+    ScreenshotProgressToDashboard(ScreenshotProgress),
+    PrimaryDashboardDeviceChanged,
+    /// Sent by compositor whenever room-view is enabled (for scene apps only - not for construct or
+    /// transient bounds)
+    RoomViewShown,
+    /// Sent by compositor whenever room-view is disabled (for scene apps only - not for construct
+    /// or transient bounds)
+    RoomViewHidden,
+    ShowUI(ShowUi),
+    ShowDevTools(ShowDevTools),
+    DesktopViewUpdating,
+    DesktopViewReady,
+    StartDashboard,
+    ElevatePrism,
+    /// The overlay's close button is pressed.
+    OverlayClosed,
+    /// Sent when a dashboard thumbnail image changes
+    DashboardThumbChanged,
+    /// Sent when any known desktop related overlay is visible
+    DesktopMightBeVisible,
+    /// Sent when all known desktop related overlays are hidden
+    DesktopMightBeHidden,
+    /// Sent when the set of capabilities common between both Steam and SteamVR have changed.
+    MutualSteamCapabilitiesChanged,
+    /// An OpenVR overlay of any sort was created.
+    OverlayCreated(Overlay),
+    /// An OpenVR overlay of any sort was destroyed.
+    OverlayDestroyed(Overlay),
+    /// An OpenVR overlay's name changed.
+    OverlayNameChanged(Overlay),
+    TrackingRecordingStarted,
+    TrackingRecordingStopped,
+    SetTrackingRecordingPath,
+    Notification_Shown(Notification),
+    Notification_Hidden(Notification),
+    Notification_BeginInteraction(Notification),
+    Notification_Destroyed(Notification),
+    Quit(Process),
+    ProcessQuit(Process),
+    QuitAcknowledged(Process),
+    /// The driver has requested that SteamVR shut down
+    DriverRequestedQuit,
+    /// A driver or other component wants the user to restart SteamVR
+    RestartRequested,
+    InvalidateSwapTextureSets,
+    /// vrserver asks vrlink to disconnect
+    RequestDisconnectWirelessHMD,
+    /// this will never happen with the new chaperone system
+    ChaperoneDataHasChanged,
+    ChaperoneUniverseHasChanged(Chaperone),
+    /// this will never happen with the new chaperone system
+    ChaperoneTempDataHasChanged,
+    ChaperoneSettingsHaveChanged,
+    SeatedZeroPoseReset(SeatedZeroPoseReset),
+    /// Sent when the process needs to reload any cached data it retrieved from VRChaperone()
+    ChaperoneFlushCache,
+    /// Triggered by CVRChaperoneClient::RoomSetupStarting
+    ChaperoneRoomSetupStarting,
+    /// Triggered by CVRChaperoneClient::CommitWorkingCopy (formerly
+    /// VREvent_ChaperoneRoomSetupFinished)
+    ChaperoneRoomSetupCommitted,
+    StandingZeroPoseReset(SeatedZeroPoseReset),
+    AudioSettingsHaveChanged,
+    BackgroundSettingHasChanged,
+    CameraSettingsHaveChanged,
+    ReprojectionSettingHasChanged,
+    ModelSkinSettingsHaveChanged,
+    EnvironmentSettingsHaveChanged,
+    PowerSettingsHaveChanged,
+    EnableHomeAppSettingsHaveChanged,
+    SteamVRSectionSettingChanged,
+    LighthouseSectionSettingChanged,
+    NullSectionSettingChanged,
+    UserInterfaceSectionSettingChanged,
+    NotificationsSectionSettingChanged,
+    KeyboardSectionSettingChanged,
+    PerfSectionSettingChanged,
+    DashboardSectionSettingChanged,
+    WebInterfaceSectionSettingChanged,
+    TrackersSectionSettingChanged,
+    LastKnownSectionSettingChanged,
+    DismissedWarningsSectionSettingChanged,
+    GpuSpeedSectionSettingChanged,
+    WindowsMRSectionSettingChanged,
+    OtherSectionSettingChanged,
+    AnyDriverSettingsChanged,
+    StatusUpdate(Status),
+    WebInterface_InstallDriverCompleted,
+    MCImageUpdated,
+    FirmwareUpdateStarted,
+    FirmwareUpdateFinished,
+    /// Sent only to the overlay it closed for, or globally if it was closed for a scene app
+    #[deprecated]
+    KeyboardClosed(Keyboard),
+    /// Sent on keyboard input. Warning: event type appears as both global event and overlay event
+    KeyboardCharInput(Keyboard),
+    /// Sent when DONE button clicked on keyboard. Warning: event type appears as both global event
+    /// and overlay event
+    KeyboardDone(Keyboard),
+    /// Sent globally when the keyboard is opened. data.keyboard.overlayHandle is who it was opened
+    /// for (scene app if k_ulOverlayHandleInvalid)
+    KeyboardOpened_Global(Keyboard),
+    /// Sent globally when the keyboard is closed. data.keyboard.overlayHandle is who it was opened
+    /// for (scene app if k_ulOverlayHandleInvalid)
+    KeyboardClosed_Global(Keyboard),
+    ApplicationListUpdated,
+    ApplicationMimeTypeLoad(ApplicationLaunch),
+    ProcessConnected(Process),
+    ProcessDisconnected(Process),
+    Compositor_ChaperoneBoundsShown,
+    Compositor_ChaperoneBoundsHidden,
+    Compositor_DisplayDisconnected,
+    Compositor_DisplayReconnected,
+    Compositor_HDCPError(HdcpError),
+    Compositor_ApplicationNotResponding,
+    Compositor_ApplicationResumed,
+    Compositor_OutOfVideoMemory,
+    /// k_pch_SteamVR_PreferredRefreshRate
+    Compositor_DisplayModeNotSupported,
+    Compositor_StageOverrideReady,
+    Compositor_RequestDisconnectReconnect,
+    TrackedCamera_StartVideoStream,
+    TrackedCamera_StopVideoStream,
+    TrackedCamera_PauseVideoStream,
+    TrackedCamera_ResumeVideoStream,
+    TrackedCamera_EditingSurface(EditingCameraSurface),
+    PerformanceTest_EnableCapture,
+    PerformanceTest_DisableCapture,
+    PerformanceTest_FidelityLevel(PerformanceTest),
+    MessageOverlay_Closed(MessageOverlay),
+    MessageOverlayCloseRequested,
+    Input_HapticVibration(HapticVibration),
+    Input_BindingLoadFailed(InputBindingLoad),
+    Input_BindingLoadSuccessful(InputBindingLoad),
+    Input_ActionManifestReloaded,
+    Input_ActionManifestLoadFailed(InputActionManifestLoad),
+    Input_ProgressUpdate(ProgressUpdate),
+    Input_TrackerActivated,
+    Input_BindingsUpdated,
+    Input_BindingSubscriptionChanged,
+    /// broadcast
+    SpatialAnchors_PoseUpdated(SpatialAnchor),
+    /// broadcast
+    SpatialAnchors_DescriptorUpdated(SpatialAnchor),
+    /// sent to specific driver
+    SpatialAnchors_RequestPoseUpdate(SpatialAnchor),
+    /// sent to specific driver
+    SpatialAnchors_RequestDescriptorUpdate(SpatialAnchor),
+    /// user or system initiated generation of a system report. broadcast
+    SystemReport_Started,
+    Monitor_ShowHeadsetView(Process),
+    Monitor_HideHeadsetView(Process),
+    Audio_SetSpeakersVolume(AudioVolumeControl),
+    Audio_SetSpeakersMute(AudioMuteControl),
+    Audio_SetMicrophoneVolume(AudioVolumeControl),
+    Audio_SetMicrophoneMute(AudioMuteControl),
+    /// Number of RenderModels in the system has changed
+    RenderModel_CountChanged,
+
+    VendorSpecific(sys::EVREventType),
+    Unknown(sys::EVREventType),
+}
+
+impl Event {
+    pub(super) fn from_sys(ty: sys::EVREventType, data: &sys::VREvent_Data_t) -> Self {
+        use self::Event::*;
+
+        #[allow(deprecated)]
+        match ty {
+            sys::EVREventType_VREvent_TrackedDeviceActivated => TrackedDeviceActivated,
+            sys::EVREventType_VREvent_TrackedDeviceDeactivated => TrackedDeviceDeactivated,
+            sys::EVREventType_VREvent_TrackedDeviceUpdated => TrackedDeviceUpdated,
+            sys::EVREventType_VREvent_TrackedDeviceUserInteractionStarted => {
+                TrackedDeviceUserInteractionStarted
+            }
+            sys::EVREventType_VREvent_TrackedDeviceUserInteractionEnded => {
+                TrackedDeviceUserInteractionEnded
+            }
+            sys::EVREventType_VREvent_IpdChanged => IpdChanged(get(data)),
+            sys::EVREventType_VREvent_EnterStandbyMode => EnterStandbyMode,
+            sys::EVREventType_VREvent_LeaveStandbyMode => LeaveStandbyMode,
+            sys::EVREventType_VREvent_TrackedDeviceRoleChanged => TrackedDeviceRoleChanged,
+            sys::EVREventType_VREvent_WatchdogWakeUpRequested => WatchdogWakeUpRequested,
+            sys::EVREventType_VREvent_LensDistortionChanged => LensDistortionChanged,
+            sys::EVREventType_VREvent_PropertyChanged => PropertyChanged(get(data)),
+            sys::EVREventType_VREvent_WirelessDisconnect => WirelessDisconnect,
+            sys::EVREventType_VREvent_WirelessReconnect => WirelessReconnect,
+            sys::EVREventType_VREvent_ButtonPress => ButtonPress(get(data)),
+            sys::EVREventType_VREvent_ButtonUnpress => ButtonUnpress(get(data)),
+            sys::EVREventType_VREvent_ButtonTouch => ButtonTouch(get(data)),
+            sys::EVREventType_VREvent_ButtonUntouch => ButtonUntouch(get(data)),
+            sys::EVREventType_VREvent_Modal_Cancel => Modal_Cancel,
+            sys::EVREventType_VREvent_MouseMove => MouseMove(get(data)),
+            sys::EVREventType_VREvent_MouseButtonDown => MouseButtonDown(get(data)),
+            sys::EVREventType_VREvent_MouseButtonUp => MouseButtonUp(get(data)),
+            sys::EVREventType_VREvent_FocusEnter => FocusEnter(get(data)),
+            sys::EVREventType_VREvent_FocusLeave => FocusLeave(get(data)),
+            sys::EVREventType_VREvent_ScrollDiscrete => ScrollDiscrete(get(data)),
+            sys::EVREventType_VREvent_TouchPadMove => TouchPadMove(get(data)),
+            sys::EVREventType_VREvent_OverlayFocusChanged => OverlayFocusChanged(get(data)),
+            sys::EVREventType_VREvent_ReloadOverlays => ReloadOverlays,
+            sys::EVREventType_VREvent_ScrollSmooth => ScrollSmooth(get(data)),
+            sys::EVREventType_VREvent_LockMousePosition => LockMousePosition(get(data)),
+            sys::EVREventType_VREvent_UnlockMousePosition => UnlockMousePosition(get(data)),
+            sys::EVREventType_VREvent_InputFocusCaptured => InputFocusCaptured(get(data)),
+            sys::EVREventType_VREvent_InputFocusReleased => InputFocusReleased(get(data)),
+            sys::EVREventType_VREvent_SceneApplicationChanged => SceneApplicationChanged(get(data)),
+            sys::EVREventType_VREvent_InputFocusChanged => InputFocusChanged(get(data)),
+            sys::EVREventType_VREvent_SceneApplicationUsingWrongGraphicsAdapter => {
+                SceneApplicationUsingWrongGraphicsAdapter(get(data))
+            }
+            sys::EVREventType_VREvent_ActionBindingReloaded => ActionBindingReloaded(get(data)),
+            sys::EVREventType_VREvent_HideRenderModels => HideRenderModels,
+            sys::EVREventType_VREvent_ShowRenderModels => ShowRenderModels,
+            sys::EVREventType_VREvent_SceneApplicationStateChanged => SceneApplicationStateChanged,
+            sys::EVREventType_VREvent_SceneAppPipeDisconnected => {
+                SceneAppPipeDisconnected(get(data))
+            }
+            sys::EVREventType_VREvent_ConsoleOpened => ConsoleOpened,
+            sys::EVREventType_VREvent_ConsoleClosed => ConsoleClosed,
+            sys::EVREventType_VREvent_OverlayShown => OverlayShown,
+            sys::EVREventType_VREvent_OverlayHidden => OverlayHidden,
+            sys::EVREventType_VREvent_DashboardActivated => DashboardActivated,
+            sys::EVREventType_VREvent_DashboardDeactivated => DashboardDeactivated,
+            sys::EVREventType_VREvent_ResetDashboard => ResetDashboard,
+            sys::EVREventType_VREvent_ImageLoaded => ImageLoaded,
+            sys::EVREventType_VREvent_ShowKeyboard => ShowKeyboard,
+            sys::EVREventType_VREvent_HideKeyboard => HideKeyboard,
+            sys::EVREventType_VREvent_OverlayGamepadFocusGained => OverlayGamepadFocusGained,
+            sys::EVREventType_VREvent_OverlayGamepadFocusLost => OverlayGamepadFocusLost,
+            sys::EVREventType_VREvent_OverlaySharedTextureChanged => OverlaySharedTextureChanged,
+            sys::EVREventType_VREvent_ScreenshotTriggered => ScreenshotTriggered,
+            sys::EVREventType_VREvent_ImageFailed => ImageFailed,
+            sys::EVREventType_VREvent_DashboardOverlayCreated => DashboardOverlayCreated,
+            sys::EVREventType_VREvent_SwitchGamepadFocus => SwitchGamepadFocus,
+            sys::EVREventType_VREvent_RequestScreenshot => RequestScreenshot(get(data)),
+            sys::EVREventType_VREvent_ScreenshotTaken => ScreenshotTaken(get(data)),
+            sys::EVREventType_VREvent_ScreenshotFailed => ScreenshotFailed(get(data)),
+            sys::EVREventType_VREvent_SubmitScreenshotToDashboard => {
+                SubmitScreenshotToDashboard(get(data))
+            }
+            sys::EVREventType_VREvent_ScreenshotProgressToDashboard => {
+                ScreenshotProgressToDashboard(get(data))
+            }
+            sys::EVREventType_VREvent_PrimaryDashboardDeviceChanged => {
+                PrimaryDashboardDeviceChanged
+            }
+            sys::EVREventType_VREvent_RoomViewShown => RoomViewShown,
+            sys::EVREventType_VREvent_RoomViewHidden => RoomViewHidden,
+            sys::EVREventType_VREvent_ShowUI => ShowUI(get(data)),
+            sys::EVREventType_VREvent_ShowDevTools => ShowDevTools(get(data)),
+            sys::EVREventType_VREvent_DesktopViewUpdating => DesktopViewUpdating,
+            sys::EVREventType_VREvent_DesktopViewReady => DesktopViewReady,
+            sys::EVREventType_VREvent_StartDashboard => StartDashboard,
+            sys::EVREventType_VREvent_ElevatePrism => ElevatePrism,
+            sys::EVREventType_VREvent_OverlayClosed => OverlayClosed,
+            sys::EVREventType_VREvent_DashboardThumbChanged => DashboardThumbChanged,
+            sys::EVREventType_VREvent_DesktopMightBeVisible => DesktopMightBeVisible,
+            sys::EVREventType_VREvent_DesktopMightBeHidden => DesktopMightBeHidden,
+            sys::EVREventType_VREvent_MutualSteamCapabilitiesChanged => {
+                MutualSteamCapabilitiesChanged
+            }
+            sys::EVREventType_VREvent_OverlayCreated => OverlayCreated(get(data)),
+            sys::EVREventType_VREvent_OverlayDestroyed => OverlayDestroyed(get(data)),
+            sys::EVREventType_VREvent_OverlayNameChanged => OverlayNameChanged(get(data)),
+            sys::EVREventType_VREvent_TrackingRecordingStarted => TrackingRecordingStarted,
+            sys::EVREventType_VREvent_TrackingRecordingStopped => TrackingRecordingStopped,
+            sys::EVREventType_VREvent_SetTrackingRecordingPath => SetTrackingRecordingPath,
+            sys::EVREventType_VREvent_Notification_Shown => Notification_Shown(get(data)),
+            sys::EVREventType_VREvent_Notification_Hidden => Notification_Hidden(get(data)),
+            sys::EVREventType_VREvent_Notification_BeginInteraction => {
+                Notification_BeginInteraction(get(data))
+            }
+            sys::EVREventType_VREvent_Notification_Destroyed => Notification_Destroyed(get(data)),
+            sys::EVREventType_VREvent_Quit => Quit(get(data)),
+            sys::EVREventType_VREvent_ProcessQuit => ProcessQuit(get(data)),
+            sys::EVREventType_VREvent_QuitAcknowledged => QuitAcknowledged(get(data)),
+            sys::EVREventType_VREvent_DriverRequestedQuit => DriverRequestedQuit,
+            sys::EVREventType_VREvent_RestartRequested => RestartRequested,
+            sys::EVREventType_VREvent_InvalidateSwapTextureSets => InvalidateSwapTextureSets,
+            sys::EVREventType_VREvent_RequestDisconnectWirelessHMD => RequestDisconnectWirelessHMD,
+            sys::EVREventType_VREvent_ChaperoneDataHasChanged => ChaperoneDataHasChanged,
+            sys::EVREventType_VREvent_ChaperoneUniverseHasChanged => {
+                ChaperoneUniverseHasChanged(get(data))
+            }
+            sys::EVREventType_VREvent_ChaperoneTempDataHasChanged => ChaperoneTempDataHasChanged,
+            sys::EVREventType_VREvent_ChaperoneSettingsHaveChanged => ChaperoneSettingsHaveChanged,
+            sys::EVREventType_VREvent_SeatedZeroPoseReset => SeatedZeroPoseReset(get(data)),
+            sys::EVREventType_VREvent_ChaperoneFlushCache => ChaperoneFlushCache,
+            sys::EVREventType_VREvent_ChaperoneRoomSetupStarting => ChaperoneRoomSetupStarting,
+            sys::EVREventType_VREvent_ChaperoneRoomSetupCommitted => ChaperoneRoomSetupCommitted,
+            sys::EVREventType_VREvent_StandingZeroPoseReset => StandingZeroPoseReset(get(data)),
+            sys::EVREventType_VREvent_AudioSettingsHaveChanged => AudioSettingsHaveChanged,
+            sys::EVREventType_VREvent_BackgroundSettingHasChanged => BackgroundSettingHasChanged,
+            sys::EVREventType_VREvent_CameraSettingsHaveChanged => CameraSettingsHaveChanged,
+            sys::EVREventType_VREvent_ReprojectionSettingHasChanged => {
+                ReprojectionSettingHasChanged
+            }
+            sys::EVREventType_VREvent_ModelSkinSettingsHaveChanged => ModelSkinSettingsHaveChanged,
+            sys::EVREventType_VREvent_EnvironmentSettingsHaveChanged => {
+                EnvironmentSettingsHaveChanged
+            }
+            sys::EVREventType_VREvent_PowerSettingsHaveChanged => PowerSettingsHaveChanged,
+            sys::EVREventType_VREvent_EnableHomeAppSettingsHaveChanged => {
+                EnableHomeAppSettingsHaveChanged
+            }
+            sys::EVREventType_VREvent_SteamVRSectionSettingChanged => SteamVRSectionSettingChanged,
+            sys::EVREventType_VREvent_LighthouseSectionSettingChanged => {
+                LighthouseSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_NullSectionSettingChanged => NullSectionSettingChanged,
+            sys::EVREventType_VREvent_UserInterfaceSectionSettingChanged => {
+                UserInterfaceSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_NotificationsSectionSettingChanged => {
+                NotificationsSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_KeyboardSectionSettingChanged => {
+                KeyboardSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_PerfSectionSettingChanged => PerfSectionSettingChanged,
+            sys::EVREventType_VREvent_DashboardSectionSettingChanged => {
+                DashboardSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_WebInterfaceSectionSettingChanged => {
+                WebInterfaceSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_TrackersSectionSettingChanged => {
+                TrackersSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_LastKnownSectionSettingChanged => {
+                LastKnownSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_DismissedWarningsSectionSettingChanged => {
+                DismissedWarningsSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_GpuSpeedSectionSettingChanged => {
+                GpuSpeedSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_WindowsMRSectionSettingChanged => {
+                WindowsMRSectionSettingChanged
+            }
+            sys::EVREventType_VREvent_OtherSectionSettingChanged => OtherSectionSettingChanged,
+            sys::EVREventType_VREvent_AnyDriverSettingsChanged => AnyDriverSettingsChanged,
+            sys::EVREventType_VREvent_StatusUpdate => StatusUpdate(get(data)),
+            sys::EVREventType_VREvent_WebInterface_InstallDriverCompleted => {
+                WebInterface_InstallDriverCompleted
+            }
+            sys::EVREventType_VREvent_MCImageUpdated => MCImageUpdated,
+            sys::EVREventType_VREvent_FirmwareUpdateStarted => FirmwareUpdateStarted,
+            sys::EVREventType_VREvent_FirmwareUpdateFinished => FirmwareUpdateFinished,
+            sys::EVREventType_VREvent_KeyboardClosed => KeyboardClosed(get(data)),
+            sys::EVREventType_VREvent_KeyboardCharInput => KeyboardCharInput(get(data)),
+            sys::EVREventType_VREvent_KeyboardDone => KeyboardDone(get(data)),
+            sys::EVREventType_VREvent_KeyboardOpened_Global => KeyboardOpened_Global(get(data)),
+            sys::EVREventType_VREvent_KeyboardClosed_Global => KeyboardClosed_Global(get(data)),
+            sys::EVREventType_VREvent_ApplicationListUpdated => ApplicationListUpdated,
+            sys::EVREventType_VREvent_ApplicationMimeTypeLoad => ApplicationMimeTypeLoad(get(data)),
+            sys::EVREventType_VREvent_ProcessConnected => ProcessConnected(get(data)),
+            sys::EVREventType_VREvent_ProcessDisconnected => ProcessDisconnected(get(data)),
+            sys::EVREventType_VREvent_Compositor_ChaperoneBoundsShown => {
+                Compositor_ChaperoneBoundsShown
+            }
+            sys::EVREventType_VREvent_Compositor_ChaperoneBoundsHidden => {
+                Compositor_ChaperoneBoundsHidden
+            }
+            sys::EVREventType_VREvent_Compositor_DisplayDisconnected => {
+                Compositor_DisplayDisconnected
+            }
+            sys::EVREventType_VREvent_Compositor_DisplayReconnected => {
+                Compositor_DisplayReconnected
+            }
+            sys::EVREventType_VREvent_Compositor_HDCPError => Compositor_HDCPError(get(data)),
+            sys::EVREventType_VREvent_Compositor_ApplicationNotResponding => {
+                Compositor_ApplicationNotResponding
+            }
+            sys::EVREventType_VREvent_Compositor_ApplicationResumed => {
+                Compositor_ApplicationResumed
+            }
+            sys::EVREventType_VREvent_Compositor_OutOfVideoMemory => Compositor_OutOfVideoMemory,
+            sys::EVREventType_VREvent_Compositor_DisplayModeNotSupported => {
+                Compositor_DisplayModeNotSupported
+            }
+            sys::EVREventType_VREvent_Compositor_StageOverrideReady => {
+                Compositor_StageOverrideReady
+            }
+            sys::EVREventType_VREvent_Compositor_RequestDisconnectReconnect => {
+                Compositor_RequestDisconnectReconnect
+            }
+            sys::EVREventType_VREvent_TrackedCamera_StartVideoStream => {
+                TrackedCamera_StartVideoStream
+            }
+            sys::EVREventType_VREvent_TrackedCamera_StopVideoStream => {
+                TrackedCamera_StopVideoStream
+            }
+            sys::EVREventType_VREvent_TrackedCamera_PauseVideoStream => {
+                TrackedCamera_PauseVideoStream
+            }
+            sys::EVREventType_VREvent_TrackedCamera_ResumeVideoStream => {
+                TrackedCamera_ResumeVideoStream
+            }
+            sys::EVREventType_VREvent_TrackedCamera_EditingSurface => {
+                TrackedCamera_EditingSurface(get(data))
+            }
+            sys::EVREventType_VREvent_PerformanceTest_EnableCapture => {
+                PerformanceTest_EnableCapture
+            }
+            sys::EVREventType_VREvent_PerformanceTest_DisableCapture => {
+                PerformanceTest_DisableCapture
+            }
+            sys::EVREventType_VREvent_PerformanceTest_FidelityLevel => {
+                PerformanceTest_FidelityLevel(get(data))
+            }
+            sys::EVREventType_VREvent_MessageOverlay_Closed => MessageOverlay_Closed(get(data)),
+            sys::EVREventType_VREvent_MessageOverlayCloseRequested => MessageOverlayCloseRequested,
+            sys::EVREventType_VREvent_Input_HapticVibration => Input_HapticVibration(get(data)),
+            sys::EVREventType_VREvent_Input_BindingLoadFailed => Input_BindingLoadFailed(get(data)),
+            sys::EVREventType_VREvent_Input_BindingLoadSuccessful => {
+                Input_BindingLoadSuccessful(get(data))
+            }
+            sys::EVREventType_VREvent_Input_ActionManifestReloaded => Input_ActionManifestReloaded,
+            sys::EVREventType_VREvent_Input_ActionManifestLoadFailed => {
+                Input_ActionManifestLoadFailed(get(data))
+            }
+            sys::EVREventType_VREvent_Input_ProgressUpdate => Input_ProgressUpdate(get(data)),
+            sys::EVREventType_VREvent_Input_TrackerActivated => Input_TrackerActivated,
+            sys::EVREventType_VREvent_Input_BindingsUpdated => Input_BindingsUpdated,
+            sys::EVREventType_VREvent_Input_BindingSubscriptionChanged => {
+                Input_BindingSubscriptionChanged
+            }
+            sys::EVREventType_VREvent_SpatialAnchors_PoseUpdated => {
+                SpatialAnchors_PoseUpdated(get(data))
+            }
+            sys::EVREventType_VREvent_SpatialAnchors_DescriptorUpdated => {
+                SpatialAnchors_DescriptorUpdated(get(data))
+            }
+            sys::EVREventType_VREvent_SpatialAnchors_RequestPoseUpdate => {
+                SpatialAnchors_RequestPoseUpdate(get(data))
+            }
+            sys::EVREventType_VREvent_SpatialAnchors_RequestDescriptorUpdate => {
+                SpatialAnchors_RequestDescriptorUpdate(get(data))
+            }
+            sys::EVREventType_VREvent_SystemReport_Started => SystemReport_Started,
+            sys::EVREventType_VREvent_Monitor_ShowHeadsetView => Monitor_ShowHeadsetView(get(data)),
+            sys::EVREventType_VREvent_Monitor_HideHeadsetView => Monitor_HideHeadsetView(get(data)),
+            sys::EVREventType_VREvent_Audio_SetSpeakersVolume => Audio_SetSpeakersVolume(get(data)),
+            sys::EVREventType_VREvent_Audio_SetSpeakersMute => Audio_SetSpeakersMute(get(data)),
+            sys::EVREventType_VREvent_Audio_SetMicrophoneVolume => {
+                Audio_SetMicrophoneVolume(get(data))
+            }
+            sys::EVREventType_VREvent_Audio_SetMicrophoneMute => Audio_SetMicrophoneMute(get(data)),
+            sys::EVREventType_VREvent_RenderModel_CountChanged => RenderModel_CountChanged,
+            x if (sys::EVREventType_VREvent_VendorSpecific_Reserved_Start
+                ..=sys::EVREventType_VREvent_VendorSpecific_Reserved_End)
+                .contains(&x) =>
+            {
+                VendorSpecific(x)
+            }
+            x => Unknown(x),
+        }
+    }
+
+    /// The `EVREventType` this event was decoded from.
+    pub fn event_type(&self) -> sys::EVREventType {
+        use self::Event::*;
+
+        #[allow(deprecated)]
+        match *self {
+            TrackedDeviceActivated => sys::EVREventType_VREvent_TrackedDeviceActivated,
+            TrackedDeviceDeactivated => sys::EVREventType_VREvent_TrackedDeviceDeactivated,
+            TrackedDeviceUpdated => sys::EVREventType_VREvent_TrackedDeviceUpdated,
+            TrackedDeviceUserInteractionStarted => {
+                sys::EVREventType_VREvent_TrackedDeviceUserInteractionStarted
+            }
+            TrackedDeviceUserInteractionEnded => {
+                sys::EVREventType_VREvent_TrackedDeviceUserInteractionEnded
+            }
+            IpdChanged(_) => sys::EVREventType_VREvent_IpdChanged,
+            EnterStandbyMode => sys::EVREventType_VREvent_EnterStandbyMode,
+            LeaveStandbyMode => sys::EVREventType_VREvent_LeaveStandbyMode,
+            TrackedDeviceRoleChanged => sys::EVREventType_VREvent_TrackedDeviceRoleChanged,
+            WatchdogWakeUpRequested => sys::EVREventType_VREvent_WatchdogWakeUpRequested,
+            LensDistortionChanged => sys::EVREventType_VREvent_LensDistortionChanged,
+            PropertyChanged(_) => sys::EVREventType_VREvent_PropertyChanged,
+            WirelessDisconnect => sys::EVREventType_VREvent_WirelessDisconnect,
+            WirelessReconnect => sys::EVREventType_VREvent_WirelessReconnect,
+            ButtonPress(_) => sys::EVREventType_VREvent_ButtonPress,
+            ButtonUnpress(_) => sys::EVREventType_VREvent_ButtonUnpress,
+            ButtonTouch(_) => sys::EVREventType_VREvent_ButtonTouch,
+            ButtonUntouch(_) => sys::EVREventType_VREvent_ButtonUntouch,
+            Modal_Cancel => sys::EVREventType_VREvent_Modal_Cancel,
+            MouseMove(_) => sys::EVREventType_VREvent_MouseMove,
+            MouseButtonDown(_) => sys::EVREventType_VREvent_MouseButtonDown,
+            MouseButtonUp(_) => sys::EVREventType_VREvent_MouseButtonUp,
+            FocusEnter(_) => sys::EVREventType_VREvent_FocusEnter,
+            FocusLeave(_) => sys::EVREventType_VREvent_FocusLeave,
+            ScrollDiscrete(_) => sys::EVREventType_VREvent_ScrollDiscrete,
+            TouchPadMove(_) => sys::EVREventType_VREvent_TouchPadMove,
+            OverlayFocusChanged(_) => sys::EVREventType_VREvent_OverlayFocusChanged,
+            ReloadOverlays => sys::EVREventType_VREvent_ReloadOverlays,
+            ScrollSmooth(_) => sys::EVREventType_VREvent_ScrollSmooth,
+            LockMousePosition(_) => sys::EVREventType_VREvent_LockMousePosition,
+            UnlockMousePosition(_) => sys::EVREventType_VREvent_UnlockMousePosition,
+            InputFocusCaptured(_) => sys::EVREventType_VREvent_InputFocusCaptured,
+            InputFocusReleased(_) => sys::EVREventType_VREvent_InputFocusReleased,
+            SceneApplicationChanged(_) => sys::EVREventType_VREvent_SceneApplicationChanged,
+            InputFocusChanged(_) => sys::EVREventType_VREvent_InputFocusChanged,
+            SceneApplicationUsingWrongGraphicsAdapter(_) => {
+                sys::EVREventType_VREvent_SceneApplicationUsingWrongGraphicsAdapter
+            }
+            ActionBindingReloaded(_) => sys::EVREventType_VREvent_ActionBindingReloaded,
+            HideRenderModels => sys::EVREventType_VREvent_HideRenderModels,
+            ShowRenderModels => sys::EVREventType_VREvent_ShowRenderModels,
+            SceneApplicationStateChanged => sys::EVREventType_VREvent_SceneApplicationStateChanged,
+            SceneAppPipeDisconnected(_) => sys::EVREventType_VREvent_SceneAppPipeDisconnected,
+            ConsoleOpened => sys::EVREventType_VREvent_ConsoleOpened,
+            ConsoleClosed => sys::EVREventType_VREvent_ConsoleClosed,
+            OverlayShown => sys::EVREventType_VREvent_OverlayShown,
+            OverlayHidden => sys::EVREventType_VREvent_OverlayHidden,
+            DashboardActivated => sys::EVREventType_VREvent_DashboardActivated,
+            DashboardDeactivated => sys::EVREventType_VREvent_DashboardDeactivated,
+            ResetDashboard => sys::EVREventType_VREvent_ResetDashboard,
+            ImageLoaded => sys::EVREventType_VREvent_ImageLoaded,
+            ShowKeyboard => sys::EVREventType_VREvent_ShowKeyboard,
+            HideKeyboard => sys::EVREventType_VREvent_HideKeyboard,
+            OverlayGamepadFocusGained => sys::EVREventType_VREvent_OverlayGamepadFocusGained,
+            OverlayGamepadFocusLost => sys::EVREventType_VREvent_OverlayGamepadFocusLost,
+            OverlaySharedTextureChanged => sys::EVREventType_VREvent_OverlaySharedTextureChanged,
+            ScreenshotTriggered => sys::EVREventType_VREvent_ScreenshotTriggered,
+            ImageFailed => sys::EVREventType_VREvent_ImageFailed,
+            DashboardOverlayCreated => sys::EVREventType_VREvent_DashboardOverlayCreated,
+            SwitchGamepadFocus => sys::EVREventType_VREvent_SwitchGamepadFocus,
+            RequestScreenshot(_) => sys::EVREventType_VREvent_RequestScreenshot,
+            ScreenshotTaken(_) => sys::EVREventType_VREvent_ScreenshotTaken,
+            ScreenshotFailed(_) => sys::EVREventType_VREvent_ScreenshotFailed,
+            SubmitScreenshotToDashboard(_) => sys::EVREventType_VREvent_SubmitScreenshotToDashboard,
+            ScreenshotProgressToDashboard(_) => {
+                sys::EVREventType_VREvent_ScreenshotProgressToDashboard
+            }
+            PrimaryDashboardDeviceChanged => {
+                sys::EVREventType_VREvent_PrimaryDashboardDeviceChanged
+            }
+            RoomViewShown => sys::EVREventType_VREvent_RoomViewShown,
+            RoomViewHidden => sys::EVREventType_VREvent_RoomViewHidden,
+            ShowUI(_) => sys::EVREventType_VREvent_ShowUI,
+            ShowDevTools(_) => sys::EVREventType_VREvent_ShowDevTools,
+            DesktopViewUpdating => sys::EVREventType_VREvent_DesktopViewUpdating,
+            DesktopViewReady => sys::EVREventType_VREvent_DesktopViewReady,
+            StartDashboard => sys::EVREventType_VREvent_StartDashboard,
+            ElevatePrism => sys::EVREventType_VREvent_ElevatePrism,
+            OverlayClosed => sys::EVREventType_VREvent_OverlayClosed,
+            DashboardThumbChanged => sys::EVREventType_VREvent_DashboardThumbChanged,
+            DesktopMightBeVisible => sys::EVREventType_VREvent_DesktopMightBeVisible,
+            DesktopMightBeHidden => sys::EVREventType_VREvent_DesktopMightBeHidden,
+            MutualSteamCapabilitiesChanged => {
+                sys::EVREventType_VREvent_MutualSteamCapabilitiesChanged
+            }
+            OverlayCreated(_) => sys::EVREventType_VREvent_OverlayCreated,
+            OverlayDestroyed(_) => sys::EVREventType_VREvent_OverlayDestroyed,
+            OverlayNameChanged(_) => sys::EVREventType_VREvent_OverlayNameChanged,
+            TrackingRecordingStarted => sys::EVREventType_VREvent_TrackingRecordingStarted,
+            TrackingRecordingStopped => sys::EVREventType_VREvent_TrackingRecordingStopped,
+            SetTrackingRecordingPath => sys::EVREventType_VREvent_SetTrackingRecordingPath,
+            Notification_Shown(_) => sys::EVREventType_VREvent_Notification_Shown,
+            Notification_Hidden(_) => sys::EVREventType_VREvent_Notification_Hidden,
+            Notification_BeginInteraction(_) => {
+                sys::EVREventType_VREvent_Notification_BeginInteraction
+            }
+            Notification_Destroyed(_) => sys::EVREventType_VREvent_Notification_Destroyed,
+            Quit(_) => sys::EVREventType_VREvent_Quit,
+            ProcessQuit(_) => sys::EVREventType_VREvent_ProcessQuit,
+            QuitAcknowledged(_) => sys::EVREventType_VREvent_QuitAcknowledged,
+            DriverRequestedQuit => sys::EVREventType_VREvent_DriverRequestedQuit,
+            RestartRequested => sys::EVREventType_VREvent_RestartRequested,
+            InvalidateSwapTextureSets => sys::EVREventType_VREvent_InvalidateSwapTextureSets,
+            RequestDisconnectWirelessHMD => sys::EVREventType_VREvent_RequestDisconnectWirelessHMD,
+            ChaperoneDataHasChanged => sys::EVREventType_VREvent_ChaperoneDataHasChanged,
+            ChaperoneUniverseHasChanged(_) => sys::EVREventType_VREvent_ChaperoneUniverseHasChanged,
+            ChaperoneTempDataHasChanged => sys::EVREventType_VREvent_ChaperoneTempDataHasChanged,
+            ChaperoneSettingsHaveChanged => sys::EVREventType_VREvent_ChaperoneSettingsHaveChanged,
+            SeatedZeroPoseReset(_) => sys::EVREventType_VREvent_SeatedZeroPoseReset,
+            ChaperoneFlushCache => sys::EVREventType_VREvent_ChaperoneFlushCache,
+            ChaperoneRoomSetupStarting => sys::EVREventType_VREvent_ChaperoneRoomSetupStarting,
+            ChaperoneRoomSetupCommitted => sys::EVREventType_VREvent_ChaperoneRoomSetupCommitted,
+            StandingZeroPoseReset(_) => sys::EVREventType_VREvent_StandingZeroPoseReset,
+            AudioSettingsHaveChanged => sys::EVREventType_VREvent_AudioSettingsHaveChanged,
+            BackgroundSettingHasChanged => sys::EVREventType_VREvent_BackgroundSettingHasChanged,
+            CameraSettingsHaveChanged => sys::EVREventType_VREvent_CameraSettingsHaveChanged,
+            ReprojectionSettingHasChanged => {
+                sys::EVREventType_VREvent_ReprojectionSettingHasChanged
+            }
+            ModelSkinSettingsHaveChanged => sys::EVREventType_VREvent_ModelSkinSettingsHaveChanged,
+            EnvironmentSettingsHaveChanged => {
+                sys::EVREventType_VREvent_EnvironmentSettingsHaveChanged
+            }
+            PowerSettingsHaveChanged => sys::EVREventType_VREvent_PowerSettingsHaveChanged,
+            EnableHomeAppSettingsHaveChanged => {
+                sys::EVREventType_VREvent_EnableHomeAppSettingsHaveChanged
+            }
+            SteamVRSectionSettingChanged => sys::EVREventType_VREvent_SteamVRSectionSettingChanged,
+            LighthouseSectionSettingChanged => {
+                sys::EVREventType_VREvent_LighthouseSectionSettingChanged
+            }
+            NullSectionSettingChanged => sys::EVREventType_VREvent_NullSectionSettingChanged,
+            UserInterfaceSectionSettingChanged => {
+                sys::EVREventType_VREvent_UserInterfaceSectionSettingChanged
+            }
+            NotificationsSectionSettingChanged => {
+                sys::EVREventType_VREvent_NotificationsSectionSettingChanged
+            }
+            KeyboardSectionSettingChanged => {
+                sys::EVREventType_VREvent_KeyboardSectionSettingChanged
+            }
+            PerfSectionSettingChanged => sys::EVREventType_VREvent_PerfSectionSettingChanged,
+            DashboardSectionSettingChanged => {
+                sys::EVREventType_VREvent_DashboardSectionSettingChanged
+            }
+            WebInterfaceSectionSettingChanged => {
+                sys::EVREventType_VREvent_WebInterfaceSectionSettingChanged
+            }
+            TrackersSectionSettingChanged => {
+                sys::EVREventType_VREvent_TrackersSectionSettingChanged
+            }
+            LastKnownSectionSettingChanged => {
+                sys::EVREventType_VREvent_LastKnownSectionSettingChanged
+            }
+            DismissedWarningsSectionSettingChanged => {
+                sys::EVREventType_VREvent_DismissedWarningsSectionSettingChanged
+            }
+            GpuSpeedSectionSettingChanged => {
+                sys::EVREventType_VREvent_GpuSpeedSectionSettingChanged
+            }
+            WindowsMRSectionSettingChanged => {
+                sys::EVREventType_VREvent_WindowsMRSectionSettingChanged
+            }
+            OtherSectionSettingChanged => sys::EVREventType_VREvent_OtherSectionSettingChanged,
+            AnyDriverSettingsChanged => sys::EVREventType_VREvent_AnyDriverSettingsChanged,
+            StatusUpdate(_) => sys::EVREventType_VREvent_StatusUpdate,
+            WebInterface_InstallDriverCompleted => {
+                sys::EVREventType_VREvent_WebInterface_InstallDriverCompleted
+            }
+            MCImageUpdated => sys::EVREventType_VREvent_MCImageUpdated,
+            FirmwareUpdateStarted => sys::EVREventType_VREvent_FirmwareUpdateStarted,
+            FirmwareUpdateFinished => sys::EVREventType_VREvent_FirmwareUpdateFinished,
+            KeyboardClosed(_) => sys::EVREventType_VREvent_KeyboardClosed,
+            KeyboardCharInput(_) => sys::EVREventType_VREvent_KeyboardCharInput,
+            KeyboardDone(_) => sys::EVREventType_VREvent_KeyboardDone,
+            KeyboardOpened_Global(_) => sys::EVREventType_VREvent_KeyboardOpened_Global,
+            KeyboardClosed_Global(_) => sys::EVREventType_VREvent_KeyboardClosed_Global,
+            ApplicationListUpdated => sys::EVREventType_VREvent_ApplicationListUpdated,
+            ApplicationMimeTypeLoad(_) => sys::EVREventType_VREvent_ApplicationMimeTypeLoad,
+            ProcessConnected(_) => sys::EVREventType_VREvent_ProcessConnected,
+            ProcessDisconnected(_) => sys::EVREventType_VREvent_ProcessDisconnected,
+            Compositor_ChaperoneBoundsShown => {
+                sys::EVREventType_VREvent_Compositor_ChaperoneBoundsShown
+            }
+            Compositor_ChaperoneBoundsHidden => {
+                sys::EVREventType_VREvent_Compositor_ChaperoneBoundsHidden
+            }
+            Compositor_DisplayDisconnected => {
+                sys::EVREventType_VREvent_Compositor_DisplayDisconnected
+            }
+            Compositor_DisplayReconnected => {
+                sys::EVREventType_VREvent_Compositor_DisplayReconnected
+            }
+            Compositor_HDCPError(_) => sys::EVREventType_VREvent_Compositor_HDCPError,
+            Compositor_ApplicationNotResponding => {
+                sys::EVREventType_VREvent_Compositor_ApplicationNotResponding
+            }
+            Compositor_ApplicationResumed => {
+                sys::EVREventType_VREvent_Compositor_ApplicationResumed
+            }
+            Compositor_OutOfVideoMemory => sys::EVREventType_VREvent_Compositor_OutOfVideoMemory,
+            Compositor_DisplayModeNotSupported => {
+                sys::EVREventType_VREvent_Compositor_DisplayModeNotSupported
+            }
+            Compositor_StageOverrideReady => {
+                sys::EVREventType_VREvent_Compositor_StageOverrideReady
+            }
+            Compositor_RequestDisconnectReconnect => {
+                sys::EVREventType_VREvent_Compositor_RequestDisconnectReconnect
+            }
+            TrackedCamera_StartVideoStream => {
+                sys::EVREventType_VREvent_TrackedCamera_StartVideoStream
+            }
+            TrackedCamera_StopVideoStream => {
+                sys::EVREventType_VREvent_TrackedCamera_StopVideoStream
+            }
+            TrackedCamera_PauseVideoStream => {
+                sys::EVREventType_VREvent_TrackedCamera_PauseVideoStream
+            }
+            TrackedCamera_ResumeVideoStream => {
+                sys::EVREventType_VREvent_TrackedCamera_ResumeVideoStream
+            }
+            TrackedCamera_EditingSurface(_) => {
+                sys::EVREventType_VREvent_TrackedCamera_EditingSurface
+            }
+            PerformanceTest_EnableCapture => {
+                sys::EVREventType_VREvent_PerformanceTest_EnableCapture
+            }
+            PerformanceTest_DisableCapture => {
+                sys::EVREventType_VREvent_PerformanceTest_DisableCapture
+            }
+            PerformanceTest_FidelityLevel(_) => {
+                sys::EVREventType_VREvent_PerformanceTest_FidelityLevel
+            }
+            MessageOverlay_Closed(_) => sys::EVREventType_VREvent_MessageOverlay_Closed,
+            MessageOverlayCloseRequested => sys::EVREventType_VREvent_MessageOverlayCloseRequested,
+            Input_HapticVibration(_) => sys::EVREventType_VREvent_Input_HapticVibration,
+            Input_BindingLoadFailed(_) => sys::EVREventType_VREvent_Input_BindingLoadFailed,
+            Input_BindingLoadSuccessful(_) => sys::EVREventType_VREvent_Input_BindingLoadSuccessful,
+            Input_ActionManifestReloaded => sys::EVREventType_VREvent_Input_ActionManifestReloaded,
+            Input_ActionManifestLoadFailed(_) => {
+                sys::EVREventType_VREvent_Input_ActionManifestLoadFailed
+            }
+            Input_ProgressUpdate(_) => sys::EVREventType_VREvent_Input_ProgressUpdate,
+            Input_TrackerActivated => sys::EVREventType_VREvent_Input_TrackerActivated,
+            Input_BindingsUpdated => sys::EVREventType_VREvent_Input_BindingsUpdated,
+            Input_BindingSubscriptionChanged => {
+                sys::EVREventType_VREvent_Input_BindingSubscriptionChanged
+            }
+            SpatialAnchors_PoseUpdated(_) => sys::EVREventType_VREvent_SpatialAnchors_PoseUpdated,
+            SpatialAnchors_DescriptorUpdated(_) => {
+                sys::EVREventType_VREvent_SpatialAnchors_DescriptorUpdated
+            }
+            SpatialAnchors_RequestPoseUpdate(_) => {
+                sys::EVREventType_VREvent_SpatialAnchors_RequestPoseUpdate
+            }
+            SpatialAnchors_RequestDescriptorUpdate(_) => {
+                sys::EVREventType_VREvent_SpatialAnchors_RequestDescriptorUpdate
+            }
+            SystemReport_Started => sys::EVREventType_VREvent_SystemReport_Started,
+            Monitor_ShowHeadsetView(_) => sys::EVREventType_VREvent_Monitor_ShowHeadsetView,
+            Monitor_HideHeadsetView(_) => sys::EVREventType_VREvent_Monitor_HideHeadsetView,
+            Audio_SetSpeakersVolume(_) => sys::EVREventType_VREvent_Audio_SetSpeakersVolume,
+            Audio_SetSpeakersMute(_) => sys::EVREventType_VREvent_Audio_SetSpeakersMute,
+            Audio_SetMicrophoneVolume(_) => sys::EVREventType_VREvent_Audio_SetMicrophoneVolume,
+            Audio_SetMicrophoneMute(_) => sys::EVREventType_VREvent_Audio_SetMicrophoneMute,
+            RenderModel_CountChanged => sys::EVREventType_VREvent_RenderModel_CountChanged,
+            VendorSpecific(x) | Unknown(x) => x,
+        }
+    }
+}
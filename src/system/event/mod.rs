@@ -0,0 +1,577 @@
+#[derive(Debug, Copy, Clone)]
+pub struct EventInfo {
+    /// The tracked device index of the event. For events that aren't connected to a tracked device
+    /// this is k_unTrackedDeviceIndexInvalid
+    pub tracked_device_index: TrackedDeviceIndex,
+
+    /// The age of the event in seconds.
+    pub age: f32,
+
+    /// More information about the event.
+    pub event: Event,
+}
+
+impl From<sys::VREvent_t> for EventInfo {
+    #[allow(unused_unsafe)]
+    fn from(x: sys::VREvent_t) -> Self {
+        // workaround unaligned reference to a field of a packed struct (E0793) on linux/mac
+        // https://doc.rust-lang.org/error_codes/E0793.html
+        let data = x.data;
+        EventInfo {
+            tracked_device_index: tracking::TrackedDeviceIndex(x.trackedDeviceIndex),
+            age: x.eventAgeSeconds,
+            event: Event::from_sys(x.eventType as sys::EVREventType, &data),
+        }
+    }
+}
+
+/// Iterator over pending events, returned by `System::events`.
+pub struct Events<'a> {
+    pub(super) system: &'a System<'a>,
+}
+
+impl Iterator for Events<'_> {
+    type Item = EventInfo;
+    fn next(&mut self) -> Option<EventInfo> {
+        self.system.poll_next_event()
+    }
+}
+
+/// Iterator over pending events and the pose of the device each concerns, returned by
+/// `System::events_with_pose`.
+pub struct EventsWithPose<'a> {
+    pub(super) system: &'a System<'a>,
+    pub(super) origin: TrackingUniverseOrigin,
+}
+
+impl Iterator for EventsWithPose<'_> {
+    type Item = (EventInfo, TrackedDevicePose);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.system.poll_next_event_with_pose(self.origin)
+    }
+}
+
+trait FromEventData {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self;
+}
+
+fn get<T: FromEventData>(x: &sys::VREvent_Data_t) -> T {
+    unsafe { T::from_event_data(x) }
+}
+
+use super::*;
+
+mod generated;
+pub use self::generated::Event;
+
+#[derive(Debug, Copy, Clone)]
+/// Controller button events
+pub struct Controller {
+    pub button: u32,
+}
+
+impl FromEventData for Controller {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Controller {
+            button: x.controller.button,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// Simulated mouse events in overlay space
+pub struct Mouse {
+    /// Absolute position in texcoords, with the origin at the bottom left.
+    pub position: (f32, f32),
+    /// Bitfield
+    pub button: u32,
+    /// Which laser pointer is driving the cursor, for overlays that accept several.
+    pub cursor_index: u32,
+}
+
+impl FromEventData for Mouse {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Mouse {
+            position: (x.mouse.x, x.mouse.y),
+            button: x.mouse.button,
+            cursor_index: x.mouse.cursorIndex,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// Simulated mouse wheel scroll in overlay space
+///
+/// Coordinates are fraction of the touchpad traversed since last scroll event.
+pub struct Scroll {
+    pub delta: (f32, f32),
+    pub viewportscale: f32,
+    pub cursor_index: u32,
+}
+
+impl FromEventData for Scroll {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Scroll {
+            delta: (x.scroll.xdelta, x.scroll.ydelta),
+            viewportscale: x.scroll.viewportscale,
+            cursor_index: x.scroll.cursorIndex,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// When in mouse input mode you can receive data from the touchpad, these events are only sent if
+/// the user's finger is on the touchpad (or just released from it)
+pub struct TouchPadMove {
+    /// if the user's finger is detected on the touch pad
+    pub finger_down: bool,
+    /// How long the finger has been down in seconds
+    pub seconds_finger_down: f32,
+    /// Starting finger position (so you can do some basic swipe stuff)
+    pub first: (f32, f32),
+    /// This is the raw sampled coordinate without deadzoning
+    pub raw: (f32, f32),
+}
+
+impl FromEventData for TouchPadMove {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        TouchPadMove {
+            finger_down: x.touchPadMove.bFingerDown,
+            seconds_finger_down: x.touchPadMove.flSecondsFingerDown,
+            first: (x.touchPadMove.fValueXFirst, x.touchPadMove.fValueYFirst),
+            raw: (x.touchPadMove.fValueXRaw, x.touchPadMove.fValueYRaw),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// notification related events. Details will still change at this point
+pub struct Notification {
+    pub user_value: u64,
+    pub notification_id: u32,
+}
+
+impl FromEventData for Notification {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Notification {
+            user_value: x.notification.ulUserValue,
+            notification_id: x.notification.notificationId,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Process {
+    pub pid: u32,
+    pub old_pid: u32,
+    pub forced: bool,
+    /// If the associated event was triggered by a connection loss
+    pub connection_lost: bool,
+}
+
+impl FromEventData for Process {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Process {
+            pid: x.process.pid,
+            old_pid: x.process.oldPid,
+            forced: x.process.bForced,
+            connection_lost: x.process.bConnectionLost,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Overlay {
+    pub overlay_handle: u64,
+    pub device_path: u64,
+    pub memory_block_id: u64,
+    pub cursor_index: u32,
+}
+
+impl FromEventData for Overlay {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Overlay {
+            overlay_handle: x.overlay.overlayHandle,
+            device_path: x.overlay.devicePath,
+            memory_block_id: x.overlay.memoryBlockId,
+            cursor_index: x.overlay.cursorIndex,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Status {
+    pub status_state: u32,
+}
+
+impl FromEventData for Status {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Status {
+            status_state: x.status.statusState,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Keyboard {
+    pub new_input: [u8; 8],
+    pub user_value: u64,
+    /// Overlay the keyboard was opened for, or `k_ulOverlayHandleInvalid` for the scene application
+    pub overlay_handle: u64,
+}
+
+impl FromEventData for Keyboard {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        let overlay_handle = x.keyboard.overlayHandle;
+        let x = &*(&x.keyboard as *const _ as *const sys::VREvent_Keyboard_t_real);
+        Keyboard {
+            new_input: *(x.cNewInput.as_ptr() as *const _),
+            user_value: x.uUserValue,
+            overlay_handle,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Ipd {
+    pub ipd_meters: f32,
+}
+
+impl FromEventData for Ipd {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Ipd {
+            ipd_meters: x.ipd.ipdMeters,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Chaperone {
+    /// No longer filled in by the runtime
+    pub previous_universe: u64,
+    pub current_universe: u64,
+}
+
+impl FromEventData for Chaperone {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Chaperone {
+            previous_universe: x.chaperone.m_nPreviousUniverse_deprecated,
+            current_universe: x.chaperone.m_nCurrentUniverse,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Property {
+    pub container: PropertyContainerHandle,
+    pub property: TrackedDeviceProperty,
+}
+
+impl FromEventData for Property {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Property {
+            container: x.property.container,
+            property: tracking::TrackedDeviceProperty(x.property.prop),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PerformanceTest {
+    pub fidelity_level: u32,
+}
+
+impl FromEventData for PerformanceTest {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        PerformanceTest {
+            fidelity_level: x.performanceTest.m_nFidelityLevel,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SeatedZeroPoseReset {
+    pub reset_by_system_menu: bool,
+}
+
+impl FromEventData for SeatedZeroPoseReset {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        SeatedZeroPoseReset {
+            reset_by_system_menu: x.seatedZeroPoseReset.bResetBySystemMenu,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Screenshot {
+    pub handle: sys::ScreenshotHandle_t,
+    /// An `EVRScreenshotType`
+    pub ty: u32,
+}
+
+impl FromEventData for Screenshot {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Screenshot {
+            handle: x.screenshot.handle,
+            ty: x.screenshot.type_,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ScreenshotProgress {
+    pub progress: f32,
+}
+
+impl FromEventData for ScreenshotProgress {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        ScreenshotProgress {
+            progress: x.screenshotProgress.progress,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ApplicationLaunch {
+    pub pid: u32,
+    /// Pass to `IVRApplications::GetApplicationLaunchArguments`
+    pub args_handle: u32,
+}
+
+impl FromEventData for ApplicationLaunch {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        ApplicationLaunch {
+            pid: x.applicationLaunch.pid,
+            args_handle: x.applicationLaunch.unArgsHandle,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct EditingCameraSurface {
+    pub overlay_handle: u64,
+    pub visual_mode: u32,
+}
+
+impl FromEventData for EditingCameraSurface {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        EditingCameraSurface {
+            overlay_handle: x.cameraSurface.overlayHandle,
+            visual_mode: x.cameraSurface.nVisualMode,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct MessageOverlay {
+    /// A `VRMessageOverlayResponse`
+    pub response: u32,
+}
+
+impl FromEventData for MessageOverlay {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        MessageOverlay {
+            response: x.messageOverlay.unVRMessageOverlayResponse,
+        }
+    }
+}
+
+/// A haptic pulse requested through an output action
+#[derive(Debug, Copy, Clone)]
+pub struct HapticVibration {
+    /// Property container of the device or display
+    pub container_handle: u64,
+    /// Component the pulse is for
+    pub component_handle: u64,
+    pub duration_seconds: f32,
+    pub frequency: f32,
+    pub amplitude: f32,
+}
+
+impl FromEventData for HapticVibration {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        HapticVibration {
+            container_handle: x.hapticVibration.containerHandle,
+            component_handle: x.hapticVibration.componentHandle,
+            duration_seconds: x.hapticVibration.fDurationSeconds,
+            frequency: x.hapticVibration.fFrequency,
+            amplitude: x.hapticVibration.fAmplitude,
+        }
+    }
+}
+
+/// Not sent with any event type in the current headers; kept for completeness of `VREvent_Data_t`.
+#[derive(Debug, Copy, Clone)]
+pub struct WebConsole {
+    pub web_console_handle: sys::WebConsoleHandle_t,
+}
+
+impl FromEventData for WebConsole {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        WebConsole {
+            web_console_handle: x.webConsole.webConsoleHandle,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct InputBindingLoad {
+    pub app_container: PropertyContainerHandle,
+    pub path_message: u64,
+    pub path_url: u64,
+    pub path_controller_type: u64,
+}
+
+impl FromEventData for InputBindingLoad {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        InputBindingLoad {
+            app_container: x.inputBinding.ulAppContainer,
+            path_message: x.inputBinding.pathMessage,
+            path_url: x.inputBinding.pathUrl,
+            path_controller_type: x.inputBinding.pathControllerType,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct InputActionManifestLoad {
+    pub path_app_key: u64,
+    pub path_message: u64,
+    pub path_message_param: u64,
+    pub path_manifest_path: u64,
+}
+
+impl FromEventData for InputActionManifestLoad {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        InputActionManifestLoad {
+            path_app_key: x.actionManifest.pathAppKey,
+            path_message: x.actionManifest.pathMessage,
+            path_message_param: x.actionManifest.pathMessageParam,
+            path_manifest_path: x.actionManifest.pathManifestPath,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SpatialAnchor {
+    pub handle: sys::SpatialAnchorHandle_t,
+}
+
+impl FromEventData for SpatialAnchor {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        SpatialAnchor {
+            handle: x.spatialAnchor.unHandle,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ProgressUpdate {
+    pub application_property_container: u64,
+    pub path_device: u64,
+    pub path_input_source: u64,
+    pub path_progress_action: u64,
+    pub path_icon: u64,
+    pub progress: f32,
+}
+
+impl FromEventData for ProgressUpdate {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        ProgressUpdate {
+            application_property_container: x.progressUpdate.ulApplicationPropertyContainer,
+            path_device: x.progressUpdate.pathDevice,
+            path_input_source: x.progressUpdate.pathInputSource,
+            path_progress_action: x.progressUpdate.pathProgressAction,
+            path_icon: x.progressUpdate.pathIcon,
+            progress: x.progressUpdate.fProgress,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ShowUi {
+    pub ty: sys::EShowUIType,
+}
+
+impl FromEventData for ShowUi {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        ShowUi { ty: x.showUi.eType }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ShowDevTools {
+    pub browser_identifier: i32,
+}
+
+impl FromEventData for ShowDevTools {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        ShowDevTools {
+            browser_identifier: x.showDevTools.nBrowserIdentifier,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct HdcpError {
+    pub code: sys::EHDCPError,
+}
+
+impl FromEventData for HdcpError {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        HdcpError {
+            code: x.hdcpError.eCode,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct AudioVolumeControl {
+    pub volume_level: f32,
+}
+
+impl FromEventData for AudioVolumeControl {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        AudioVolumeControl {
+            volume_level: x.audioVolumeControl.fVolumeLevel,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct AudioMuteControl {
+    pub mute: bool,
+}
+
+impl FromEventData for AudioMuteControl {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        AudioMuteControl {
+            mute: x.audioMuteControl.bMute,
+        }
+    }
+}
+
+pub use sys::PropertyContainerHandle_t as PropertyContainerHandle;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_type_round_trips() {
+        let data = sys::VREvent_Data_t::default();
+        for ty in (0..2300).chain(10000..10002) {
+            let event = Event::from_sys(ty, &data);
+            assert_eq!(event.event_type(), ty, "{:?}", event);
+        }
+        assert!(matches!(
+            Event::from_sys(sys::EVREventType_VREvent_Input_HapticVibration, &data),
+            Event::Input_HapticVibration(_)
+        ));
+        assert!(matches!(
+            Event::from_sys(10001, &data),
+            Event::VendorSpecific(10001)
+        ));
+        assert!(matches!(Event::from_sys(0, &data), Event::Unknown(0)));
+    }
+}
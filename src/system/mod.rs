@@ -1,19 +1,27 @@
 //! The `System` interface provides access to display configuration information, tracking data, controller state,
 //! events, and device properties. It is the main interface of OpenVR.
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 use std::{mem, ptr, slice};
 
 use openvr_sys as sys;
 
+pub mod dispatch;
 pub mod event;
+pub mod registry;
 
 use super::*;
 
-pub use self::event::{Event, EventInfo};
+pub use self::dispatch::{
+    ButtonEvent, ButtonEventKind, EventDispatcher, EventFilter, MouseEvent, MouseEventKind,
+};
+pub use self::event::{Event, EventInfo, Events, EventsWithPose};
+pub use self::registry::{DeviceChange, DeviceId, DeviceInfo, DeviceRegistry};
+use crate::controller::{AxisType, Button, ButtonMask};
+use crate::property::{Property, PropertyType};
 
-impl System {
+impl System<'_> {
     /// Provides the game with the minimum size that it should use for its offscreen render target to minimize pixel
     /// stretching. This size is matched with the projection matrix and distortion function and will change from display
     /// to display depending on resolution, distortion, and field of view.
@@ -111,16 +119,7 @@ impl System {
     }
 
     pub fn tracked_device_class(&self, index: TrackedDeviceIndex) -> TrackedDeviceClass {
-        use self::TrackedDeviceClass::*;
-        match unsafe { self.0.GetTrackedDeviceClass.unwrap()(index.0) } {
-            sys::ETrackedDeviceClass_TrackedDeviceClass_Invalid => Invalid,
-            sys::ETrackedDeviceClass_TrackedDeviceClass_HMD => HMD,
-            sys::ETrackedDeviceClass_TrackedDeviceClass_Controller => Controller,
-            sys::ETrackedDeviceClass_TrackedDeviceClass_GenericTracker => GenericTracker,
-            sys::ETrackedDeviceClass_TrackedDeviceClass_TrackingReference => TrackingReference,
-            sys::ETrackedDeviceClass_TrackedDeviceClass_DisplayRedirect => DisplayRedirect,
-            _ => Invalid,
-        }
+        unsafe { self.0.GetTrackedDeviceClass.unwrap()(index.0) }.into()
     }
 
     pub fn is_tracked_device_connected(&self, index: TrackedDeviceIndex) -> bool {
@@ -150,6 +149,11 @@ impl System {
     pub fn poll_next_event(
         &self,
     ) -> Option<EventInfo> {
+        self.poll_next_raw_event().map(Into::into)
+    }
+
+    /// Like `poll_next_event`, without decoding the event.
+    pub(crate) fn poll_next_raw_event(&self) -> Option<sys::VREvent_t> {
         let mut event = mem::MaybeUninit::uninit();
         if unsafe {
             self.0.PollNextEvent.unwrap()(
@@ -158,12 +162,33 @@ impl System {
             )
 
         } {
-            unsafe { Some(event.assume_init().into()) }
+            unsafe { Some(event.assume_init()) }
         } else {
             None
         }
     }
 
+    /// Drains the pending events, polling `poll_next_event` until the queue is empty.
+    ///
+    /// ```no_run
+    /// # let context = openvr::init(openvr::ApplicationType::Other).unwrap();
+    /// # let system = context.system().unwrap();
+    /// for event in system.events() {
+    ///     println!("{:?}", event.event);
+    /// }
+    /// ```
+    pub fn events(&self) -> Events<'_> {
+        Events { system: self }
+    }
+
+    /// Like `events`, with the pose of the device each event concerns, in `origin` space.
+    pub fn events_with_pose(&self, origin: TrackingUniverseOrigin) -> EventsWithPose<'_> {
+        EventsWithPose {
+            system: self,
+            origin,
+        }
+    }
+
     /// Computes the distortion caused by the optics
     /// Gets the result of a single distortion value for use in a distortion map. Input UVs are in a single eye's viewport, and output UVs are for the source render target in the distortion shader.
     pub fn compute_distortion(&self, eye: Eye, u: f32, v: f32) -> Option<DistortionCoordinates> {
@@ -244,129 +269,83 @@ impl System {
         }
     }
 
-    pub fn bool_tracked_device_property(
+    /// Reads a property of a tracked device as the type carried by `key`.
+    ///
+    /// ```no_run
+    /// # let context = openvr::init(openvr::ApplicationType::Other).unwrap();
+    /// # let system = context.system().unwrap();
+    /// use openvr::{property, tracked_device_index};
+    /// let model = system.property(tracked_device_index::HMD, property::ModelNumber_String);
+    /// ```
+    pub fn property<T: PropertyType>(
         &self,
         device: TrackedDeviceIndex,
-        property: TrackedDeviceProperty,
-    ) -> Result<bool, TrackedPropertyError> {
-        let mut error: mem::MaybeUninit<TrackedPropertyError> = mem::MaybeUninit::uninit();
-        let r = unsafe {
-            self.0.GetBoolTrackedDeviceProperty.unwrap()(
-                device.0,
-                property.0,
-                error.as_mut_ptr() as *mut sys::TrackedPropertyError,
-            )
-        };
+        key: Property<T>,
+    ) -> Result<T, TrackedPropertyError> {
+        T::get(self, device, key.raw())
+    }
 
-        let error = unsafe { error.assume_init() };
+    /// Identifies the physical device currently at `device` by its serial number.
+    ///
+    /// Unlike `TrackedDeviceIndex`, which is reused once a device disconnects, the result stays the
+    /// same across reconnects.
+    pub fn device_id(&self, device: TrackedDeviceIndex) -> Result<DeviceId, TrackedPropertyError> {
+        self.property(device, crate::property::SerialNumber_String)
+            .map(DeviceId::new)
+    }
 
-        if error == tracked_property_error::SUCCESS {
-            Ok(r)
-        } else {
-            Err(error)
-        }
+    #[deprecated(note = "use `System::property`")]
+    pub fn bool_tracked_device_property(
+        &self,
+        device: TrackedDeviceIndex,
+        property: impl Into<TrackedDeviceProperty>,
+    ) -> Result<bool, TrackedPropertyError> {
+        self.property(device, Property::new(property.into().0))
     }
 
+    #[deprecated(note = "use `System::property`")]
     pub fn float_tracked_device_property(
         &self,
         device: TrackedDeviceIndex,
-        property: TrackedDeviceProperty,
+        property: impl Into<TrackedDeviceProperty>,
     ) -> Result<f32, TrackedPropertyError> {
-        let mut error: mem::MaybeUninit<TrackedPropertyError> = mem::MaybeUninit::uninit();
-        let r = unsafe {
-            self.0.GetFloatTrackedDeviceProperty.unwrap()(
-                device.0,
-                property.0,
-                error.as_mut_ptr() as *mut sys::TrackedPropertyError,
-            )
-        };
-
-        let error = unsafe { error.assume_init() };
-
-        if error == tracked_property_error::SUCCESS {
-            Ok(r)
-        } else {
-            Err(error)
-        }
+        self.property(device, Property::new(property.into().0))
     }
 
+    #[deprecated(note = "use `System::property`")]
     pub fn int32_tracked_device_property(
         &self,
         device: TrackedDeviceIndex,
-        property: TrackedDeviceProperty,
+        property: impl Into<TrackedDeviceProperty>,
     ) -> Result<i32, TrackedPropertyError> {
-        let mut error: mem::MaybeUninit<TrackedPropertyError> = mem::MaybeUninit::uninit();
-
-        let r = unsafe {
-            self.0.GetInt32TrackedDeviceProperty.unwrap()(
-                device.0,
-                property.0,
-                error.as_mut_ptr() as *mut sys::TrackedPropertyError,
-            )
-        };
-
-        let error = unsafe { error.assume_init() };
-
-        if error == tracked_property_error::SUCCESS {
-            Ok(r)
-        } else {
-            Err(error)
-        }
+        self.property(device, Property::new(property.into().0))
     }
 
+    #[deprecated(note = "use `System::property`")]
     pub fn uint64_tracked_device_property(
         &self,
         device: TrackedDeviceIndex,
-        property: TrackedDeviceProperty,
+        property: impl Into<TrackedDeviceProperty>,
     ) -> Result<u64, TrackedPropertyError> {
-        let mut error: mem::MaybeUninit<TrackedPropertyError> = mem::MaybeUninit::uninit();
-
-        let r = unsafe {
-            self.0.GetUint64TrackedDeviceProperty.unwrap()(
-                device.0,
-                property.0,
-                error.as_mut_ptr() as *mut sys::TrackedPropertyError,
-            )
-        };
-
-        let error = unsafe { error.assume_init() };
-
-        if error == tracked_property_error::SUCCESS {
-            Ok(r)
-        } else {
-            Err(error)
-        }
+        self.property(device, Property::new(property.into().0))
     }
 
+    #[deprecated(note = "use `System::property`")]
     pub fn matrix34_tracked_device_property(
         &self,
         device: TrackedDeviceIndex,
-        property: TrackedDeviceProperty,
+        property: impl Into<TrackedDeviceProperty>,
     ) -> Result<[[f32; 4]; 3], TrackedPropertyError> {
-        let mut error: mem::MaybeUninit<TrackedPropertyError> = mem::MaybeUninit::uninit();
-
-        let r = unsafe {
-            self.0.GetMatrix34TrackedDeviceProperty.unwrap()(
-                device.0,
-                property.0,
-                error.as_mut_ptr() as *mut sys::TrackedPropertyError,
-            )
-        };
-
-        let error = unsafe { error.assume_init() };
-
-        if error == tracked_property_error::SUCCESS {
-            Ok(r.m)
-        } else {
-            Err(error)
-        }
+        self.property(device, Property::new(property.into().0))
     }
 
+    #[deprecated(note = "use `System::property`")]
     pub fn string_tracked_device_property(
         &self,
         device: TrackedDeviceIndex,
-        property: TrackedDeviceProperty,
+        property: impl Into<TrackedDeviceProperty>,
     ) -> Result<CString, TrackedPropertyError> {
+        let property = property.into();
         unsafe {
             let mut error = mem::MaybeUninit::uninit();
             let res = get_string(|ptr, n| {
@@ -378,7 +357,7 @@ impl System {
                     error.as_mut_ptr(),
                 )
             });
-            res.map_or(Err(TrackedPropertyError(error.assume_init())), Ok)
+            res.ok_or_else(|| TrackedPropertyError(error.assume_init()))
         }
     }
 
@@ -471,6 +450,33 @@ impl System {
         unsafe { self.0.TriggerHapticPulse.unwrap()(device.0, axis, microseconds) }
     }
 
+    /// Buttons `device` has, from its `SupportedButtons_Uint64` property.
+    pub fn supported_buttons(
+        &self,
+        device: TrackedDeviceIndex,
+    ) -> Result<ButtonMask, TrackedPropertyError> {
+        self.property(device, crate::property::SupportedButtons_Uint64)
+            .map(ButtonMask::from_u64_truncated)
+    }
+
+    /// Name of a button's `EVRButtonId`, e.g. `k_EButton_Grip`.
+    pub fn button_name(&self, button: Button) -> String {
+        unsafe { CStr::from_ptr(self.0.GetButtonIdNameFromEnum.unwrap()(button.id())) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Name of an `EVRControllerAxisType`, e.g. `k_eControllerAxis_Joystick`.
+    pub fn controller_axis_type_name(&self, ty: AxisType) -> String {
+        unsafe {
+            CStr::from_ptr(self.0.GetControllerAxisTypeNameFromEnum.unwrap()(
+                ty as sys::EVRControllerAxisType,
+            ))
+        }
+        .to_string_lossy()
+        .into_owned()
+    }
+
     /// Call this to acknowledge to the system that `Event::Quit` has been received and that the process is exiting.
     ///
     /// This extends the timeout until the process is killed.
@@ -480,93 +486,62 @@ impl System {
         }
     }
 
+    /// Transform from the seated zero pose to standing space, to convert seated poses to standing
+    /// ones.
+    pub fn seated_zero_pose_to_standing_absolute_tracking_pose(&self) -> [[f32; 4]; 3] {
+        unsafe {
+            self.0
+                .GetSeatedZeroPoseToStandingAbsoluteTrackingPose
+                .unwrap()()
+            .m
+        }
+    }
+
     pub fn raw_zero_pose_to_standing_absolute_tracking_pose(&self) -> [[f32; 4]; 3] {
         unsafe {
             let matrix = self.0.GetRawZeroPoseToStandingAbsoluteTrackingPose.unwrap()();
             matrix.m
         }
     }
+    #[deprecated(note = "use `System::property`")]
     pub fn get_tracked_device_property_string(
         &self,
         index: TrackedDeviceIndex,
         prop: sys::ETrackedDeviceProperty,
     ) -> Result<String, TrackedPropertyError> {
-        let mut vec: Vec<u8> = Vec::with_capacity(512);
-        let mut err = TrackedPropertyError(0);
-        unsafe {
-            let read = self.0.GetStringTrackedDeviceProperty.unwrap()(
-                index.0,
-                prop,
-                vec.as_mut_ptr().cast(),
-                vec.capacity() as u32,
-                (&raw mut err).cast(),
-            );
-            if err != system::TrackedPropertyError(0) {
-                return Err(err);
-            }
-            vec.set_len(read as usize);
-            Ok(String::from_utf8(vec).unwrap())
-        }
+        self.property(index, Property::new(prop))
     }
+    #[deprecated(note = "use `System::property`")]
     pub fn get_tracked_device_property_bool(
         &self,
         index: TrackedDeviceIndex,
         prop: sys::ETrackedDeviceProperty,
     ) -> Result<bool, TrackedPropertyError> {
-        let mut err = TrackedPropertyError(0);
-        unsafe {
-            let ret =
-                self.0.GetBoolTrackedDeviceProperty.unwrap()(index.0, prop, (&raw mut err).cast());
-            if err != system::TrackedPropertyError(0) {
-                return Err(err);
-            }
-            Ok(ret)
-        }
+        self.property(index, Property::new(prop))
     }
+    #[deprecated(note = "use `System::property`")]
     pub fn get_tracked_device_property_f32(
         &self,
         index: TrackedDeviceIndex,
         prop: sys::ETrackedDeviceProperty,
     ) -> Result<f32, TrackedPropertyError> {
-        let mut err = TrackedPropertyError(0);
-        unsafe {
-            let ret =
-                self.0.GetFloatTrackedDeviceProperty.unwrap()(index.0, prop, (&raw mut err).cast());
-            if err != system::TrackedPropertyError(0) {
-                return Err(err);
-            }
-            Ok(ret)
-        }
+        self.property(index, Property::new(prop))
     }
+    #[deprecated(note = "use `System::property`")]
     pub fn get_tracked_device_property_i32(
         &self,
         index: TrackedDeviceIndex,
         prop: sys::ETrackedDeviceProperty,
     ) -> Result<i32, TrackedPropertyError> {
-        let mut err = TrackedPropertyError(0);
-        unsafe {
-            let ret =
-                self.0.GetInt32TrackedDeviceProperty.unwrap()(index.0, prop, (&raw mut err).cast());
-            if err != system::TrackedPropertyError(0) {
-                return Err(err);
-            }
-            Ok(ret)
-        }
+        self.property(index, Property::new(prop))
     }
+    #[deprecated(note = "use `System::property`")]
     pub fn get_tracked_device_property_u64(
         &self,
         index: TrackedDeviceIndex,
         prop: sys::ETrackedDeviceProperty,
     ) -> Result<u64, TrackedPropertyError> {
-        let mut err = TrackedPropertyError(0);
-        unsafe {
-            let ret =
-                self.0.GetUint64TrackedDeviceProperty.unwrap()(index.0, prop, (&raw mut err).cast());
-            if err != system::TrackedPropertyError(0) {
-                return Err(err);
-            }
-            Ok(ret)
-        }
+        self.property(index, Property::new(prop))
     }
 }
 
@@ -591,7 +566,7 @@ pub struct DistortionCoordinates {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq)]
-pub struct TrackedPropertyError(sys::TrackedPropertyError);
+pub struct TrackedPropertyError(pub(crate) sys::TrackedPropertyError);
 
 pub mod tracked_property_error {
     use super::{sys, TrackedPropertyError};
@@ -0,0 +1,414 @@
+//! Bookkeeping of the connected tracked devices.
+
+use std::{fmt, slice};
+
+use super::event::{Event, EventInfo};
+use crate::property;
+use crate::{
+    System, TrackedControllerRole, TrackedDeviceClass, TrackedDeviceIndex, MAX_TRACKED_DEVICE_COUNT,
+};
+
+/// Identity of a physical tracked device, independent of the index it is currently connected at.
+///
+/// Keyed on `Prop_SerialNumber_String`, so state attached to a `DeviceId` follows the device when
+/// it disconnects and reconnects at another `TrackedDeviceIndex`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DeviceId(String);
+
+impl DeviceId {
+    pub fn new(serial_number: impl Into<String>) -> Self {
+        DeviceId(serial_number.into())
+    }
+
+    pub fn serial_number(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&self.0)
+    }
+}
+
+/// What the registry knows about a connected device.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceInfo {
+    pub index: TrackedDeviceIndex,
+    pub class: TrackedDeviceClass,
+    /// `TrackedControllerRole::Invalid` for devices without a role, e.g. the HMD.
+    pub role: TrackedControllerRole,
+    pub serial_number: Option<String>,
+    pub model_number: Option<String>,
+    pub render_model_name: Option<String>,
+}
+
+impl DeviceInfo {
+    /// Reads the current state of `index` from `system`, or `None` if no device is connected there.
+    pub fn query(system: &System, index: TrackedDeviceIndex) -> Option<Self> {
+        let class = system.tracked_device_class(index);
+        if class == TrackedDeviceClass::Invalid || !system.is_tracked_device_connected(index) {
+            return None;
+        }
+        Some(DeviceInfo {
+            index,
+            class,
+            role: role(system, index),
+            serial_number: system.property(index, property::SerialNumber_String).ok(),
+            model_number: system.property(index, property::ModelNumber_String).ok(),
+            render_model_name: system
+                .property(index, property::RenderModelName_String)
+                .ok(),
+        })
+    }
+
+    /// `None` for devices that don't report a serial number.
+    pub fn id(&self) -> Option<DeviceId> {
+        self.serial_number.clone().map(DeviceId)
+    }
+}
+
+fn role(system: &System, index: TrackedDeviceIndex) -> TrackedControllerRole {
+    system
+        .get_controller_role_for_tracked_device_index(index)
+        .unwrap_or(TrackedControllerRole::Invalid)
+}
+
+/// A change to the set of connected devices, as reported by `DeviceRegistry`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeviceChange {
+    Added(DeviceInfo),
+    Removed(DeviceInfo),
+    RoleChanged {
+        index: TrackedDeviceIndex,
+        old: TrackedControllerRole,
+        new: TrackedControllerRole,
+    },
+}
+
+/// Snapshot of all connected tracked devices, kept up to date from `System` events.
+///
+/// ```no_run
+/// # let context = openvr::init(openvr::ApplicationType::Other).unwrap();
+/// # let system = context.system().unwrap();
+/// use openvr::system::{DeviceChange, DeviceRegistry};
+///
+/// let mut registry = DeviceRegistry::new(&system);
+/// while let Some(event) = system.poll_next_event() {
+///     for change in registry.handle_event(&system, &event) {
+///         if let DeviceChange::Added(device) = change {
+///             println!("{:?} connected: {:?}", device.class, device.serial_number);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeviceRegistry {
+    devices: Vec<Option<DeviceInfo>>,
+}
+
+impl DeviceRegistry {
+    /// Snapshots every connected device.
+    pub fn new(system: &System) -> Self {
+        let mut registry = DeviceRegistry {
+            devices: vec![None; MAX_TRACKED_DEVICE_COUNT],
+        };
+        registry.refresh(system);
+        registry
+    }
+
+    /// Re-reads every device slot, returning what changed since the last snapshot.
+    pub fn refresh(&mut self, system: &System) -> Vec<DeviceChange> {
+        let mut changes = Vec::new();
+        for i in 0..MAX_TRACKED_DEVICE_COUNT {
+            self.update(
+                DeviceInfo::query(system, TrackedDeviceIndex(i as u32)),
+                i,
+                &mut changes,
+            );
+        }
+        changes
+    }
+
+    /// Updates the registry from an event returned by `System::poll_next_event`, returning what
+    /// changed.
+    ///
+    /// Events that don't concern the set of devices are ignored.
+    pub fn handle_event(&mut self, system: &System, event: &EventInfo) -> Vec<DeviceChange> {
+        let mut changes = Vec::new();
+        let index = event.tracked_device_index;
+        match event.event {
+            Event::TrackedDeviceActivated
+            | Event::TrackedDeviceDeactivated
+            | Event::TrackedDeviceUpdated
+            | Event::PropertyChanged(_)
+                if (index.0 as usize) < MAX_TRACKED_DEVICE_COUNT =>
+            {
+                self.update(
+                    DeviceInfo::query(system, index),
+                    index.0 as usize,
+                    &mut changes,
+                );
+            }
+            // Role changes are not reliably reported against the device whose role changed.
+            Event::TrackedDeviceRoleChanged => {
+                for device in self.devices.iter_mut().flatten() {
+                    let new = role(system, device.index);
+                    if new != device.role {
+                        changes.push(DeviceChange::RoleChanged {
+                            index: device.index,
+                            old: device.role,
+                            new,
+                        });
+                        device.role = new;
+                    }
+                }
+            }
+            _ => {}
+        }
+        changes
+    }
+
+    fn update(&mut self, info: Option<DeviceInfo>, i: usize, changes: &mut Vec<DeviceChange>) {
+        // A device that reappears elsewhere without a deactivation being seen is no longer at its
+        // old index.
+        if let Some(serial) = info.as_ref().and_then(|x| x.serial_number.as_ref()) {
+            for (j, slot) in self.devices.iter_mut().enumerate() {
+                if j != i
+                    && slot
+                        .as_ref()
+                        .is_some_and(|x| x.serial_number.as_ref() == Some(serial))
+                {
+                    changes.push(DeviceChange::Removed(slot.take().unwrap()));
+                }
+            }
+        }
+        let slot = &mut self.devices[i];
+        match (slot.take(), info) {
+            (None, None) => {}
+            (None, Some(new)) => {
+                changes.push(DeviceChange::Added(new.clone()));
+                *slot = Some(new);
+            }
+            (Some(old), None) => changes.push(DeviceChange::Removed(old)),
+            (Some(old), Some(new)) => {
+                if old.class != new.class || old.serial_number != new.serial_number {
+                    changes.push(DeviceChange::Removed(old));
+                    changes.push(DeviceChange::Added(new.clone()));
+                } else if old.role != new.role {
+                    changes.push(DeviceChange::RoleChanged {
+                        index: new.index,
+                        old: old.role,
+                        new: new.role,
+                    });
+                }
+                *slot = Some(new);
+            }
+        }
+    }
+
+    pub fn get(&self, index: TrackedDeviceIndex) -> Option<&DeviceInfo> {
+        self.devices.get(index.0 as usize).and_then(|x| x.as_ref())
+    }
+
+    /// Connected devices, in index order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self.devices.iter())
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    pub fn find_by_role(&self, role: TrackedControllerRole) -> Option<&DeviceInfo> {
+        self.iter().find(|x| x.role == role)
+    }
+
+    pub fn find_by_serial(&self, serial_number: &str) -> Option<&DeviceInfo> {
+        self.iter()
+            .find(|x| x.serial_number.as_deref() == Some(serial_number))
+    }
+
+    pub fn get_by_id(&self, id: &DeviceId) -> Option<&DeviceInfo> {
+        self.find_by_serial(id.serial_number())
+    }
+
+    /// Index `id` is currently connected at, if it is connected.
+    pub fn index_of(&self, id: &DeviceId) -> Option<TrackedDeviceIndex> {
+        self.get_by_id(id).map(|x| x.index)
+    }
+
+    /// Identity of the device currently connected at `index`.
+    pub fn id_of(&self, index: TrackedDeviceIndex) -> Option<DeviceId> {
+        self.get(index).and_then(DeviceInfo::id)
+    }
+
+    pub fn of_class(&self, class: TrackedDeviceClass) -> impl Iterator<Item = &DeviceInfo> {
+        self.iter().filter(move |x| x.class == class)
+    }
+}
+
+impl<'a> IntoIterator for &'a DeviceRegistry {
+    type Item = &'a DeviceInfo;
+    type IntoIter = Iter<'a>;
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the devices in a `DeviceRegistry`.
+#[derive(Debug, Clone)]
+pub struct Iter<'a>(slice::Iter<'a, Option<DeviceInfo>>);
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a DeviceInfo;
+    fn next(&mut self) -> Option<&'a DeviceInfo> {
+        self.0.by_ref().flatten().next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockDevice, MockRuntime};
+    use crate::{sys, Context};
+
+    #[test]
+    fn tracks_devices_from_events() {
+        let runtime = MockRuntime::new();
+        let hmd = runtime.add_device(
+            MockDevice::new(TrackedDeviceClass::HMD)
+                .with_property(property::SerialNumber_String, "HMD-1"),
+        );
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        let mut registry = DeviceRegistry::new(&system);
+        assert_eq!(registry.len(), 1);
+        assert_eq!(
+            registry.get(hmd).unwrap().serial_number.as_deref(),
+            Some("HMD-1")
+        );
+
+        let left = runtime.add_device(
+            MockDevice::new(TrackedDeviceClass::Controller)
+                .with_role(TrackedControllerRole::LeftHand)
+                .with_property(property::SerialNumber_String, "CTRL-1"),
+        );
+        runtime.push_event(
+            left,
+            sys::EVREventType_VREvent_TrackedDeviceActivated,
+            Default::default(),
+        );
+        let changes = registry.handle_event(&system, &system.poll_next_event().unwrap());
+        assert!(matches!(changes[..], [DeviceChange::Added(ref x)] if x.index == left));
+        assert_eq!(
+            registry
+                .find_by_role(TrackedControllerRole::LeftHand)
+                .unwrap()
+                .index,
+            left
+        );
+
+        runtime.update_device(left, |x| x.role = TrackedControllerRole::RightHand);
+        runtime.push_event(
+            hmd,
+            sys::EVREventType_VREvent_TrackedDeviceRoleChanged,
+            Default::default(),
+        );
+        let changes = registry.handle_event(&system, &system.poll_next_event().unwrap());
+        assert_eq!(
+            changes,
+            [DeviceChange::RoleChanged {
+                index: left,
+                old: TrackedControllerRole::LeftHand,
+                new: TrackedControllerRole::RightHand,
+            }]
+        );
+
+        // Property changes are reported too; a new serial number means a different device.
+        runtime.update_device(left, |x| x.role = TrackedControllerRole::LeftHand);
+        runtime.push_event(
+            left,
+            sys::EVREventType_VREvent_PropertyChanged,
+            Default::default(),
+        );
+        let changes = registry.handle_event(&system, &system.poll_next_event().unwrap());
+        assert!(matches!(
+            changes[..],
+            [DeviceChange::RoleChanged {
+                new: TrackedControllerRole::LeftHand,
+                ..
+            }]
+        ));
+        runtime.set_property(left, property::SerialNumber_String, "CTRL-2");
+        runtime.push_event(
+            left,
+            sys::EVREventType_VREvent_PropertyChanged,
+            Default::default(),
+        );
+        let changes = registry.handle_event(&system, &system.poll_next_event().unwrap());
+        let serial = |x: &DeviceInfo| x.serial_number.clone();
+        assert!(
+            matches!(changes[..], [DeviceChange::Removed(ref x), DeviceChange::Added(ref y)]
+            if serial(x).as_deref() == Some("CTRL-1") && serial(y).as_deref() == Some("CTRL-2"))
+        );
+
+        runtime.remove_device(left);
+        runtime.push_event(
+            left,
+            sys::EVREventType_VREvent_TrackedDeviceDeactivated,
+            Default::default(),
+        );
+        let changes = registry.handle_event(&system, &system.poll_next_event().unwrap());
+        assert!(matches!(changes[..], [DeviceChange::Removed(ref x)]
+            if serial(x).as_deref() == Some("CTRL-2")));
+        assert_eq!(registry.iter().map(|x| x.index).collect::<Vec<_>>(), [hmd]);
+    }
+
+    #[test]
+    fn ids_follow_devices_across_indices() {
+        let runtime = MockRuntime::new();
+        let controller = || {
+            MockDevice::new(TrackedDeviceClass::Controller)
+                .with_property(property::SerialNumber_String, "CTRL-1")
+        };
+        let other = MockDevice::new(TrackedDeviceClass::GenericTracker)
+            .with_property(property::SerialNumber_String, "TRK-1");
+        let first = runtime.add_device(controller());
+        let context = Context::mock(&runtime);
+        let system = context.system().unwrap();
+        let mut registry = DeviceRegistry::new(&system);
+        let id = system.device_id(first).unwrap();
+        assert_eq!(id, DeviceId::new("CTRL-1"));
+        assert_eq!(registry.id_of(first), Some(id.clone()));
+
+        // The controller reconnects at another index after a tracker took its slot, and the
+        // deactivation is missed.
+        runtime.set_device(first, other);
+        let second = runtime.add_device(controller());
+        runtime.push_event(
+            second,
+            sys::EVREventType_VREvent_TrackedDeviceActivated,
+            Default::default(),
+        );
+        let changes = registry.handle_event(&system, &system.poll_next_event().unwrap());
+        assert!(
+            matches!(changes[..], [DeviceChange::Removed(ref x), DeviceChange::Added(ref y)]
+            if x.index == first && y.index == second)
+        );
+        assert_eq!(registry.index_of(&id), Some(second));
+
+        runtime.push_event(
+            first,
+            sys::EVREventType_VREvent_TrackedDeviceActivated,
+            Default::default(),
+        );
+        registry.handle_event(&system, &system.poll_next_event().unwrap());
+        assert_eq!(registry.id_of(first), Some(DeviceId::new("TRK-1")));
+        assert_eq!(registry.index_of(&id), Some(second));
+    }
+}
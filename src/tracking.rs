@@ -93,6 +93,20 @@ pub enum TrackedDeviceClass {
     TrackingReference = sys::ETrackedDeviceClass_TrackedDeviceClass_TrackingReference as isize,
     DisplayRedirect = sys::ETrackedDeviceClass_TrackedDeviceClass_DisplayRedirect as isize,
 }
+impl From<sys::ETrackedDeviceClass> for TrackedDeviceClass {
+    fn from(x: sys::ETrackedDeviceClass) -> Self {
+        use self::TrackedDeviceClass::*;
+        match x {
+            sys::ETrackedDeviceClass_TrackedDeviceClass_HMD => HMD,
+            sys::ETrackedDeviceClass_TrackedDeviceClass_Controller => Controller,
+            sys::ETrackedDeviceClass_TrackedDeviceClass_GenericTracker => GenericTracker,
+            sys::ETrackedDeviceClass_TrackedDeviceClass_TrackingReference => TrackingReference,
+            sys::ETrackedDeviceClass_TrackedDeviceClass_DisplayRedirect => DisplayRedirect,
+            _ => Invalid,
+        }
+    }
+}
+
 #[derive(Clone, Copy,PartialEq,Debug)]
 pub struct TrackedDeviceIndex(pub sys::TrackedDeviceIndex_t);
 
@@ -115,5 +129,19 @@ pub enum TrackedControllerRole {
 
 }
 
+impl From<sys::ETrackedControllerRole> for TrackedControllerRole {
+    fn from(x: sys::ETrackedControllerRole) -> Self {
+        use self::TrackedControllerRole::*;
+        match x {
+            sys::ETrackedControllerRole_TrackedControllerRole_LeftHand => LeftHand,
+            sys::ETrackedControllerRole_TrackedControllerRole_RightHand => RightHand,
+            sys::ETrackedControllerRole_TrackedControllerRole_OptOut => OptOut,
+            sys::ETrackedControllerRole_TrackedControllerRole_Treadmill => Treadmill,
+            sys::ETrackedControllerRole_TrackedControllerRole_Stylus => Stylus,
+            _ => Invalid,
+        }
+    }
+}
+
 pub const MAX_TRACKED_DEVICE_COUNT: usize = sys::k_unMaxTrackedDeviceCount as usize;
 pub type TrackedDevicePoses=[TrackedDevicePose; MAX_TRACKED_DEVICE_COUNT];